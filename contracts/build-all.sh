@@ -8,8 +8,8 @@ DIR="${DIR:=./deployments}" # Output directory for build files
 # Copy command helper (cross-platform)
 CP_CMD=$(command -v cp &> /dev/null && echo "cp" || echo "copy")
 
-# Determine all contracts under `$CONTRACTS_DIR`
-contracts=($(find $CONTRACTS_DIR -maxdepth 1 -type d -exec test -f {}/Cargo.toml \; -print | xargs -n 1 basename))
+# Determine all contracts under `$CONTRACTS_DIR` (skipping plain library crates like `traits`)
+contracts=($(find $CONTRACTS_DIR -maxdepth 1 -type d -exec test -f {}/Cargo.toml \; -exec grep -q "ink(storage)" {}/lib.rs \; -print | xargs -n 1 basename))
 
 # Build all contracts
 for i in "${contracts[@]}"
@@ -0,0 +1,157 @@
+//! Weight benchmarks of the `Demo` messages with worst-case inputs, run against a node with
+//! `cargo test --features bench`.
+//!
+//! Each benchmark fails if the message requires more `ref_time` than the weight budget, which
+//! can be configured with the `BENCH_REF_TIME_BUDGET` environment variable.
+
+use crate::{
+    demo::{DemoRef, APPROVAL_LOG_CAPACITY},
+    types::{Config, ContributionId, Platform, RewardMode, SelfApproval, MAX_BPS},
+};
+use ink::primitives::AccountId;
+use ink_e2e::build_message;
+use traits::workflow::Workflow;
+
+type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+/// The default weight budget of a message: a tenth of the `ref_time` of a block.
+const DEFAULT_REF_TIME_BUDGET: u64 = 50_000_000_000;
+
+/// The number of co-authors of the benchmarked shared contribution.
+const BENCH_SPLITS: u8 = 32;
+
+/// Returns the weight budget of a message.
+fn ref_time_budget() -> u64 {
+    std::env::var("BENCH_REF_TIME_BUDGET")
+        .ok()
+        .and_then(|budget| budget.parse().ok())
+        .unwrap_or(DEFAULT_REF_TIME_BUDGET)
+}
+
+/// Calls a message, records the weight it requires and checks it against the budget.
+macro_rules! bench {
+    ($client:ident, $signer:expr, $name:literal, $message:expr) => {{
+        let result = $client
+            .call($signer, $message, 0, None)
+            .await
+            .expect(concat!($name, " failed"));
+        let ref_time = result.dry_run.exec_result.gas_required.ref_time();
+        println!("{}: {} ref_time", $name, ref_time);
+        assert!(
+            ref_time <= ref_time_budget(),
+            "{} exceeds the weight budget with {} ref_time",
+            $name,
+            ref_time
+        );
+    }};
+}
+
+#[ink_e2e::test]
+async fn bench_messages(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+    let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+    let demo = client
+        .instantiate("demo", &ink_e2e::alice(), DemoRef::new(), 0, None)
+        .await
+        .expect("instantiate failed")
+        .account_id;
+
+    // Enable every setting read on approval, without reward as the contract has no balance
+    let config = Config {
+        reward: 0,
+        bonus_bps: 5_000,
+        reward_mode: RewardMode::Sqrt,
+        epoch_length: 10,
+        streak_bonus_bps: 1_000,
+        contributor_cap: u32::MAX,
+        claim_ttl: 1_000,
+        referral_bonus: 0,
+        relayer_fee_bps: 0,
+        approval_stake: 0,
+        challenge_period: 0,
+        poke_tip: 0,
+        min_claim: 0,
+        endorsement_bonus: 0,
+        max_endorsements: 0,
+        review_quorum: 0,
+        attestation_threshold: 0,
+        min_account_age: 0,
+        self_approval: SelfApproval::Allowed,
+        emit_transfer_events: true,
+        halving_period: 0,
+        deposit_per_byte: 0,
+        duplicate_claim_threshold: 0,
+        sweep_bounty_bps: 0,
+    };
+    bench!(
+        client,
+        &ink_e2e::alice(),
+        "set_config",
+        build_message::<DemoRef>(demo.clone()).call(|demo| demo.set_config(config.clone()))
+    );
+    let create_campaign =
+        build_message::<DemoRef>(demo.clone()).call(|demo| demo.create_campaign(0, u32::MAX, 1));
+    client
+        .call(&ink_e2e::alice(), create_campaign, 0, None)
+        .await
+        .expect("create_campaign failed");
+
+    // Fill the approval log, so the next approvals prune it
+    for contribution_id in 0..APPROVAL_LOG_CAPACITY as ContributionId {
+        let approve = build_message::<DemoRef>(demo.clone())
+            .call(|demo| Workflow::approve(demo, contribution_id, bob));
+        client
+            .call(&ink_e2e::alice(), approve, 0, None)
+            .await
+            .expect("approve failed");
+    }
+    let contribution_id = APPROVAL_LOG_CAPACITY as ContributionId;
+    bench!(
+        client,
+        &ink_e2e::alice(),
+        "approve",
+        build_message::<DemoRef>(demo.clone()).call(|demo| Workflow::approve(
+            demo,
+            contribution_id,
+            bob
+        ))
+    );
+
+    let mut splits = vec![(bob, MAX_BPS - u16::from(BENCH_SPLITS - 1))];
+    splits.extend((1..BENCH_SPLITS).map(|index| (AccountId::from([index; 32]), 1)));
+    bench!(
+        client,
+        &ink_e2e::alice(),
+        "approve_with_splits",
+        build_message::<DemoRef>(demo.clone())
+            .call(|demo| demo.approve_with_splits(contribution_id + 1, splits.clone()))
+    );
+
+    bench!(
+        client,
+        &ink_e2e::bob(),
+        "check_detailed",
+        build_message::<DemoRef>(demo.clone())
+            .call(|demo| demo.check_detailed(contribution_id + 1))
+    );
+    bench!(
+        client,
+        &ink_e2e::bob(),
+        "claim",
+        build_message::<DemoRef>(demo.clone()).call(|demo| demo.claim(contribution_id + 1))
+    );
+    bench!(
+        client,
+        &ink_e2e::bob(),
+        "approvals_since",
+        build_message::<DemoRef>(demo.clone()).call(|demo| demo.approvals_since(0))
+    );
+    bench!(
+        client,
+        &ink_e2e::bob(),
+        "register_identity",
+        build_message::<DemoRef>(demo.clone())
+            .call(|demo| demo.register_identity((Platform::GitHub, [0x01; 32])))
+    );
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+//! Codec helpers for addresses given as strings and raw storage values.
+
+use ink::prelude::{vec, vec::Vec};
+
+/// The base58 alphabet of SS58 addresses.
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// The length of a decoded SS58 address with a two-byte network prefix.
+const MAX_SS58_LENGTH: usize = 36;
+
+/// Returns the 32-byte public key encoded in an SS58 `address`, whatever its network prefix.
+///
+/// The checksum is not verified, as the runtime exposes no Blake2b-512 hash: a mistyped
+/// address decodes to a key nobody holds rather than failing.
+pub fn decode_ss58(address: &str) -> Option<[u8; 32]> {
+    let mut bytes = [0u8; MAX_SS58_LENGTH];
+    let (prefix_length, length) = match decode_base58(address, &mut bytes)? {
+        35 if bytes[0] < 64 => (1, 35),
+        36 if bytes[0] & 0b1100_0000 == 0b0100_0000 => (2, 36),
+        _ => return None,
+    };
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&bytes[prefix_length..length - 2]);
+    Some(key)
+}
+
+/// Bytes encoded as they are, without a length prefix, to read raw storage keys and values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawBytes(pub Vec<u8>);
+
+impl scale::Encode for RawBytes {
+    fn size_hint(&self) -> usize {
+        self.0.len()
+    }
+
+    fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
+        dest.write(&self.0)
+    }
+}
+
+impl scale::Decode for RawBytes {
+    fn decode<I: scale::Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let length = input
+            .remaining_len()?
+            .ok_or("the length of raw bytes is unknown")?;
+        let mut bytes = vec![0u8; length];
+        input.read(&mut bytes)?;
+        Ok(Self(bytes))
+    }
+}
+
+/// Decodes a base58 `input` into `output`, returning the decoded length.
+fn decode_base58(input: &str, output: &mut [u8; MAX_SS58_LENGTH]) -> Option<usize> {
+    // The decoded number, little-endian
+    let mut number = [0u8; MAX_SS58_LENGTH];
+    let mut length = 0;
+    for char in input.bytes() {
+        let mut carry = ALPHABET.iter().position(|&digit| digit == char)? as u32;
+        for byte in number[..length].iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            if length == MAX_SS58_LENGTH {
+                return None;
+            }
+            number[length] = carry as u8;
+            length += 1;
+            carry >>= 8;
+        }
+    }
+
+    // Each leading '1' encodes a leading zero byte
+    let zeros = input.bytes().take_while(|&char| char == b'1').count();
+    if zeros + length > MAX_SS58_LENGTH {
+        return None;
+    }
+    output[..zeros].fill(0);
+    for (index, byte) in number[..length].iter().rev().enumerate() {
+        output[zeros + index] = *byte;
+    }
+    Some(zeros + length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scale::{Decode, Encode};
+
+    /// The public key of ALICE in the development keyring.
+    const ALICE: [u8; 32] = [
+        0xd4, 0x35, 0x93, 0xc7, 0x15, 0xfd, 0xd3, 0x1c, 0x61, 0x14, 0x1a, 0xbd, 0x04, 0xa9, 0x9f,
+        0xd6, 0x82, 0x2c, 0x85, 0x58, 0x85, 0x4c, 0xcd, 0xe3, 0x9a, 0x56, 0x84, 0xe7, 0xa5, 0x6d,
+        0xa2, 0x7d,
+    ];
+
+    #[test]
+    fn decode_ss58_works() {
+        // Generic substrate (42), Polkadot (0) and Kusama (2) addresses
+        assert_eq!(
+            decode_ss58("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"),
+            Some(ALICE)
+        );
+        assert_eq!(
+            decode_ss58("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5"),
+            Some(ALICE)
+        );
+        assert_eq!(
+            decode_ss58("HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F"),
+            Some(ALICE)
+        );
+    }
+
+    #[test]
+    fn decode_invalid_ss58_fails() {
+        assert_eq!(decode_ss58(""), None);
+        assert_eq!(
+            decode_ss58("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQ0"),
+            None
+        );
+        assert_eq!(
+            decode_ss58("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKut"),
+            None
+        );
+    }
+
+    #[test]
+    fn raw_bytes_are_not_prefixed() {
+        let raw = RawBytes(vec![0x01, 0x02, 0x03]);
+        assert_eq!(raw.encode(), vec![0x01, 0x02, 0x03]);
+        assert_eq!(RawBytes::decode(&mut &[0x01, 0x02, 0x03][..]), Ok(raw));
+        assert_eq!(RawBytes::decode(&mut &[][..]), Ok(RawBytes(Vec::new())));
+    }
+}
@@ -1,185 +1,12462 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
+pub mod codec;
 pub mod errors;
+pub mod math;
+pub mod merkle;
+pub mod scheduler;
+pub mod state;
 pub mod types;
 
+#[cfg(all(test, feature = "bench"))]
+mod bench;
+
 #[openbrush::implementation(Ownable)]
 #[openbrush::contract]
 pub mod demo {
+    use super::codec::{decode_ss58, RawBytes};
     use super::errors::DemoError;
-    use super::types::ContributionId;
-    use ink::storage::Mapping;
-    use openbrush::{modifiers, traits::Storage};
+    use super::math::{decay, isqrt, mul_div};
+    use super::merkle;
+    use super::scheduler::{Due, Now};
+    use super::state::{transition, Action, Status};
+    use super::types::{
+        Activity, Asset, AssetInfo, BoundedString, BoundedVec, Campaign, CampaignId,
+        CampaignSummary, ClaimEstimate, Config, Contribution, ContributionDetails, ContributionId,
+        ContributionKind, ContributionStatus, ContributionView, ContributorId, Did, Dispute,
+        EcdsaPublicKey, EcdsaSignature, EncryptionPublicKey, HashAlgorithm, HashValue,
+        IdentityView, InvariantViolation, LanguageCode, LedgerEntry, Movement, Payout, Platform,
+        Profile, ProjectEvent, Proposal, ProposalAction, ProposalId, ProposedApproval, Rejection,
+        Relation, RewardMode, RoleId, SelfApproval, Split, Streak, String, Submission, Sweep, Task,
+        UsdPricing, Voucher, MAX_BPS, MAX_DIFFICULTY, MAX_ERROR_MESSAGE_LENGTH, MAX_SPLITS,
+        MAX_URI_LENGTH,
+    };
+    use ink::{
+        env::hash::Blake2x256,
+        prelude::{vec, vec::Vec},
+        storage::{Lazy, Mapping},
+    };
+    use openbrush::{
+        contracts::{
+            reentrancy_guard::{self, non_reentrant},
+            traits::{
+                ownable::Ownable,
+                psp22::{extensions::metadata::PSP22Metadata, PSP22Error, PSP22 as _},
+                psp34::PSP34 as _,
+            },
+        },
+        modifiers,
+        traits::Storage,
+    };
+    use traits::{
+        attestor::KudosAttestor, hooks::OnContributionApproved, identity::IdentityRegistry,
+        price::PriceFeed, strategy::RewardStrategy, vault::RewardVault, workflow::Workflow,
+    };
+
+    /// The hash identifying the project of a contract (e.g. `blake2("kudos-ink/demo")`).
+    pub type ProjectId = Hash;
+
+    /// An estimate of the storage added by an approval, in bytes: the contribution with a
+    /// single contributor and its indexes.
+    pub const APPROVAL_STORAGE_BYTES: Balance = 256;
+
+    /// The KUDOS points minted to each co-author of an approved contribution.
+    pub const POINTS_PER_APPROVAL: Balance = 1;
+
+    /// The default delay between scheduling and executing a sweep: 7 days (in milliseconds).
+    pub const DEFAULT_SWEEP_TIMELOCK: Timestamp = 7 * 24 * 60 * 60 * 1000;
+
+    /// The maximum number of entries kept in the approval log.
+    pub const APPROVAL_LOG_CAPACITY: usize = 100;
+
+    /// The maximum number of entries kept in the activity log of a contributor.
+    pub const ACTIVITY_LOG_CAPACITY: usize = 10;
+
+    /// The maximum number of tags of a contribution.
+    pub const MAX_TAGS: usize = 5;
+
+    /// The maximum length of a tag (in bytes).
+    pub const MAX_TAG_LENGTH: usize = 32;
+
+    /// The maximum length of a revealed contributor handle (in bytes).
+    pub const MAX_HANDLE_LENGTH: usize = 64;
+
+    /// The number of blocks over which the reputation of a contributor halves: about 90 days
+    /// of 6s blocks.
+    pub const REPUTATION_HALF_LIFE: BlockNumber = 90 * 24 * 60 * 10;
+
+    /// The reputation units per KUDOS point, so the decay of a few points stays visible.
+    pub const REPUTATION_PRECISION: Balance = 1_000_000;
+
+    /// The maximum length of a Merkle proof of the airdrop.
+    pub const MAX_PROOF_LENGTH: usize = 32;
+
+    /// The maximum number of entries returned by a paginated query.
+    pub const MAX_PAGE_SIZE: u32 = 100;
+
+    /// The number of blocks after approval from which anyone can archive a settled
+    /// contribution: about 30 days of 6s blocks.
+    pub const PUBLIC_ARCHIVE_DELAY: BlockNumber = 30 * 24 * 60 * 10;
+
+    /// The maximum length of an encrypted memo, in bytes.
+    pub const MAX_ENCRYPTED_MEMO_LENGTH: usize = 1024;
+
+    /// The length of a merge commit SHA, in bytes: 20 for SHA-1 and 32 for SHA-256
+    /// repositories.
+    pub const COMMIT_SHA_LENGTHS: [usize; 2] = [20, 32];
+
+    /// The number of blocks a governance proposal is open to votes: about 7 days of 6s blocks.
+    pub const VOTING_PERIOD: BlockNumber = 7 * 24 * 60 * 10;
+
+    /// The share (in basis points) of the KUDOS points supply that must vote on a governance
+    /// proposal for it to pass.
+    pub const QUORUM_BPS: u16 = 2_000;
+
+    /// The number of blocks before its claim deadline from which a reminder is emitted for an
+    /// unclaimed contribution: about 1 day of 6s blocks.
+    pub const EXPIRY_NOTICE_PERIOD: BlockNumber = 24 * 60 * 10;
+
+    /// The number of blocks of an era, the period earnings are totalled over: about 30 days of
+    /// 6s blocks.
+    pub const ERA_LENGTH: BlockNumber = 30 * 24 * 60 * 10;
+
+    /// The maximum number of sponsors credited on a contribution.
+    pub const MAX_SPONSORS: usize = 20;
+
+    /// The maximum number of localized error messages, over every language.
+    pub const MAX_ERROR_MESSAGES: u32 = 500;
+
+    /// The maximum number of maintainers sharing the fees of a project.
+    pub const MAX_MAINTAINERS: usize = 20;
+
+    /// The maximum number of arbiters in the pool.
+    pub const MAX_ARBITERS: usize = 20;
+
+    /// The maximum number of contributor levels.
+    pub const MAX_LEVELS: usize = 10;
+
+    /// The maximum number of proposed approvals pending at once.
+    pub const MAX_PENDING_APPROVALS: usize = 100;
+
+    /// The number of blocks the assigned arbiter has to resolve a dispute before the owner
+    /// takes over: about 3 days of 6s blocks.
+    pub const ARBITRATION_PERIOD: BlockNumber = 3 * 24 * 60 * 10;
+
+    /// The maximum number of entries kept in the ledger of the native reward pool.
+    pub const LEDGER_CAPACITY: u32 = 1_000;
+
+    /// A USD cent in the 18-decimal prices of the price feed.
+    pub const USD_CENT: u128 = 10_000_000_000_000_000;
+
+    /// The maximum number of contracts notified of approvals.
+    pub const MAX_SUBSCRIBERS: usize = 10;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Demo {
+        // The field to save the owner of the contract
+        #[storage_field]
+        ownable: ownable::Data,
+
+        // The field to guard payouts against reentrancy
+        #[storage_field]
+        guard: reentrancy_guard::Data,
+
+        // The reward settings.
+        config: Lazy<Config>,
+
+        // The canonical algorithm hashing identities.
+        hash_algorithm: Lazy<HashAlgorithm>,
+
+        // The project indexing the approval and claim events.
+        project_id: Lazy<ProjectId>,
+
+        // The base URI of the off-chain JSON metadata of the contributions.
+        metadata_base_uri: Lazy<BoundedString<MAX_URI_LENGTH>>,
+
+        // The maintainers of each project and their share (in basis points) of its fees.
+        maintainer_shares: Mapping<ProjectId, Vec<(AccountId, u16)>>,
+
+        // The fees of each project not distributed yet, rounding dust included.
+        project_fees: Mapping<ProjectId, Balance>,
+
+        // The approved `Contribution`.
+        contributions: Mapping<ContributionId, Contribution>,
+
+        // The id of each approved `Contribution`, by approval order.
+        contribution_ids: Mapping<u32, ContributionId>,
+
+        // The number of approved `Contribution`.
+        contribution_count: Lazy<u32>,
+
+        // The ids of the unclaimed `Contribution`, in no particular order.
+        unclaimed_ids: Mapping<u32, ContributionId>,
+
+        // The index of each unclaimed `Contribution` in `unclaimed_ids`.
+        unclaimed_indexes: Mapping<ContributionId, u32>,
+
+        // The number of unclaimed `Contribution`.
+        unclaimed_count: Lazy<u32>,
+
+        // The account bound to each contributor identity, per platform.
+        identities: Mapping<ContributorId, AccountId>,
+
+        // The (first) contributor identity bound to each account.
+        accounts: Mapping<AccountId, ContributorId>,
+
+        // The accounts allowed to be bound to many contributor identities.
+        shared: Mapping<AccountId, ()>,
+
+        // The receipt hashes of the claimed `Contribution`.
+        receipts: Mapping<HashValue, ContributionId>,
+
+        // The number of approved `Contribution` per contributor.
+        approvals: Mapping<AccountId, u32>,
+
+        // The reward campaigns.
+        campaigns: Mapping<CampaignId, Campaign>,
+
+        // The id of the next created `Campaign`.
+        next_campaign_id: Lazy<CampaignId>,
+
+        // The accounts allowed to register identities in strict mode.
+        allowed: Mapping<AccountId, ()>,
+
+        // The sponsors allowed to fund and approve contributions at once.
+        allowed_sponsors: Mapping<AccountId, ()>,
+
+        // The accounts banned from approvals and claims.
+        denied: Mapping<AccountId, ()>,
+
+        // The reason code of each ban.
+        ban_reasons: Mapping<AccountId, u8>,
+
+        // Whether only allowed accounts can register identities.
+        strict_mode: Lazy<bool>,
+
+        // The latest movements of the native reward pool, by index modulo the capacity.
+        ledger: Mapping<u32, LedgerEntry>,
+
+        // The number of movements of the native reward pool.
+        ledger_count: Lazy<u32>,
+
+        // The Merkle root of the (GitHub identity hash, amount) airdrops, if open.
+        airdrop_root: Lazy<Option<HashValue>>,
+
+        // The native balance set aside for the airdrop.
+        airdrop_pool: Lazy<Balance>,
+
+        // The GitHub identity hashes whose airdrop is claimed.
+        airdrop_claimed: Mapping<HashValue, ()>,
+
+        // The native tips attached to each `Contribution`.
+        tips: Mapping<ContributionId, Balance>,
+
+        // The sponsors of each `Contribution` and the total of their tips.
+        sponsors: Mapping<ContributionId, Vec<(AccountId, Balance)>>,
+
+        // The part of the tips of a `Contribution` paid to each co-author.
+        paid_tips: Mapping<(ContributionId, AccountId), Balance>,
+
+        // The latest approvals, oldest first.
+        approval_log: Lazy<Vec<(BlockNumber, ContributionId)>>,
+
+        // The latest actions of each contributor, oldest first.
+        activities: Mapping<AccountId, Vec<(BlockNumber, Activity)>>,
+
+        // The streak of each contributor.
+        streaks: Mapping<AccountId, Streak>,
+
+        // The non-transferable KUDOS points of each contributor.
+        points: Mapping<AccountId, Balance>,
+
+        // The total supply of KUDOS points.
+        points_supply: Lazy<Balance>,
+
+        // The native payouts of each contributor below the minimum claim, not paid yet.
+        dust: Mapping<AccountId, Balance>,
+
+        // The decaying reputation of each contributor and the block it was last updated at.
+        reputations: Mapping<AccountId, (Balance, BlockNumber)>,
+
+        // The delay between scheduling and executing a sweep (in milliseconds).
+        sweep_timelock: Lazy<Timestamp>,
+
+        // The scheduled drain of the contract balance.
+        sweep: Lazy<Option<Sweep>>,
+
+        // Whether approvals are published as `system.remark_with_event` chain remarks.
+        remark_approvals: Lazy<bool>,
+
+        // The blocks and milliseconds the sandbox clock is ahead of the chain.
+        #[cfg(feature = "sandbox")]
+        time_offset: Lazy<(BlockNumber, Timestamp)>,
+
+        // Whether importing contributions from a previous deployment is over.
+        import_finalized: Lazy<bool>,
+
+        // Whether KUDOS points holders can change the settings through proposals.
+        governance: Lazy<bool>,
+
+        // The governance proposals, by id.
+        proposals: Mapping<ProposalId, Proposal>,
+
+        // The number of governance proposals.
+        proposal_count: Lazy<ProposalId>,
+
+        // The accounts which voted on each proposal.
+        votes: Mapping<(ProposalId, AccountId), ()>,
+
+        // The sequence number of the last emitted event.
+        event_seq: Lazy<u64>,
+
+        // The index of the next contribution scanned by `poke`.
+        poke_cursor: Lazy<u32>,
+
+        // The index of the next contribution scanned by `tick`.
+        tick_cursor: Lazy<u32>,
+
+        // The ids of the proposed approvals, scanned by `tick`.
+        pending_approvals: Lazy<Vec<ContributionId>>,
+
+        // The `Contribution` whose expiry reminder was emitted.
+        reminded: Mapping<ContributionId, ()>,
+
+        // The scheduled termination of the contract, if any.
+        termination: Lazy<Option<Sweep>>,
+
+        // The accounts that claimed their intent to take over the ownership.
+        ownership_intents: Mapping<AccountId, ()>,
+
+        // The number of approved `Contribution` per contributor in each campaign.
+        campaign_approvals: Mapping<(CampaignId, AccountId), u32>,
+
+        // The rewards granted per contributor in each campaign.
+        campaign_rewards: Mapping<(CampaignId, AccountId), Balance>,
+
+        // The number of distinct contributors rewarded in each campaign.
+        campaign_contributors: Mapping<CampaignId, u32>,
+
+        // The contributor granted the most rewards in each campaign, with their rewards.
+        campaign_tops: Mapping<CampaignId, (AccountId, Balance)>,
+
+        // The frozen summary of each finalized campaign.
+        campaign_summaries: Mapping<CampaignId, CampaignSummary>,
+
+        // The tags of each `Contribution`.
+        tags: Mapping<ContributionId, BoundedVec<BoundedString<MAX_TAG_LENGTH>, MAX_TAGS>>,
+
+        // The last block each session key can approve contributions at.
+        sessions: Mapping<AccountId, BlockNumber>,
+
+        // The allowlisted proxy contracts, with whether they must forward the original signer.
+        proxies: Mapping<AccountId, bool>,
+
+        // The role of each delegate account.
+        roles: Mapping<AccountId, RoleId>,
+
+        // The message selectors each role is permitted to call.
+        permissions: Mapping<(RoleId, [u8; 4]), ()>,
+
+        // The memo hash of each `Contribution`, keeping its metadata off-chain.
+        memos: Mapping<ContributionId, HashValue>,
+
+        // The merge commit SHA of each `Contribution`, to cross-check it with the repository.
+        merge_commits: Mapping<ContributionId, BoundedVec<u8, 32>>,
+
+        // The memo of each `Contribution`, encrypted to the public key of its contributor.
+        encrypted_memos: Mapping<ContributionId, BoundedVec<u8, MAX_ENCRYPTED_MEMO_LENGTH>>,
+
+        // The `Submission` of each contribution pending its approval.
+        submissions: Mapping<ContributionId, Submission>,
+
+        // The `Rejection` of each contribution declined by an approver.
+        rejections: Mapping<ContributionId, Rejection>,
+
+        // The evidence hash of each `Contribution` approved from a submission.
+        evidences: Mapping<ContributionId, HashValue>,
+
+        // Whether each account endorsed a `Contribution`.
+        endorsements: Mapping<(ContributionId, AccountId), ()>,
+
+        // The number of endorsements of each `Contribution`.
+        endorsement_counts: Mapping<ContributionId, u32>,
+
+        // Whether each approver reviewed a contribution pending its review quorum.
+        reviews: Mapping<(ContributionId, AccountId), ()>,
+
+        // The contributor and number of reviews of each contribution pending its review quorum.
+        review_tallies: Mapping<ContributionId, (AccountId, u32)>,
+
+        // The contributors and number of reviews of each contribution reviewed for different
+        // contributors, pending the resolution of the conflict.
+        conflicts: Mapping<ContributionId, Vec<(AccountId, u32)>>,
+
+        // The tagged `Contribution` of each tag, by index.
+        tagged: Mapping<(String, u32), ContributionId>,
+
+        // The number of tagged `Contribution` of each tag.
+        tag_counts: Mapping<String, u32>,
+
+        // The `Contribution` of each contributor identity, by index.
+        identity_contributions: Mapping<(ContributorId, u32), ContributionId>,
+
+        // The number of `Contribution` of each contributor identity.
+        identity_contribution_counts: Mapping<ContributorId, u32>,
+
+        // The contribution each `Contribution` relates to, and how.
+        relations: Mapping<ContributionId, (Relation, ContributionId)>,
+
+        // The contributions relating to each `Contribution`.
+        related: Mapping<ContributionId, Vec<ContributionId>>,
+
+        // The referrer of each referred contributor.
+        referrers: Mapping<AccountId, AccountId>,
+
+        // The contributors referred by each referrer.
+        referrals: Mapping<AccountId, Vec<AccountId>>,
+
+        // The referral bonuses owed to each referrer.
+        referral_rewards: Mapping<AccountId, Balance>,
+
+        // The next approval nonce of each approver.
+        approval_nonces: Mapping<AccountId, u64>,
+
+        // The archived `Contribution`, removed from `contributions`.
+        archived: Mapping<ContributionId, ()>,
+
+        // The hash chaining every archived `Contribution`.
+        archive_root: Lazy<HashValue>,
+
+        // The PSP22 tokens accepted as campaign rewards.
+        reward_assets: Mapping<AccountId, ()>,
+
+        // The rewards owed and not claimed yet, per asset.
+        liabilities: Mapping<Asset, Balance>,
+
+        // The number of assets with rewards owed.
+        indebted_assets: Lazy<u32>,
+
+        // The native liabilities owed as the rewards of approved contributions.
+        reward_liabilities: Lazy<Balance>,
+
+        // The latest claim deadline of the contributions rewarded in the native currency.
+        latest_deadline: Lazy<BlockNumber>,
+
+        // The number of unclaimed contributions rewarded in the native currency without a
+        // claim deadline.
+        open_ended_rewards: Lazy<u32>,
+
+        // The public profile of each contributor account.
+        profiles: Mapping<AccountId, Profile>,
+
+        // The DID linked to each contributor account.
+        dids: Mapping<AccountId, Did>,
+
+        // The localized error messages displayed by the UI, per error code and language.
+        error_messages: Mapping<(u32, LanguageCode), BoundedString<MAX_ERROR_MESSAGE_LENGTH>>,
+
+        // The number of localized error messages.
+        error_message_count: Lazy<u32>,
+
+        // The public key each contributor account receives encrypted memos with.
+        encryption_pubkeys: Mapping<AccountId, EncryptionPublicKey>,
+
+        // The identities whose rewards are paid on approval.
+        auto_claims: Mapping<ContributorId, ()>,
+
+        // The payout address of each contributor account.
+        payouts: Mapping<AccountId, Payout>,
+
+        // The identity that last set each account as its payout address.
+        payout_targets: Mapping<AccountId, ContributorId>,
+
+        // The identity each suspected duplicate account was the payout address of.
+        suspected_duplicates: Mapping<AccountId, ContributorId>,
+
+        // The amount claimed by each suspected duplicate account since it was flagged.
+        suspect_claims: Mapping<AccountId, Balance>,
+
+        // The handle of each contributor identity revealed by its owner.
+        revealed: Mapping<ContributorId, BoundedString<MAX_HANDLE_LENGTH>>,
+
+        // The hash of the (repository, kind, number) each contribution id was derived from.
+        preimages: Mapping<ContributionId, HashValue>,
+
+        // The nonce of the next relayed claim of each contributor.
+        claim_nonces: Mapping<AccountId, u64>,
+
+        // The part of its share each co-author claimed, per contribution.
+        claimed_amounts: Mapping<(ContributionId, AccountId), Balance>,
+
+        // The total paid to each account per era.
+        earnings: Mapping<(AccountId, u32), Balance>,
+
+        // The eras each account was paid in, in ascending order.
+        earning_eras: Mapping<AccountId, Vec<u32>>,
+
+        // The approvals proposed optimistically and not finalized yet.
+        proposed_approvals: Mapping<ContributionId, ProposedApproval>,
+
+        // The accounts eligible to arbitrate disputes.
+        arbiters: Lazy<Vec<AccountId>>,
+
+        // The dispute of each disputed `ProposedApproval`.
+        disputes: Mapping<ContributionId, Dispute>,
+
+        // The number of arbiter draws, salting the next one.
+        draw_nonce: Lazy<u64>,
+
+        // The nonces of the redeemed vouchers.
+        redeemed_nonces: Mapping<u64, ()>,
+
+        // The off-chain verifier key signing identity proofs.
+        verifier: Lazy<Option<EcdsaPublicKey>>,
+
+        // The expiry of the account age attestation of each contributor.
+        age_attestations: Mapping<AccountId, Timestamp>,
+
+        // The shared `IdentityRegistry` contract superseding `identities`, if any.
+        identity_registry: Lazy<Option<AccountId>>,
+
+        // The `RewardVault` contract holding the rewards, if any.
+        reward_vault: Lazy<Option<AccountId>>,
+
+        // The `RewardStrategy` contract computing the rewards on approval, if any.
+        reward_strategy: Lazy<Option<AccountId>>,
+
+        // The conversion of rewards denominated in USD cents, if any.
+        usd_pricing: Lazy<Option<UsdPricing>>,
+
+        // The account scoring the difficulty of contributions, if any.
+        difficulty_oracle: Lazy<Option<AccountId>>,
+
+        // The difficulty score of each `Contribution` pushed by the oracle.
+        difficulties: Mapping<ContributionId, u8>,
+
+        // The PSP34 booster collection and the multiplier (in basis points) of the claims of
+        // its holders, if any.
+        booster: Lazy<Option<(AccountId, u16)>>,
+
+        // The number of approvals reaching each contributor level, in ascending order.
+        level_thresholds: Lazy<Vec<u32>>,
+
+        // The highest level reached by each contributor.
+        levels: Mapping<AccountId, u8>,
+
+        // The `OnContributionApproved` contracts notified of approvals.
+        subscribers: Lazy<Vec<AccountId>>,
+    }
+
+    /// The runtime calls made by the contract, encoded as in the runtime `RuntimeCall`.
+    #[cfg(feature = "call-runtime")]
+    #[derive(scale::Encode)]
+    enum RuntimeCall {
+        #[codec(index = 0)]
+        System(SystemCall),
+    }
+
+    #[cfg(feature = "call-runtime")]
+    #[derive(scale::Encode)]
+    enum SystemCall {
+        #[codec(index = 7)]
+        RemarkWithEvent { remark: Vec<u8> },
+    }
+
+    /// Emitted when a contributor identity is bound to an account.
+    #[ink(event)]
+    pub struct IdentityRegistered {
+        seq: u64,
+        contributor_id: ContributorId,
+        caller: AccountId,
+    }
+
+    /// Emitted when a contributor opts in to (or out of) the payout of their rewards on
+    /// approval.
+    #[ink(event)]
+    pub struct AutoClaimUpdated {
+        seq: u64,
+        contributor_id: ContributorId,
+        enabled: bool,
+    }
+
+    /// Emitted when the verifier attests the account age of a contributor.
+    #[ink(event)]
+    pub struct AccountAgeAttested {
+        seq: u64,
+        #[ink(topic)]
+        account: AccountId,
+        min_account_age: u32,
+        expires_at: Timestamp,
+    }
+
+    /// Emitted when a contributor links a DID to their account, pending its verification.
+    #[ink(event)]
+    pub struct DidLinked {
+        seq: u64,
+        #[ink(topic)]
+        account: AccountId,
+        uri: String,
+    }
+
+    /// Emitted when the owner attests that a contributor controls their linked DID.
+    #[ink(event)]
+    pub struct DidVerified {
+        seq: u64,
+        #[ink(topic)]
+        account: AccountId,
+    }
+
+    /// Emitted when a contributor changes the address their rewards are paid to.
+    #[ink(event)]
+    pub struct PayoutAddressChanged {
+        seq: u64,
+        account: AccountId,
+        payout: AccountId,
+        effective_at: BlockNumber,
+    }
+
+    /// Emitted when an identity registers an account that was the payout address of
+    /// `other`, flagging it for manual review.
+    #[ink(event)]
+    pub struct SuspectedDuplicate {
+        seq: u64,
+        #[ink(topic)]
+        account: AccountId,
+        contributor_id: ContributorId,
+        other: ContributorId,
+    }
+
+    /// Emitted when a suspected duplicate account is cleared by its manual review.
+    #[ink(event)]
+    pub struct SuspectedDuplicateCleared {
+        seq: u64,
+        #[ink(topic)]
+        account: AccountId,
+    }
+
+    /// Emitted when the handle behind a salted contributor identity is revealed.
+    #[ink(event)]
+    pub struct IdentityRevealed {
+        seq: u64,
+        contributor_id: ContributorId,
+        handle: BoundedString<MAX_HANDLE_LENGTH>,
+    }
+
+    /// Emitted when a `contribution` is approved.
+    #[ink(event)]
+    pub struct ContributionApproval {
+        #[ink(topic)]
+        project: ProjectId,
+        seq: u64,
+        id: ContributionId,
+        contributor: AccountId,
+    }
+
+    /// Emitted when the base URI of the metadata of the contributions is set.
+    #[ink(event)]
+    pub struct MetadataBaseUriUpdated {
+        seq: u64,
+        base_uri: String,
+    }
+
+    /// Emitted when the share of a maintainer in the fees of a project is set.
+    #[ink(event)]
+    pub struct MaintainerShareUpdated {
+        seq: u64,
+        #[ink(topic)]
+        project: ProjectId,
+        maintainer: AccountId,
+        bps: u16,
+    }
+
+    /// Emitted when fees are deposited for the maintainers of a project.
+    #[ink(event)]
+    pub struct ProjectFeesDeposited {
+        seq: u64,
+        #[ink(topic)]
+        project: ProjectId,
+        depositor: AccountId,
+        amount: Balance,
+    }
+
+    /// Emitted when the fees of a project are distributed to its maintainers, the `dust`
+    /// left being carried over to the next distribution.
+    #[ink(event)]
+    pub struct FeesDistributed {
+        seq: u64,
+        #[ink(topic)]
+        project: ProjectId,
+        amount: Balance,
+        dust: Balance,
+    }
+
+    /// Emitted when an approval is re-submitted for the same contributor.
+    #[ink(event)]
+    pub struct AlreadyApproved {
+        #[ink(topic)]
+        project: ProjectId,
+        seq: u64,
+        id: ContributionId,
+        contributor: AccountId,
+    }
+
+    /// Emitted when a session key is granted the right to approve contributions.
+    #[ink(event)]
+    pub struct SessionGranted {
+        seq: u64,
+        key: AccountId,
+        expires_at: BlockNumber,
+    }
+
+    /// Emitted when a session key is revoked.
+    #[ink(event)]
+    pub struct SessionRevoked {
+        seq: u64,
+        key: AccountId,
+    }
+
+    /// Emitted when a proxy contract is allowlisted (or not) to call as the owner.
+    #[ink(event)]
+    pub struct ProxyUpdated {
+        seq: u64,
+        #[ink(topic)]
+        proxy: AccountId,
+        allowed: bool,
+        requires_signer: bool,
+    }
+
+    /// Emitted when a role is permitted (or not) to call the message `selector`.
+    #[ink(event)]
+    pub struct PermissionSet {
+        seq: u64,
+        role: RoleId,
+        selector: [u8; 4],
+        allowed: bool,
+    }
+
+    /// Emitted when a role is assigned to (or removed from) an account.
+    #[ink(event)]
+    pub struct RoleSet {
+        seq: u64,
+        #[ink(topic)]
+        account: AccountId,
+        role: Option<RoleId>,
+    }
+
+    /// Emitted when the owner sets or removes the localized message of an error.
+    #[ink(event)]
+    pub struct ErrorMessageUpdated {
+        seq: u64,
+        #[ink(topic)]
+        code: u32,
+        lang: LanguageCode,
+    }
+
+    /// Emitted when the reward settings are updated at once.
+    #[ink(event)]
+    pub struct ConfigUpdated {
+        seq: u64,
+        config: Config,
+    }
+
+    /// Emitted when a reward campaign is created.
+    #[ink(event)]
+    pub struct CampaignCreated {
+        seq: u64,
+        id: CampaignId,
+        start: BlockNumber,
+        end: BlockNumber,
+        budget: Balance,
+    }
+
+    /// Emitted when a campaign is finalized, freezing its summary.
+    #[ink(event)]
+    pub struct CampaignFinalized {
+        seq: u64,
+        #[ink(topic)]
+        id: CampaignId,
+        summary: CampaignSummary,
+    }
+
+    /// Emitted when an account is added to or removed from the allow list.
+    #[ink(event)]
+    pub struct AllowListUpdated {
+        seq: u64,
+        account: AccountId,
+        allowed: bool,
+    }
+
+    /// Emitted when the owner shifts the sandbox clock.
+    #[ink(event)]
+    pub struct TimeOffsetUpdated {
+        seq: u64,
+        blocks: BlockNumber,
+        millis: Timestamp,
+    }
+
+    /// Emitted when a sponsor is added to or removed from the sponsor allow list.
+    #[ink(event)]
+    pub struct SponsorAllowListUpdated {
+        seq: u64,
+        #[ink(topic)]
+        account: AccountId,
+        allowed: bool,
+    }
+
+    /// Emitted when an account is allowed (or disallowed) to be bound to many identities.
+    #[ink(event)]
+    pub struct SharedAccountUpdated {
+        seq: u64,
+        account: AccountId,
+        shared: bool,
+    }
+
+    /// Emitted when a contribution is linked to another one.
+    #[ink(event)]
+    pub struct ContributionLinked {
+        seq: u64,
+        id: ContributionId,
+        relation: Relation,
+        target: ContributionId,
+    }
+
+    /// Emitted when a contribution is submitted by its contributor.
+    #[ink(event)]
+    pub struct ContributionSubmitted {
+        seq: u64,
+        id: ContributionId,
+        contributor: AccountId,
+        evidence: HashValue,
+    }
+
+    /// Emitted when a submitted contribution is rejected.
+    #[ink(event)]
+    pub struct SubmissionRejected {
+        seq: u64,
+        id: ContributionId,
+    }
+
+    /// Emitted when an approver declines a contribution, for a given reason.
+    #[ink(event)]
+    pub struct ContributionRejected {
+        seq: u64,
+        #[ink(topic)]
+        id: ContributionId,
+        reason_code: u32,
+    }
+
+    /// Emitted when a contribution is endorsed by a peer, increasing its reward by `bonus`.
+    #[ink(event)]
+    pub struct ContributionEndorsed {
+        seq: u64,
+        id: ContributionId,
+        endorser: AccountId,
+        bonus: Balance,
+    }
+
+    /// Emitted when an approver reviews a contribution, with the `current` number of reviews
+    /// out of the `required` quorum, so the bot can report the progress on the pull request.
+    #[ink(event)]
+    pub struct EndorsementRecorded {
+        seq: u64,
+        #[ink(topic)]
+        id: ContributionId,
+        approver: AccountId,
+        contributor: AccountId,
+        current: u32,
+        required: u32,
+    }
+
+    /// Emitted when approvers review a contribution for different contributors, which stops
+    /// its approval by quorum until the conflict is resolved.
+    #[ink(event)]
+    pub struct ConflictDetected {
+        seq: u64,
+        #[ink(topic)]
+        id: ContributionId,
+        candidates: Vec<(AccountId, u32)>,
+    }
+
+    /// Emitted when the owner or an arbiter resolves conflicting reviews for a `winner`.
+    #[ink(event)]
+    pub struct ConflictResolved {
+        seq: u64,
+        #[ink(topic)]
+        id: ContributionId,
+        resolver: AccountId,
+        winner: AccountId,
+    }
+
+    /// Emitted when an account is added to or removed from the deny list.
+    #[ink(event)]
+    pub struct DenyListUpdated {
+        seq: u64,
+        account: AccountId,
+        denied: bool,
+    }
+
+    /// Emitted when a contributor is banned, with a reason code.
+    #[ink(event)]
+    pub struct ContributorBanned {
+        seq: u64,
+        account: AccountId,
+        reason: u8,
+    }
+
+    /// Emitted when a contributor is unbanned.
+    #[ink(event)]
+    pub struct ContributorUnbanned {
+        seq: u64,
+        account: AccountId,
+    }
+
+    /// Emitted when the streak of a contributor changes.
+    #[ink(event)]
+    pub struct StreakUpdated {
+        seq: u64,
+        account: AccountId,
+        streak: u32,
+    }
+
+    /// Emitted when a `contribution` is claimed.
+    #[ink(event)]
+    pub struct ContributionClaim {
+        #[ink(topic)]
+        project: ProjectId,
+        seq: u64,
+        id: ContributionId,
+        contributor: AccountId,
+        receipt: HashValue,
+    }
+
+    /// Emitted when an approval of a `contribution` is proposed optimistically.
+    #[ink(event)]
+    pub struct ApprovalProposed {
+        seq: u64,
+        id: ContributionId,
+        proposer: AccountId,
+        contributor: AccountId,
+        stake: Balance,
+    }
+
+    /// Emitted when a proposed approval is challenged and its stake slashed.
+    #[ink(event)]
+    pub struct ApprovalChallenged {
+        seq: u64,
+        id: ContributionId,
+        stake: Balance,
+    }
+
+    /// Emitted when a proposed approval is disputed and an arbiter assigned.
+    #[ink(event)]
+    pub struct DisputeRaised {
+        seq: u64,
+        id: ContributionId,
+        arbiter: AccountId,
+    }
+
+    /// Emitted when a dispute is resolved, upholding or rejecting the proposed approval.
+    #[ink(event)]
+    pub struct DisputeResolved {
+        seq: u64,
+        id: ContributionId,
+        resolver: AccountId,
+        upheld: bool,
+    }
+
+    /// Emitted when the unclaimed reward of an expired `contribution` returns to its campaign.
+    #[ink(event)]
+    pub struct ContributionExpired {
+        seq: u64,
+        id: ContributionId,
+        amount: Balance,
+    }
+
+    /// Emitted when the claim deadline of an unclaimed `contribution` is approaching.
+    #[ink(event)]
+    pub struct ClaimExpiringSoon {
+        seq: u64,
+        id: ContributionId,
+        deadline: BlockNumber,
+    }
+
+    /// Emitted when a referrer earns a bonus for the first approved contribution of a referee.
+    #[ink(event)]
+    pub struct ReferralRewarded {
+        seq: u64,
+        referrer: AccountId,
+        referee: AccountId,
+        amount: Balance,
+    }
+
+    /// Emitted when a settled `contribution` is archived.
+    #[ink(event)]
+    pub struct ContributionArchived {
+        seq: u64,
+        id: ContributionId,
+        archive_root: HashValue,
+    }
+
+    /// Emitted when a sweep of the contract balance is scheduled.
+    #[ink(event)]
+    pub struct SweepScheduled {
+        seq: u64,
+        to: AccountId,
+        executable_at: Timestamp,
+    }
+
+    /// Emitted when the scheduled sweep is executed.
+    #[ink(event)]
+    pub struct SweepExecuted {
+        seq: u64,
+        to: AccountId,
+        amount: Balance,
+    }
+
+    /// Emitted when the scheduled sweep is cancelled.
+    #[ink(event)]
+    pub struct SweepCancelled {
+        seq: u64,
+        to: AccountId,
+    }
+
+    /// Emitted when the airdrop of a GitHub identity is claimed.
+    #[ink(event)]
+    pub struct AirdropClaimed {
+        seq: u64,
+        account: AccountId,
+        amount: Balance,
+    }
+
+    /// Emitted when a contributor reaches a new `level`.
+    #[ink(event)]
+    pub struct LevelUp {
+        seq: u64,
+        #[ink(topic)]
+        account: AccountId,
+        level: u8,
+    }
+
+    /// Emitted when a tip is attached to a `contribution`.
+    #[ink(event)]
+    pub struct ContributionTipped {
+        seq: u64,
+        #[ink(topic)]
+        id: ContributionId,
+        tipper: AccountId,
+        amount: Balance,
+    }
+
+    /// Emitted when a sponsor funds the reward of a `contribution` with a tip, with the
+    /// `total` of their tips, so the sponsor is credited publicly.
+    #[ink(event)]
+    pub struct SponsoredBy {
+        seq: u64,
+        #[ink(topic)]
+        id: ContributionId,
+        #[ink(topic)]
+        sponsor: AccountId,
+        total: Balance,
+    }
+
+    /// Emitted when an account claims its intent to take over the ownership.
+    #[ink(event)]
+    pub struct OwnershipIntentClaimed {
+        seq: u64,
+        account: AccountId,
+    }
+
+    /// Emitted when the termination of the contract is scheduled.
+    #[ink(event)]
+    pub struct TerminationScheduled {
+        seq: u64,
+        beneficiary: AccountId,
+        executable_at: Timestamp,
+    }
+
+    /// Emitted when a governance proposal is created.
+    #[ink(event)]
+    pub struct ProposalCreated {
+        seq: u64,
+        id: ProposalId,
+        proposer: AccountId,
+    }
+
+    /// Emitted when an account votes on a governance proposal.
+    #[ink(event)]
+    pub struct Voted {
+        seq: u64,
+        id: ProposalId,
+        voter: AccountId,
+        support: bool,
+        weight: Balance,
+    }
+
+    /// Emitted when a governance proposal is executed.
+    #[ink(event)]
+    pub struct ProposalExecuted {
+        seq: u64,
+        id: ProposalId,
+    }
+
+    /// Emitted when a due action fails on `tick`, which goes on with the next ones.
+    #[ink(event)]
+    pub struct TaskFailed {
+        seq: u64,
+        task: Task,
+        error: DemoError,
+    }
+
+    /// Emitted when KUDOS points are minted (PSP22 `Transfer` event).
+    ///
+    /// Also emitted from the contract account on native claim payouts if enabled, which
+    /// indexers tracking KUDOS balances tell apart by their `from`.
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        value: Balance,
+    }
+
+    impl Demo {
+        /// Constructor that initializes an asset reward for a given workflow
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            ownable::Internal::_init_with_owner(&mut instance, Self::env().caller());
+            instance
+        }
+
+        /// Constructor that initializes the contract with the canonical algorithm hashing
+        /// identities, e.g. Keccak256 for handles hashed by Ethereum JS libraries.
+        #[ink(constructor)]
+        pub fn new_with_hash_algorithm(hash_algorithm: HashAlgorithm) -> Self {
+            let mut instance = Self::new();
+            instance.hash_algorithm.set(&hash_algorithm);
+            instance
+        }
+
+        /// Approve contribution made by many co-authors, which share the reward according to
+        /// their `splits` in basis points (summing up to 10000).
+        #[ink(message, payable)]
+        pub fn approve_with_splits(
+            &mut self,
+            contribution_id: ContributionId,
+            splits: Vec<(AccountId, u16)>,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("approve_with_splits"))?;
+            self.with_approval_deposit(|demo| {
+                demo._approve(contribution_id, splits, MAX_DIFFICULTY, None)
+            })
+        }
+
+        /// Approve contribution with a `difficulty` score (up to 100) weighting its reward
+        /// according to the reward mode.
+        #[ink(message, payable)]
+        pub fn approve_with_difficulty(
+            &mut self,
+            contribution_id: ContributionId,
+            contributor: AccountId,
+            difficulty: u8,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("approve_with_difficulty"))?;
+            self.with_approval_deposit(|demo| {
+                demo._approve(
+                    contribution_id,
+                    vec![(contributor, MAX_BPS)],
+                    difficulty,
+                    None,
+                )
+            })
+        }
+
+        /// Approve contribution, succeeding when it is already approved for the same
+        /// contributor so that retried workflow runs stay green.
+        ///
+        /// Still fails if the contribution is approved for another contributor.
+        #[ink(message, payable)]
+        pub fn approve_idempotent(
+            &mut self,
+            contribution_id: ContributionId,
+            contributor: AccountId,
+        ) -> Result<(), DemoError> {
+            self.ensure_approver(ink::selector_bytes!("approve_idempotent"))?;
+            self.with_approval_deposit(|demo| match demo.contributions.get(contribution_id) {
+                Some(contribution) if contribution.contributor == contributor => {
+                    let seq = demo.next_seq();
+                    demo.env().emit_event(AlreadyApproved {
+                        project: demo.get_project_id(),
+                        seq,
+                        id: contribution_id,
+                        contributor,
+                    });
+                    Ok(())
+                }
+                _ => demo._approve(
+                    contribution_id,
+                    vec![(contributor, MAX_BPS)],
+                    MAX_DIFFICULTY,
+                    None,
+                ),
+            })
+        }
+
+        /// Bind `account` to a contributor identity and approve contribution for it at once,
+        /// so onboarding takes a single call.
+        ///
+        /// Nothing is bound nor approved if either half fails.
+        #[ink(message, payable)]
+        pub fn approve_and_register(
+            &mut self,
+            contribution_id: ContributionId,
+            contributor_id: ContributorId,
+            account: AccountId,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("approve_and_register"))?;
+            self.ensure_approvable(contribution_id, account)?;
+            self._register_identity(contributor_id, account)?;
+            self.with_approval_deposit(|demo| {
+                demo._approve(
+                    contribution_id,
+                    vec![(account, MAX_BPS)],
+                    MAX_DIFFICULTY,
+                    None,
+                )
+            })
+        }
+
+        /// Grant a session key the right to `approve` contributions, and nothing else, until a
+        /// given block (included).
+        ///
+        /// The workflow bot signs with session keys, so a leaked key expires on its own.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn grant_session(
+            &mut self,
+            key: AccountId,
+            expires_at: BlockNumber,
+        ) -> Result<(), DemoError> {
+            if expires_at < self.block_number() {
+                return Err(DemoError::InvalidSessionExpiry);
+            }
+            self.sessions.insert(key, &expires_at);
+            let seq = self.next_seq();
+            self.env().emit_event(SessionGranted {
+                seq,
+                key,
+                expires_at,
+            });
+            Ok(())
+        }
+
+        /// Revoke a session key before its expiry.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn revoke_session(&mut self, key: AccountId) -> Result<(), DemoError> {
+            self.sessions.remove(key);
+            let seq = self.next_seq();
+            self.env().emit_event(SessionRevoked { seq, key });
+            Ok(())
+        }
+
+        /// Simply returns the last block a given session key can approve contributions at.
+        #[ink(message)]
+        pub fn session_expiry(&self, key: AccountId) -> Option<BlockNumber> {
+            self.sessions.get(key)
+        }
+
+        /// Allowlist (or not) a proxy or multisig contract calling on behalf of a team.
+        ///
+        /// Its calls are treated as the owner's, for the messages a role can be permitted to
+        /// call and `approve`, unless it `requires_signer`: it then only approves through
+        /// `approve_forwarded`, forwarding an original signer who can approve.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_proxy(
+            &mut self,
+            proxy: AccountId,
+            allowed: bool,
+            requires_signer: bool,
+        ) -> Result<(), DemoError> {
+            if allowed {
+                self.proxies.insert(proxy, &requires_signer);
+            } else {
+                self.proxies.remove(proxy);
+            }
+            let seq = self.next_seq();
+            self.env().emit_event(ProxyUpdated {
+                seq,
+                proxy,
+                allowed,
+                requires_signer,
+            });
+            Ok(())
+        }
+
+        /// Returns whether a given allowlisted proxy must forward the original signer, `None`
+        /// if it is not allowlisted.
+        #[ink(message)]
+        pub fn get_proxy(&self, proxy: AccountId) -> Option<bool> {
+            self.proxies.get(proxy)
+        }
+
+        /// Approve contribution on behalf of the original `signer` of the call, forwarded by
+        /// an allowlisted proxy which is trusted to have authenticated them.
+        ///
+        /// The signer must be the owner or hold a live session key, and the proxy attaches the
+        /// storage deposit of the approval like `approve` callers do.
+        #[ink(message, payable)]
+        pub fn approve_forwarded(
+            &mut self,
+            signer: AccountId,
+            contribution_id: ContributionId,
+            contributor: AccountId,
+        ) -> Result<(), DemoError> {
+            if !self.proxies.contains(self.env().caller()) {
+                return Err(DemoError::CallerIsNotProxy);
+            }
+            self.ensure_can_approve(signer)?;
+            // The approval checks below only see the proxy as the approver
+            self.ensure_not_self_approval(signer, &[(contributor, MAX_BPS)])?;
+            self.with_approval_deposit(|demo| {
+                demo._approve(
+                    contribution_id,
+                    vec![(contributor, MAX_BPS)],
+                    MAX_DIFFICULTY,
+                    None,
+                )
+            })
+        }
+
+        /// Permit (or forbid) a role to call the owner message of a given `selector`.
+        ///
+        /// The selector is the one the message is dispatched at, derived from its name
+        /// qualified by its trait for the trait messages: `Workflow::approve` is permitted with
+        /// `selectors::workflow::APPROVE`.
+        ///
+        /// Ownership, termination, sessions, governance and permissions stay with the owner.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_permission(
+            &mut self,
+            role: RoleId,
+            selector: [u8; 4],
+            allowed: bool,
+        ) -> Result<(), DemoError> {
+            self._set_permission(role, selector, allowed);
+            Ok(())
+        }
+
+        /// Assign a role to an account, or remove its role with `None`.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_role(
+            &mut self,
+            account: AccountId,
+            role: Option<RoleId>,
+        ) -> Result<(), DemoError> {
+            self._set_role(account, role);
+            Ok(())
+        }
+
+        /// Simply returns the role of a given account, if any.
+        #[ink(message)]
+        pub fn role_of(&self, account: AccountId) -> Option<RoleId> {
+            self.roles.get(account)
+        }
+
+        /// Returns `true` if a given role is permitted to call the message `selector`.
+        #[ink(message)]
+        pub fn is_permitted(&self, role: RoleId, selector: [u8; 4]) -> bool {
+            self.permissions.contains((role, selector))
+        }
+
+        /// Returns `true` if `account` can call the owner message `selector`: it is the owner or
+        /// its role is permitted to.
+        #[ink(message)]
+        pub fn can_call(&self, account: AccountId, selector: [u8; 4]) -> bool {
+            Some(account) == self.owner()
+                || self.proxies.get(account) == Some(false)
+                || self
+                    .role_of(account)
+                    .map_or(false, |role| self.is_permitted(role, selector))
+        }
+
+        /// Approve contribution with the next `nonce` of the caller, so that a captured call
+        /// cannot be replayed.
+        #[ink(message, payable)]
+        pub fn approve_with_nonce(
+            &mut self,
+            contribution_id: ContributionId,
+            contributor: AccountId,
+            nonce: u64,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("approve_with_nonce"))?;
+            let caller = self.env().caller();
+            if nonce != self.nonce_of(caller) {
+                return Err(DemoError::InvalidNonce);
+            }
+            self.approval_nonces.insert(caller, &(nonce + 1));
+            self.with_approval_deposit(|demo| {
+                demo._approve(
+                    contribution_id,
+                    vec![(contributor, MAX_BPS)],
+                    MAX_DIFFICULTY,
+                    None,
+                )
+            })
+        }
+
+        /// Simply returns the next approval nonce of a given approver.
+        #[ink(message)]
+        pub fn nonce_of(&self, approver: AccountId) -> u64 {
+            self.approval_nonces.get(approver).unwrap_or_default()
+        }
+
+        /// Approve contribution whose reward must be claimed until the `deadline` block
+        /// (included). Past it, the unclaimed reward can be returned to its campaign budget.
+        #[ink(message, payable)]
+        pub fn approve_with_deadline(
+            &mut self,
+            contribution_id: ContributionId,
+            contributor: AccountId,
+            deadline: BlockNumber,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("approve_with_deadline"))?;
+            if deadline < self.block_number() {
+                return Err(DemoError::InvalidDeadline);
+            }
+            self.with_approval_deposit(|demo| {
+                demo._approve(
+                    contribution_id,
+                    vec![(contributor, MAX_BPS)],
+                    MAX_DIFFICULTY,
+                    None,
+                )
+            })?;
+
+            if let Some(mut contribution) = self.contributions.get(contribution_id) {
+                contribution.deadline = Some(deadline);
+                self.contributions.insert(contribution_id, &contribution);
+            }
+            Ok(())
+        }
+
+        /// Approve the contiguous range of contributions from `start_id` to `end_id` (both
+        /// included, at most `MAX_PAGE_SIZE`) for a contributor.
+        ///
+        /// Contributions already approved or archived are skipped. Returns the number of
+        /// approved contributions.
+        #[ink(message, payable)]
+        pub fn approve_range(
+            &mut self,
+            start_id: ContributionId,
+            end_id: ContributionId,
+            contributor: AccountId,
+        ) -> Result<u32, DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("approve_range"))?;
+            if start_id > end_id {
+                return Err(DemoError::InvalidRange);
+            }
+            if end_id - start_id >= MAX_PAGE_SIZE.into() {
+                return Err(DemoError::TooManyContributions);
+            }
+            self.with_approval_deposit(|demo| {
+                let mut approved = 0;
+                for contribution_id in start_id..=end_id {
+                    if demo.contributions.contains(contribution_id)
+                        || demo.is_archived(contribution_id)
+                    {
+                        continue;
+                    }
+                    demo._approve(
+                        contribution_id,
+                        vec![(contributor, MAX_BPS)],
+                        MAX_DIFFICULTY,
+                        None,
+                    )?;
+                    approved += 1;
+                }
+                Ok(approved)
+            })
+        }
+
+        /// Approve a batch of (`contribution_id`, `contributor`) pairs (at most
+        /// `MAX_PAGE_SIZE`) on a best-effort basis: a failed approval writes nothing, and does
+        /// not revert the batch.
+        ///
+        /// The batch stops early once less than `min_gas_left` gas is left, zero never stops it.
+        /// The storage deposit limit cannot be read by the contract, so a batch exceeding it
+        /// still reverts: split it instead. Returns the result of each processed approval, in
+        /// order, so the caller resumes from the first unprocessed one.
+        #[ink(message, payable)]
+        pub fn approve_batch(
+            &mut self,
+            approvals: Vec<(ContributionId, AccountId)>,
+            min_gas_left: u64,
+        ) -> Result<Vec<Result<(), DemoError>>, DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("approve_batch"))?;
+            if approvals.len() > MAX_PAGE_SIZE as usize {
+                return Err(DemoError::TooManyContributions);
+            }
+            self.with_approval_deposit(|demo| {
+                let mut results = Vec::with_capacity(approvals.len());
+                for (contribution_id, contributor) in approvals {
+                    if min_gas_left > 0 && demo.env().gas_left() < min_gas_left {
+                        break;
+                    }
+                    results.push(demo._approve(
+                        contribution_id,
+                        vec![(contributor, MAX_BPS)],
+                        MAX_DIFFICULTY,
+                        None,
+                    ));
+                }
+                Ok(results)
+            })
+        }
+
+        /// Approve contribution with up to 5 short `tags` (e.g. its topics), so that
+        /// contributions can be filtered by tag.
+        #[ink(message, payable)]
+        pub fn approve_with_tags(
+            &mut self,
+            contribution_id: ContributionId,
+            contributor: AccountId,
+            tags: Vec<String>,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("approve_with_tags"))?;
+            let bounded_tags = tags
+                .iter()
+                .map(|tag| BoundedString::try_from(tag.as_str()))
+                .collect::<Result<Vec<_>, _>>()
+                .and_then(BoundedVec::try_from)
+                .map_err(|_| DemoError::InvalidTags)?;
+            for (index, tag) in tags.iter().enumerate() {
+                if tag.is_empty() || tags[..index].contains(tag) {
+                    return Err(DemoError::InvalidTags);
+                }
+            }
+            self.with_approval_deposit(|demo| {
+                demo._approve(
+                    contribution_id,
+                    vec![(contributor, MAX_BPS)],
+                    MAX_DIFFICULTY,
+                    None,
+                )
+            })?;
+
+            for tag in tags.iter() {
+                let count = self.tag_counts.get(tag).unwrap_or_default();
+                self.tagged.insert((tag.clone(), count), &contribution_id);
+                self.tag_counts.insert(tag, &(count + 1));
+            }
+            self.tags.insert(contribution_id, &bounded_tags);
+            Ok(())
+        }
+
+        /// Approve contribution with the Blake2x256 hash of its memo (e.g. the pull request
+        /// title), so personal data stays off-chain while the memo remains verifiable.
+        #[ink(message, payable)]
+        pub fn approve_with_memo(
+            &mut self,
+            contribution_id: ContributionId,
+            contributor: AccountId,
+            memo_hash: HashValue,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("approve_with_memo"))?;
+            self.with_approval_deposit(|demo| {
+                demo._approve(
+                    contribution_id,
+                    vec![(contributor, MAX_BPS)],
+                    MAX_DIFFICULTY,
+                    None,
+                )
+            })?;
+            self.memos.insert(contribution_id, &memo_hash);
+            Ok(())
+        }
+
+        /// Returns `true` if `memo` is the preimage of the memo hash of a given contribution.
+        #[ink(message)]
+        pub fn verify_memo(&self, contribution_id: ContributionId, memo: String) -> bool {
+            self.memos.get(contribution_id)
+                == Some(self.env().hash_bytes::<Blake2x256>(memo.as_bytes()))
+        }
+
+        /// Approve contribution with the SHA of its merge commit, so anyone can cross-check
+        /// the approval with the history of the repository.
+        #[ink(message, payable)]
+        pub fn approve_with_merge_commit(
+            &mut self,
+            contribution_id: ContributionId,
+            contributor: AccountId,
+            sha: Vec<u8>,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("approve_with_merge_commit"))?;
+            if !COMMIT_SHA_LENGTHS.contains(&sha.len()) {
+                return Err(DemoError::InvalidCommitSha);
+            }
+            let sha: BoundedVec<u8, 32> =
+                BoundedVec::try_from(sha).map_err(|_| DemoError::InvalidCommitSha)?;
+            self.with_approval_deposit(|demo| {
+                demo._approve(
+                    contribution_id,
+                    vec![(contributor, MAX_BPS)],
+                    MAX_DIFFICULTY,
+                    None,
+                )
+            })?;
+            self.merge_commits.insert(contribution_id, &sha);
+            Ok(())
+        }
+
+        /// Returns `true` if `sha` is the merge commit SHA of a given contribution.
+        #[ink(message)]
+        pub fn verify_merge_commit(&self, contribution_id: ContributionId, sha: Vec<u8>) -> bool {
+            self.merge_commit_of(contribution_id) == Some(sha)
+        }
+
+        /// Simply returns the merge commit SHA of a given contribution.
+        #[ink(message)]
+        pub fn merge_commit_of(&self, contribution_id: ContributionId) -> Option<Vec<u8>> {
+            self.merge_commits.get(contribution_id).map(Vec::from)
+        }
+
+        /// Approve contribution with a memo encrypted off-chain to the encryption public key of
+        /// the contributor, so the details of a private bounty are not world-readable.
+        #[ink(message, payable)]
+        pub fn approve_with_encrypted_memo(
+            &mut self,
+            contribution_id: ContributionId,
+            contributor: AccountId,
+            encrypted_memo: Vec<u8>,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("approve_with_encrypted_memo"))?;
+            if encrypted_memo.is_empty() {
+                return Err(DemoError::InvalidMemo);
+            }
+            let encrypted_memo: BoundedVec<u8, MAX_ENCRYPTED_MEMO_LENGTH> =
+                BoundedVec::try_from(encrypted_memo).map_err(|_| DemoError::InvalidMemo)?;
+            if !self.encryption_pubkeys.contains(contributor) {
+                return Err(DemoError::EncryptionKeyUnset);
+            }
+            self.with_approval_deposit(|demo| {
+                demo._approve(
+                    contribution_id,
+                    vec![(contributor, MAX_BPS)],
+                    MAX_DIFFICULTY,
+                    None,
+                )
+            })?;
+            self.encrypted_memos
+                .insert(contribution_id, &encrypted_memo);
+            Ok(())
+        }
+
+        /// Simply returns the encrypted memo of a given contribution.
+        #[ink(message)]
+        pub fn encrypted_memo_of(&self, contribution_id: ContributionId) -> Option<Vec<u8>> {
+            self.encrypted_memos.get(contribution_id).map(Vec::from)
+        }
+
+        /// Submit a contribution of the caller with the hash of its evidence, pending its
+        /// approval.
+        #[ink(message, selector = 0x8EAC2BAF)]
+        pub fn submit(
+            &mut self,
+            contribution_id: ContributionId,
+            evidence_hash: HashValue,
+        ) -> Result<(), DemoError> {
+            let caller = self.env().caller();
+            self.ensure_approvable(contribution_id, caller)?;
+            if self.submissions.contains(contribution_id) {
+                return Err(DemoError::AlreadySubmitted);
+            }
+            transition(self.status_of(contribution_id), Action::Submit)?;
+            self.submissions.insert(
+                contribution_id,
+                &Submission {
+                    contributor: caller,
+                    evidence: evidence_hash,
+                    submitted_at: self.block_number(),
+                },
+            );
+            let seq = self.next_seq();
+            self.env().emit_event(ContributionSubmitted {
+                seq,
+                id: contribution_id,
+                contributor: caller,
+                evidence: evidence_hash,
+            });
+            Ok(())
+        }
+
+        /// Approve a submitted contribution, keeping its evidence hash.
+        #[ink(message, payable)]
+        pub fn approve_submission(
+            &mut self,
+            contribution_id: ContributionId,
+        ) -> Result<(), DemoError> {
+            self.ensure_approver(ink::selector_bytes!("approve_submission"))?;
+            let submission = self
+                .submissions
+                .get(contribution_id)
+                .ok_or(DemoError::UnknownSubmission)?;
+            self.with_approval_deposit(|demo| {
+                demo._approve(
+                    contribution_id,
+                    vec![(submission.contributor, MAX_BPS)],
+                    MAX_DIFFICULTY,
+                    None,
+                )
+            })?;
+            self.evidences.insert(contribution_id, &submission.evidence);
+            Ok(())
+        }
+
+        /// Reject a submitted contribution, so it can be submitted again.
+        #[ink(message)]
+        pub fn reject_submission(
+            &mut self,
+            contribution_id: ContributionId,
+        ) -> Result<(), DemoError> {
+            self.ensure_approver(ink::selector_bytes!("reject_submission"))?;
+            transition(self.status_of(contribution_id), Action::Reject)
+                .map_err(|_| DemoError::UnknownSubmission)?;
+            self.submissions.remove(contribution_id);
+            let seq = self.next_seq();
+            self.env().emit_event(SubmissionRejected {
+                seq,
+                id: contribution_id,
+            });
+            Ok(())
+        }
+
+        /// Record that a contribution, submitted or pending its review, is declined for a given
+        /// reason code, so the bot posts a definitive status on the pull request.
+        ///
+        /// The pending submission and reviews of the contribution are dropped. It can still be
+        /// approved later, which clears the rejection.
+        #[ink(message)]
+        pub fn reject(
+            &mut self,
+            contribution_id: ContributionId,
+            reason_code: u32,
+        ) -> Result<(), DemoError> {
+            self.ensure_approver(ink::selector_bytes!("reject"))?;
+            match self.status_of(contribution_id) {
+                Status::Unknown => {}
+                status => {
+                    transition(status, Action::Reject)?;
+                }
+            }
+
+            self.submissions.remove(contribution_id);
+            self.review_tallies.remove(contribution_id);
+            self.conflicts.remove(contribution_id);
+            self.rejections.insert(
+                contribution_id,
+                &Rejection {
+                    reason_code,
+                    rejected_by: self.env().caller(),
+                    rejected_at: self.block_number(),
+                },
+            );
+            let seq = self.next_seq();
+            self.env().emit_event(ContributionRejected {
+                seq,
+                id: contribution_id,
+                reason_code,
+            });
+            Ok(())
+        }
+
+        /// Simply returns the rejection of a given contribution, if declined.
+        #[ink(message)]
+        pub fn rejection_of(&self, contribution_id: ContributionId) -> Option<Rejection> {
+            self.rejections.get(contribution_id)
+        }
+
+        /// Simply returns the submission of a given contribution pending its approval.
+        #[ink(message)]
+        pub fn get_submission(&self, contribution_id: ContributionId) -> Option<Submission> {
+            self.submissions.get(contribution_id)
+        }
+
+        /// Simply returns the evidence hash of a given contribution approved from a submission.
+        #[ink(message)]
+        pub fn evidence_of(&self, contribution_id: ContributionId) -> Option<HashValue> {
+            self.evidences.get(contribution_id)
+        }
+
+        /// Endorse a contribution of a peer, increasing its reward by the endorsement bonus
+        /// drawn from the budget of its campaign.
+        ///
+        /// Only registered contributors endorse, once per contribution and before its first
+        /// claim.
+        #[ink(message)]
+        pub fn endorse(&mut self, contribution_id: ContributionId) -> Result<(), DemoError> {
+            let caller = self.env().caller();
+            if self.identity_of(caller).is_none() {
+                return Err(DemoError::IdentityNotRegistered);
+            }
+            let mut contribution = self
+                .contributions
+                .get(contribution_id)
+                .ok_or(DemoError::UnknownContribution)?;
+            if contribution.split_of(caller).is_some() {
+                return Err(DemoError::CallerIsContributor);
+            }
+            if contribution.splits.iter().any(|split| {
+                split.is_claimed || self.claimed_amount(contribution_id, split.account) > 0
+            }) {
+                return Err(DemoError::AlreadyClaimed);
+            }
+            if self.has_endorsed(contribution_id, caller) {
+                return Err(DemoError::AlreadyEndorsed);
+            }
+            let count = self.endorsements_of(contribution_id);
+            if count >= self.config().max_endorsements {
+                return Err(DemoError::EndorsementCapReached);
+            }
+
+            let mut bonus = 0;
+            if let Some(campaign_id) = self.open_campaign_of(&contribution) {
+                if let Some(mut campaign) = self.campaigns.get(campaign_id) {
+                    bonus = self.config().endorsement_bonus.min(campaign.remaining);
+                    campaign.remaining -= bonus;
+                    self.campaigns.insert(campaign_id, &campaign);
+                }
+            }
+            contribution.reward = contribution.reward.saturating_add(bonus);
+            self.contributions.insert(contribution_id, &contribution);
+            if !self.is_in_usd(&contribution) {
+                self.owe_reward(contribution.asset, bonus);
+            }
+            self.endorsements.insert((contribution_id, caller), &());
+            self.endorsement_counts
+                .insert(contribution_id, &(count + 1));
+            let seq = self.next_seq();
+            self.env().emit_event(ContributionEndorsed {
+                seq,
+                id: contribution_id,
+                endorser: caller,
+                bonus,
+            });
+            Ok(())
+        }
+
+        /// Simply returns the number of endorsements of a given contribution.
+        #[ink(message)]
+        pub fn endorsements_of(&self, contribution_id: ContributionId) -> u32 {
+            self.endorsement_counts
+                .get(contribution_id)
+                .unwrap_or_default()
+        }
+
+        /// Returns `true` if `account` endorsed a given contribution.
+        #[ink(message)]
+        pub fn has_endorsed(&self, contribution_id: ContributionId, account: AccountId) -> bool {
+            self.endorsements.contains((contribution_id, account))
+        }
+
+        /// Review a contribution of `contributor`, approving it once the review quorum is
+        /// reached.
+        ///
+        /// It can be called by the owner or a live session key, once per contribution. Reviews
+        /// for different contributors are tallied as a conflict, resolved by the owner or an
+        /// arbiter instead of the quorum.
+        #[ink(message, payable)]
+        pub fn review(
+            &mut self,
+            contribution_id: ContributionId,
+            contributor: AccountId,
+        ) -> Result<(), DemoError> {
+            let required = self.config().review_quorum;
+            if required == 0 {
+                return Err(DemoError::ReviewsDisabled);
+            }
+            self.ensure_approver(ink::selector_bytes!("review"))?;
+            self.with_approval_deposit(|demo| demo._review(contribution_id, contributor, required))
+        }
+
+        /// Resolve the conflicting reviews of a contribution, approving it for `winner`, one of
+        /// the reviewed contributors.
+        ///
+        /// It can be called by the owner or an arbiter of the pool.
+        #[ink(message, payable)]
+        pub fn resolve_conflict(
+            &mut self,
+            contribution_id: ContributionId,
+            winner: AccountId,
+        ) -> Result<(), DemoError> {
+            let candidates = self
+                .conflict_of(contribution_id)
+                .ok_or(DemoError::UnknownConflict)?;
+            let caller = self.env().caller();
+            if Some(caller) != self.owner() && !self.get_arbiters().contains(&caller) {
+                return Err(DemoError::CallerIsNotArbiter);
+            }
+            if !candidates.iter().any(|(candidate, _)| *candidate == winner) {
+                return Err(DemoError::ContributorMismatch);
+            }
+
+            self.conflicts.remove(contribution_id);
+            self.with_approval_deposit(|demo| {
+                demo._approve(
+                    contribution_id,
+                    vec![(winner, MAX_BPS)],
+                    MAX_DIFFICULTY,
+                    None,
+                )
+            })?;
+            let seq = self.next_seq();
+            self.env().emit_event(ConflictResolved {
+                seq,
+                id: contribution_id,
+                resolver: caller,
+                winner,
+            });
+            Ok(())
+        }
+
+        /// Simply returns the reviewed contributors and their number of reviews of a given
+        /// contribution pending the resolution of a conflict.
+        #[ink(message)]
+        pub fn conflict_of(
+            &self,
+            contribution_id: ContributionId,
+        ) -> Option<Vec<(AccountId, u32)>> {
+            self.conflicts.get(contribution_id)
+        }
+
+        /// Simply returns the contributor and number of reviews of a given contribution pending
+        /// its review quorum.
+        #[ink(message)]
+        pub fn reviews_of(&self, contribution_id: ContributionId) -> Option<(AccountId, u32)> {
+            self.review_tallies.get(contribution_id)
+        }
+
+        /// Returns the deterministic id of the contribution `number` of a given `kind` in a
+        /// repository: the first 8 bytes of the Blake2x256 hash of (`repo`, `kind`, `number`).
+        #[ink(message)]
+        pub fn hash_contribution(
+            &self,
+            repo: String,
+            kind: ContributionKind,
+            number: u64,
+        ) -> ContributionId {
+            let (contribution_id, _) = self.preimage_of(&repo, kind, number);
+            contribution_id
+        }
+
+        /// Approve the contribution `number` of a given `kind` in a repository, under its
+        /// deterministic id.
+        ///
+        /// Fails if another contribution was approved under the same id.
+        #[ink(message, payable)]
+        pub fn approve_with_preimage(
+            &mut self,
+            repo: String,
+            kind: ContributionKind,
+            number: u64,
+            contributor: AccountId,
+        ) -> Result<ContributionId, DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("approve_with_preimage"))?;
+            let (contribution_id, preimage) = self.preimage_of(&repo, kind, number);
+            match self.preimages.get(contribution_id) {
+                Some(other) if other != preimage => return Err(DemoError::ContributionIdCollision),
+                None if self.contributions.contains(contribution_id) => {
+                    return Err(DemoError::ContributionIdCollision)
+                }
+                _ => {}
+            }
+            self.with_approval_deposit(|demo| {
+                demo._approve(
+                    contribution_id,
+                    vec![(contributor, MAX_BPS)],
+                    MAX_DIFFICULTY,
+                    None,
+                )
+            })?;
+            self.preimages.insert(contribution_id, &preimage);
+            Ok(contribution_id)
+        }
+
+        /// Simply returns the tags of a given contribution.
+        #[ink(message)]
+        pub fn get_tags(&self, contribution_id: ContributionId) -> Vec<String> {
+            self.tags
+                .get(contribution_id)
+                .map(|tags| tags.iter().map(|tag| String::from(tag.as_str())).collect())
+                .unwrap_or_default()
+        }
+
+        /// Link a contribution to another one, e.g. to collapse duplicates, replacing its
+        /// previous link.
+        #[ink(message)]
+        pub fn link(
+            &mut self,
+            contribution_a: ContributionId,
+            contribution_b: ContributionId,
+            relation: Relation,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("link"))?;
+            if contribution_a == contribution_b {
+                return Err(DemoError::InvalidRelation);
+            }
+            let is_known = |id| self.contributions.contains(id) || self.is_archived(id);
+            if !is_known(contribution_a) || !is_known(contribution_b) {
+                return Err(DemoError::UnknownContribution);
+            }
+            self.unlink_from_target(contribution_a);
+            self.relations
+                .insert(contribution_a, &(relation, contribution_b));
+            let mut related = self.related_to(contribution_b);
+            related.push(contribution_a);
+            self.related.insert(contribution_b, &related);
+            let seq = self.next_seq();
+            self.env().emit_event(ContributionLinked {
+                seq,
+                id: contribution_a,
+                relation,
+                target: contribution_b,
+            });
+            Ok(())
+        }
+
+        /// Remove the link of a contribution.
+        #[ink(message)]
+        pub fn unlink(&mut self, contribution_id: ContributionId) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("unlink"))?;
+            self.unlink_from_target(contribution_id);
+            self.relations.remove(contribution_id);
+            Ok(())
+        }
+
+        /// Simply returns the contribution a given contribution relates to, and how.
+        #[ink(message)]
+        pub fn relation_of(
+            &self,
+            contribution_id: ContributionId,
+        ) -> Option<(Relation, ContributionId)> {
+            self.relations.get(contribution_id)
+        }
+
+        /// Simply returns the contributions relating to a given contribution.
+        #[ink(message)]
+        pub fn related_to(&self, contribution_id: ContributionId) -> Vec<ContributionId> {
+            self.related.get(contribution_id).unwrap_or_default()
+        }
+
+        /// Returns a page of the contributions tagged with `tag`, oldest first.
+        ///
+        /// At most 100 contributions are returned per page.
+        #[ink(message)]
+        pub fn contributions_by_tag(
+            &self,
+            tag: String,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<ContributionId> {
+            let count = self.tag_counts.get(&tag).unwrap_or_default();
+            let end = offset.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+            (offset..end)
+                .filter_map(|index| self.tagged.get((tag.clone(), index)))
+                .collect()
+        }
+
+        /// Returns a page of the ids of the contributions approved for the account bound to a
+        /// given contributor identity, so the bot looks them up without the account.
+        ///
+        /// Only contributions approved while the identity is bound are found. At most 100
+        /// contributions are returned per page.
+        #[ink(message)]
+        pub fn contributions_by_identity(
+            &self,
+            contributor_id: ContributorId,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<ContributionId> {
+            let count = self
+                .identity_contribution_counts
+                .get(contributor_id)
+                .unwrap_or_default();
+            let end = offset.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+            (offset..end)
+                .filter_map(|index| self.identity_contributions.get((contributor_id, index)))
+                .collect()
+        }
+
+        /// Record the contribution of the caller approved by a voucher: an ECDSA `signature` of
+        /// the blake2 hash of (contract, `voucher`) made by the owner.
+        ///
+        /// Contributors redeem vouchers themselves, so the workflow submits no extrinsic. The
+        /// reward of the voucher is drawn from the active campaign budget.
+        #[ink(message, payable)]
+        pub fn redeem_voucher(
+            &mut self,
+            voucher: Voucher,
+            signature: EcdsaSignature,
+        ) -> Result<(), DemoError> {
+            if voucher.contributor != self.env().caller() {
+                return Err(DemoError::CallerIsNotContributor);
+            }
+            if self.redeemed_nonces.contains(voucher.nonce) {
+                return Err(DemoError::VoucherAlreadyRedeemed);
+            }
+            let message_hash = self
+                .env()
+                .hash_encoded::<Blake2x256, _>(&(self.env().account_id(), &voucher));
+            let signer = self
+                .env()
+                .ecdsa_recover(&signature, &message_hash)
+                .map_err(|_| DemoError::InvalidSignature)?;
+            // Like substrate ECDSA accounts, the signer account is the hash of its public key
+            let signer_account = AccountId::from(self.env().hash_bytes::<Blake2x256>(&signer));
+            if Ownable::owner(self) != Some(signer_account) {
+                return Err(DemoError::InvalidSignature);
+            }
+
+            self.redeemed_nonces.insert(voucher.nonce, &());
+            self.with_approval_deposit(|demo| {
+                demo._approve(
+                    voucher.contribution_id,
+                    vec![(voucher.contributor, MAX_BPS)],
+                    MAX_DIFFICULTY,
+                    Some(voucher.amount),
+                )
+            })
+        }
+
+        /// Propose the approval of a contribution, staking the transferred value.
+        ///
+        /// The approval can be finalized by anyone once the challenge period is over, refunding
+        /// the stake, unless the owner challenges it in time, slashing the stake.
+        #[ink(message, payable)]
+        pub fn propose_approval(
+            &mut self,
+            contribution_id: ContributionId,
+            contributor: AccountId,
+        ) -> Result<(), DemoError> {
+            let config = self.config();
+            if config.challenge_period == 0 {
+                return Err(DemoError::OptimisticApprovalsDisabled);
+            }
+            let stake = self.env().transferred_value();
+            if stake < config.approval_stake {
+                return Err(DemoError::InsufficientStake);
+            }
+            if self.proposed_approvals.contains(contribution_id) {
+                return Err(DemoError::ApprovalAlreadyProposed);
+            }
+            self.ensure_approvable(contribution_id, contributor)?;
+            transition(self.status_of(contribution_id), Action::Propose)?;
+            let mut pending_approvals = self.pending_approvals.get_or_default();
+            if pending_approvals.len() >= MAX_PENDING_APPROVALS {
+                return Err(DemoError::TooManyPendingApprovals);
+            }
+            pending_approvals.push(contribution_id);
+            self.pending_approvals.set(&pending_approvals);
+
+            let proposer = self.env().caller();
+            self.proposed_approvals.insert(
+                contribution_id,
+                &ProposedApproval {
+                    proposer,
+                    contributor,
+                    stake,
+                    challenge_end: self.block_number().saturating_add(config.challenge_period),
+                },
+            );
+            self.owe(Asset::Native, stake);
+            self.record(Movement::Stake, stake);
+            let seq = self.next_seq();
+            self.env().emit_event(ApprovalProposed {
+                seq,
+                id: contribution_id,
+                proposer,
+                contributor,
+                stake,
+            });
+            Ok(())
+        }
+
+        /// Challenge a proposed approval within its challenge period, slashing its stake into
+        /// the reward pool.
+        #[ink(message)]
+        pub fn challenge(&mut self, contribution_id: ContributionId) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("challenge"))?;
+            let proposed = self
+                .proposed_approvals
+                .get(contribution_id)
+                .ok_or(DemoError::UnknownProposedApproval)?;
+            if Due::AfterBlock(proposed.challenge_end).is_due(self.now()) {
+                return Err(DemoError::ChallengePeriodOver);
+            }
+            transition(self.status_of(contribution_id), Action::Challenge)?;
+            self.disputes.remove(contribution_id);
+            self.reject_approval(contribution_id, proposed);
+            Ok(())
+        }
+
+        /// Dispute a proposed approval within its challenge period, assigning it to an arbiter
+        /// drawn from the pool.
+        ///
+        /// The draw hashes the block number and timestamp with a nonce: it is unpredictable to
+        /// the caller, not to the block author.
+        #[ink(message)]
+        pub fn dispute(&mut self, contribution_id: ContributionId) -> Result<AccountId, DemoError> {
+            let proposed = self
+                .proposed_approvals
+                .get(contribution_id)
+                .ok_or(DemoError::UnknownProposedApproval)?;
+            if Due::AfterBlock(proposed.challenge_end).is_due(self.now()) {
+                return Err(DemoError::ChallengePeriodOver);
+            }
+            if self.disputes.contains(contribution_id) {
+                return Err(DemoError::AlreadyDisputed);
+            }
+            transition(self.status_of(contribution_id), Action::Dispute)?;
+            let arbiters = self.get_arbiters();
+            if arbiters.is_empty() {
+                return Err(DemoError::NoArbiters);
+            }
+
+            let arbiter = arbiters[self.draw_index(arbiters.len())];
+            self.disputes.insert(
+                contribution_id,
+                &Dispute {
+                    arbiter,
+                    deadline: self.block_number().saturating_add(ARBITRATION_PERIOD),
+                },
+            );
+            let seq = self.next_seq();
+            self.env().emit_event(DisputeRaised {
+                seq,
+                id: contribution_id,
+                arbiter,
+            });
+            Ok(arbiter)
+        }
+
+        /// Resolve a dispute, approving the contribution if `uphold` or slashing the stake of the
+        /// proposer otherwise.
+        ///
+        /// Only the assigned arbiter can resolve it, or the owner once the arbiter timed out.
+        #[ink(message)]
+        #[modifiers(non_reentrant)]
+        pub fn resolve_dispute(
+            &mut self,
+            contribution_id: ContributionId,
+            uphold: bool,
+        ) -> Result<(), DemoError> {
+            let dispute = self
+                .disputes
+                .get(contribution_id)
+                .ok_or(DemoError::UnknownDispute)?;
+            let caller = self.env().caller();
+            let has_timed_out = Due::AfterBlock(dispute.deadline).is_due(self.now());
+            if caller != dispute.arbiter && !(has_timed_out && Some(caller) == self.owner()) {
+                return Err(DemoError::CallerIsNotArbiter);
+            }
+            let proposed = self
+                .proposed_approvals
+                .get(contribution_id)
+                .ok_or(DemoError::UnknownProposedApproval)?;
+            let action = if uphold {
+                Action::Uphold
+            } else {
+                Action::Overturn
+            };
+            transition(self.status_of(contribution_id), action)?;
+
+            self.disputes.remove(contribution_id);
+            if uphold {
+                self.accept_approval(contribution_id, proposed)?;
+            } else {
+                self.reject_approval(contribution_id, proposed);
+            }
+            let seq = self.next_seq();
+            self.env().emit_event(DisputeResolved {
+                seq,
+                id: contribution_id,
+                resolver: caller,
+                upheld: uphold,
+            });
+            Ok(())
+        }
+
+        /// Simply returns the dispute of a given proposed approval, if any.
+        #[ink(message)]
+        pub fn get_dispute(&self, contribution_id: ContributionId) -> Option<Dispute> {
+            self.disputes.get(contribution_id)
+        }
+
+        /// Add an account to the arbiter pool.
+        #[ink(message)]
+        pub fn add_arbiter(&mut self, arbiter: AccountId) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("add_arbiter"))?;
+            let mut arbiters = self.get_arbiters();
+            if arbiters.contains(&arbiter) {
+                return Ok(());
+            }
+            if arbiters.len() >= MAX_ARBITERS {
+                return Err(DemoError::TooManyArbiters);
+            }
+            arbiters.push(arbiter);
+            self.arbiters.set(&arbiters);
+            Ok(())
+        }
+
+        /// Remove an account from the arbiter pool, keeping its assigned disputes.
+        #[ink(message)]
+        pub fn remove_arbiter(&mut self, arbiter: AccountId) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("remove_arbiter"))?;
+            let mut arbiters = self.get_arbiters();
+            arbiters.retain(|account| *account != arbiter);
+            self.arbiters.set(&arbiters);
+            Ok(())
+        }
+
+        /// Simply returns the arbiter pool.
+        #[ink(message)]
+        pub fn get_arbiters(&self) -> Vec<AccountId> {
+            self.arbiters.get_or_default()
+        }
+
+        /// Approve an unchallenged proposed approval once its challenge period is over, and
+        /// refund the stake of its proposer.
+        #[ink(message, payable)]
+        #[modifiers(non_reentrant)]
+        pub fn finalize_approval(
+            &mut self,
+            contribution_id: ContributionId,
+        ) -> Result<(), DemoError> {
+            self.with_approval_deposit(|demo| demo._finalize_approval(contribution_id))
+        }
+
+        /// Finalizes a proposed approval, see `finalize_approval`.
+        fn _finalize_approval(&mut self, contribution_id: ContributionId) -> Result<(), DemoError> {
+            let proposed = self
+                .proposed_approvals
+                .get(contribution_id)
+                .ok_or(DemoError::UnknownProposedApproval)?;
+            if !Due::AfterBlock(proposed.challenge_end).is_due(self.now()) {
+                return Err(DemoError::ChallengePeriodNotOver);
+            }
+            if self.disputes.contains(contribution_id) {
+                return Err(DemoError::ApprovalDisputed);
+            }
+            transition(self.status_of(contribution_id), Action::Finalize)?;
+            self.accept_approval(contribution_id, proposed)
+        }
+
+        /// Simply returns the proposed approval of a given contribution, if any.
+        #[ink(message)]
+        pub fn get_proposed_approval(
+            &self,
+            contribution_id: ContributionId,
+        ) -> Option<ProposedApproval> {
+            self.proposed_approvals.get(contribution_id)
+        }
+
+        /// Returns `true` if the voucher with a given nonce is redeemed.
+        #[ink(message)]
+        pub fn is_redeemed(&self, nonce: u64) -> bool {
+            self.redeemed_nonces.contains(nonce)
+        }
+
+        /// Claim `amount` of the caller's share of the reward of a given contribution, leaving
+        /// the rest claimable later.
+        ///
+        /// Returns the receipt hash of (`contribution_id`, `contributor`, `amount`, `block_number`).
+        #[ink(message, selector = 0x8BAAC0C8)]
+        #[modifiers(non_reentrant)]
+        pub fn claim_partial(
+            &mut self,
+            contribution_id: ContributionId,
+            amount: Balance,
+        ) -> Result<HashValue, DemoError> {
+            self._claim(
+                contribution_id,
+                self.env().caller(),
+                Some(amount),
+                None,
+                None,
+            )
+        }
+
+        /// Claim the caller's share of the reward of a given contribution, failing if less than
+        /// `min_amount` of the reward asset is paid.
+        ///
+        /// It bounds the slippage of the conversion of rewards denominated in USD cents.
+        #[ink(message)]
+        #[modifiers(non_reentrant)]
+        pub fn claim_with_min_amount(
+            &mut self,
+            contribution_id: ContributionId,
+            min_amount: Balance,
+        ) -> Result<HashValue, DemoError> {
+            self._claim(
+                contribution_id,
+                self.env().caller(),
+                None,
+                None,
+                Some(min_amount),
+            )
+        }
+
+        /// Claim the dust of the caller, once it reaches the minimum claim.
+        #[ink(message)]
+        #[modifiers(non_reentrant)]
+        pub fn claim_dust(&mut self) -> Result<(), DemoError> {
+            let caller = self.env().caller();
+            let dust = self.dust_of(caller);
+            if dust == 0 || dust < self.config().min_claim {
+                return Err(DemoError::DustBelowMinimum);
+            }
+            self.pay_claim(caller, 0, Asset::Native)
+        }
+
+        /// Simply returns the native payouts of a given account below the minimum claim, not
+        /// paid yet.
+        #[ink(message)]
+        pub fn dust_of(&self, account: AccountId) -> Balance {
+            self.dust.get(account).unwrap_or_default()
+        }
+
+        /// Returns a preview of the claim of the caller's share of a given contribution, so
+        /// wallets show what the caller is paid before signing.
+        #[ink(message, selector = 0x1F3DA261)]
+        pub fn estimate_claim(
+            &self,
+            contribution_id: ContributionId,
+        ) -> Result<ClaimEstimate, DemoError> {
+            let caller = self.env().caller();
+            let contribution = self
+                .contributions
+                .get(contribution_id)
+                .ok_or(DemoError::UnknownContribution)?;
+            let split = contribution
+                .split_of(caller)
+                .ok_or(DemoError::CallerIsNotContributor)?;
+            if split.is_claimed {
+                return Err(DemoError::AlreadyClaimed);
+            }
+            if contribution.is_expired(self.block_number()) {
+                return Err(DemoError::ClaimDeadlinePassed);
+            }
+
+            let bps = split.bps;
+            let remaining = contribution
+                .share(bps)
+                .saturating_sub(self.claimed_amount(contribution_id, caller));
+            let relayer_fee = mul_div(
+                remaining,
+                self.config().relayer_fee_bps.into(),
+                MAX_BPS.into(),
+            );
+            let payout = mul_div(remaining, self.boost_bps(caller).into(), MAX_BPS.into());
+            let (payout, relayer_fee) = if self.is_in_usd(&contribution) {
+                (self.usd_to_units(payout), self.usd_to_units(relayer_fee))
+            } else {
+                (payout, relayer_fee)
+            };
+            let (available, is_covered) = match contribution.asset {
+                Asset::PSP22(token) => {
+                    let token: ink::contract_ref!(openbrush::contracts::traits::psp22::PSP22) =
+                        token.into();
+                    (payout, token.balance_of(self.env().account_id()) >= payout)
+                }
+                Asset::Native => {
+                    let total = self.dust_of(caller).saturating_add(payout);
+                    let available = if total < self.config().min_claim {
+                        0
+                    } else {
+                        total
+                    };
+                    (available, self.env().balance() >= available)
+                }
+            };
+            Ok(ClaimEstimate {
+                asset: contribution.asset,
+                remaining,
+                payout,
+                relayer_fee,
+                available,
+                is_covered,
+            })
+        }
+
+        /// Claim the share of `contributor` in a given contribution on their behalf, with their
+        /// signature of (contract, `contribution_id`, `contributor`, their next claim nonce).
+        ///
+        /// The caller relays the claim so that the contributor needs no native tokens, and is
+        /// paid the relayer fee from the claimed amount.
+        #[ink(message)]
+        #[modifiers(non_reentrant)]
+        pub fn claim_on_behalf(
+            &mut self,
+            contribution_id: ContributionId,
+            contributor: AccountId,
+            signature: EcdsaSignature,
+        ) -> Result<HashValue, DemoError> {
+            let nonce = self.claim_nonce_of(contributor);
+            let message_hash = self.env().hash_encoded::<Blake2x256, _>(&(
+                self.env().account_id(),
+                contribution_id,
+                contributor,
+                nonce,
+            ));
+            let signer = self
+                .env()
+                .ecdsa_recover(&signature, &message_hash)
+                .map_err(|_| DemoError::InvalidSignature)?;
+            if AccountId::from(self.env().hash_bytes::<Blake2x256>(&signer)) != contributor {
+                return Err(DemoError::InvalidSignature);
+            }
+
+            self.claim_nonces.insert(contributor, &(nonce + 1));
+            self._claim(
+                contribution_id,
+                contributor,
+                None,
+                Some(self.env().caller()),
+                None,
+            )
+        }
+
+        /// Simply returns the nonce a contributor signs to get their next claim relayed.
+        #[ink(message)]
+        pub fn claim_nonce_of(&self, contributor: AccountId) -> u64 {
+            self.claim_nonces.get(contributor).unwrap_or_default()
+        }
+
+        /// Simply returns the part of its share an account claimed from a given contribution.
+        #[ink(message, selector = 0x25E89EBF)]
+        pub fn claimed_amount(
+            &self,
+            contribution_id: ContributionId,
+            account: AccountId,
+        ) -> Balance {
+            self.claimed_amounts
+                .get((contribution_id, account))
+                .unwrap_or_default()
+        }
+
+        /// Simply returns the total paid to an account over a given era of `ERA_LENGTH` blocks,
+        /// in the units of the assets paid, so contributors can report their earnings.
+        #[ink(message)]
+        pub fn earnings_of(&self, account: AccountId, era: u32) -> Balance {
+            self.earnings.get((account, era)).unwrap_or_default()
+        }
+
+        /// Simply returns the eras an account was paid in, in ascending order.
+        #[ink(message)]
+        pub fn eras_of(&self, account: AccountId) -> Vec<u32> {
+            self.earning_eras.get(account).unwrap_or_default()
+        }
+
+        /// Returns the era of the current block.
+        #[ink(message)]
+        pub fn current_era(&self) -> u32 {
+            self.block_number() / ERA_LENGTH
+        }
+
+        /// Returns `true` if the account of an SS58 `address` is a contributor of a given
+        /// contribution, for frontends without a wallet connection.
+        #[ink(message)]
+        pub fn check_for_ss58(
+            &self,
+            contribution_id: ContributionId,
+            address: String,
+        ) -> Result<bool, DemoError> {
+            let account = AccountId::from(decode_ss58(&address).ok_or(DemoError::InvalidAddress)?);
+            Ok(self
+                .contributions
+                .get(contribution_id)
+                .map_or(false, |contribution| {
+                    contribution.split_of(account).is_some()
+                }))
+        }
+
+        /// Return the unclaimed reward of a contribution past its claim deadline to the budget
+        /// of its campaign.
+        ///
+        /// Returns the amount returned to the campaign.
+        #[ink(message)]
+        pub fn expire(&mut self, contribution_id: ContributionId) -> Result<Balance, DemoError> {
+            let mut contribution = self
+                .contributions
+                .get(contribution_id)
+                .ok_or(DemoError::UnknownContribution)?;
+            if !contribution.is_expired(self.block_number()) {
+                return Err(DemoError::ClaimDeadlineNotPassed);
+            }
+            if contribution.is_claimed {
+                return Err(DemoError::AlreadyClaimed);
+            }
+            transition(self.status_of(contribution_id), Action::Expire)?;
+
+            let amount = contribution
+                .splits
+                .iter()
+                .filter(|split| !split.is_claimed)
+                .fold(0, |amount: Balance, split| {
+                    amount.saturating_add(
+                        contribution
+                            .share(split.bps)
+                            .saturating_sub(self.claimed_amount(contribution_id, split.account)),
+                    )
+                });
+            for split in contribution.splits.iter_mut() {
+                split.is_claimed = true;
+            }
+            contribution.is_claimed = true;
+            self.contributions.insert(contribution_id, &contribution);
+            self.remove_unclaimed(contribution_id);
+
+            if !self.is_in_usd(&contribution) {
+                self.settle_reward(contribution.asset, amount);
+            }
+            if let Some(campaign_id) = self.open_campaign_of(&contribution) {
+                if let Some(mut campaign) = self.campaigns.get(campaign_id) {
+                    campaign.remaining = campaign.remaining.saturating_add(amount);
+                    self.campaigns.insert(campaign_id, &campaign);
+                }
+            }
+            let seq = self.next_seq();
+            self.env().emit_event(ContributionExpired {
+                seq,
+                id: contribution_id,
+                amount,
+            });
+            Ok(amount)
+        }
+
+        /// Expire the contributions past their claim deadline in a page of the unclaimed
+        /// contributions (see `unclaimed_page`).
+        ///
+        /// Anyone can call it: the caller earns the sweep bounty on the native rewards
+        /// reclaimed, taken from the budgets of their open campaigns, or from the reclaimed
+        /// rewards left in the pool once their campaign is finalized. Returns the bounty paid.
+        #[ink(message)]
+        #[modifiers(non_reentrant)]
+        pub fn sweep_expired_page(
+            &mut self,
+            offset: u32,
+            limit: u32,
+        ) -> Result<Balance, DemoError> {
+            let block_number = self.block_number();
+            // Expiring contributions reorders the unclaimed ones, so the page is read first
+            let expired: Vec<(ContributionId, Contribution)> = self
+                .unclaimed_page(offset, limit)
+                .into_iter()
+                .filter_map(|contribution_id| {
+                    self.contributions
+                        .get(contribution_id)
+                        .filter(|contribution| contribution.is_expired(block_number))
+                        .map(|contribution| (contribution_id, contribution))
+                })
+                .collect();
+            let sweep_bounty_bps = self.config().sweep_bounty_bps;
+            let mut bounty: Balance = 0;
+            for (contribution_id, contribution) in expired {
+                let amount = self.expire(contribution_id)?;
+                if !matches!(contribution.asset, Asset::Native) || self.is_in_usd(&contribution) {
+                    continue;
+                }
+                let share = mul_div(amount, sweep_bounty_bps.into(), MAX_BPS.into());
+                if let Some(campaign_id) = self.open_campaign_of(&contribution) {
+                    if let Some(mut campaign) = self.campaigns.get(campaign_id) {
+                        campaign.remaining = campaign.remaining.saturating_sub(share);
+                        self.campaigns.insert(campaign_id, &campaign);
+                    }
+                }
+                bounty = bounty.saturating_add(share);
+            }
+            // The reclaimed rewards are settled, so the bounty never dips into the ones owed
+            let available = self
+                .env()
+                .balance()
+                .saturating_sub(self.liabilities_of(Asset::Native));
+            if bounty > available {
+                return Err(DemoError::InsufficientPool {
+                    needed: bounty,
+                    available,
+                });
+            }
+            if bounty > 0 {
+                self.pay(self.env().caller(), bounty, Asset::Native, Movement::Fee)?;
+            }
+            Ok(bounty)
+        }
+
+        /// Emit a `ClaimExpiringSoon` reminder for each unclaimed contribution whose claim
+        /// deadline is less than `EXPIRY_NOTICE_PERIOD` blocks away, scanning the next
+        /// `MAX_PAGE_SIZE` contributions round-robin.
+        ///
+        /// Anyone can call it: the caller earns the poke tip if a reminder is emitted and the
+        /// contract balance covers it on top of the native liabilities. Returns the number of
+        /// reminders.
+        #[ink(message)]
+        #[modifiers(non_reentrant)]
+        pub fn poke(&mut self) -> Result<u32, DemoError> {
+            let count = self.contribution_count.get_or_default();
+            if count == 0 {
+                return Ok(0);
+            }
+            let block_number = self.block_number();
+            let cursor = self.poke_cursor.get_or_default() % count;
+            let scanned = count.min(MAX_PAGE_SIZE);
+            let mut reminders = 0;
+            for offset in 0..scanned {
+                let index = (cursor + offset) % count;
+                let Some(contribution_id) = self.contribution_ids.get(index) else {
+                    continue;
+                };
+                let Some(contribution) = self.contributions.get(contribution_id) else {
+                    continue;
+                };
+                let Some(deadline) = contribution.deadline else {
+                    continue;
+                };
+                if contribution.is_claimed
+                    || contribution.is_expired(block_number)
+                    || deadline - block_number > EXPIRY_NOTICE_PERIOD
+                    || self.reminded.contains(contribution_id)
+                {
+                    continue;
+                }
+                self.reminded.insert(contribution_id, &());
+                let seq = self.next_seq();
+                self.env().emit_event(ClaimExpiringSoon {
+                    seq,
+                    id: contribution_id,
+                    deadline,
+                });
+                reminders += 1;
+            }
+            self.poke_cursor.set(&((cursor + scanned) % count));
+
+            if reminders > 0 {
+                self.pay_tip()?;
+            }
+            Ok(reminders)
+        }
+
+        /// Returns the delayed actions due now, in their execution order: the proposed
+        /// approvals past their challenge period, the contributions past their claim deadline
+        /// among the next `MAX_PAGE_SIZE` scanned round-robin, then the scheduled sweep past
+        /// its timelock.
+        ///
+        /// Disputes past their deadline are not listed: they are left to the owner.
+        #[ink(message)]
+        pub fn due_actions(&self) -> Vec<Task> {
+            let now = self.now();
+            let mut tasks = Vec::new();
+            for contribution_id in self.pending_approvals.get_or_default() {
+                let Some(proposed) = self.proposed_approvals.get(contribution_id) else {
+                    continue;
+                };
+                if Due::AfterBlock(proposed.challenge_end).is_due(now)
+                    && !self.disputes.contains(contribution_id)
+                {
+                    tasks.push(Task::Finalize(contribution_id));
+                }
+            }
+
+            let count = self.contribution_count.get_or_default();
+            let cursor = self.tick_cursor.get_or_default() % count.max(1);
+            for offset in 0..count.min(MAX_PAGE_SIZE) {
+                let Some(contribution_id) = self.contribution_ids.get((cursor + offset) % count)
+                else {
+                    continue;
+                };
+                let Some(contribution) = self.contributions.get(contribution_id) else {
+                    continue;
+                };
+                let is_due = contribution
+                    .deadline
+                    .map_or(false, |deadline| Due::AfterBlock(deadline).is_due(now));
+                if is_due && !contribution.is_claimed {
+                    tasks.push(Task::Expire(contribution_id));
+                }
+            }
+
+            if let Some(sweep) = self.get_scheduled_sweep() {
+                if Due::At(sweep.executable_at).is_due(now) {
+                    tasks.push(Task::Sweep);
+                }
+            }
+            tasks
+        }
+
+        /// Execute the delayed actions due now, as listed by `due_actions`.
+        ///
+        /// A failed action emits `TaskFailed` and does not stop the next ones. Anyone can call
+        /// it: the caller earns the poke tip if an action is executed and the contract balance
+        /// covers it on top of the native liabilities. Returns the number of executed actions.
+        #[ink(message)]
+        #[modifiers(non_reentrant)]
+        pub fn tick(&mut self) -> Result<u32, DemoError> {
+            let mut executed = 0;
+            for task in self.due_actions() {
+                let outcome = match task {
+                    Task::Finalize(contribution_id) => self._finalize_approval(contribution_id),
+                    Task::Expire(contribution_id) => self.expire(contribution_id).map(|_| ()),
+                    Task::Sweep => self._execute_sweep(),
+                };
+                match outcome {
+                    Ok(()) => executed += 1,
+                    Err(error) => {
+                        let seq = self.next_seq();
+                        self.env().emit_event(TaskFailed { seq, task, error });
+                    }
+                }
+            }
+            let count = self.contribution_count.get_or_default();
+            if count > 0 {
+                let cursor = self.tick_cursor.get_or_default() % count;
+                self.tick_cursor
+                    .set(&((cursor + count.min(MAX_PAGE_SIZE)) % count));
+            }
+
+            if executed > 0 {
+                self.pay_tip()?;
+            }
+            Ok(executed)
+        }
+
+        /// Simply returns the co-authors of a given contribution and their share in basis points.
+        #[ink(message)]
+        pub fn get_splits(&self, contribution_id: ContributionId) -> Vec<(AccountId, u16)> {
+            self.contributions
+                .get(contribution_id)
+                .map(|contribution| {
+                    contribution
+                        .splits
+                        .iter()
+                        .map(|split| (split.account, split.bps))
+                        .collect()
+                })
+                .unwrap_or_default()
+        }
+
+        /// Returns the whole status of many contributions for the caller in one read call.
+        ///
+        /// At most 100 contributions are checked, the extra ids are ignored.
+        #[ink(message)]
+        pub fn check_many(
+            &self,
+            ids: Vec<ContributionId>,
+        ) -> Vec<(ContributionId, ContributionView)> {
+            ids.into_iter()
+                .take(MAX_PAGE_SIZE as usize)
+                .map(|contribution_id| (contribution_id, self.check_detailed(contribution_id)))
+                .collect()
+        }
+
+        /// Returns the raw SCALE-encoded values stored under given raw storage keys (e.g. the
+        /// root key of a `Lazy` field, or the encoded root key and key of a `Mapping` entry),
+        /// so light clients read slices of the state in one dry-run.
+        ///
+        /// The storage of the contract is public on-chain anyway, so every key can be read. At
+        /// most 100 keys are read, the extra keys are ignored.
+        #[ink(message)]
+        pub fn read_raw(&self, keys: Vec<Vec<u8>>) -> Vec<Option<Vec<u8>>> {
+            keys.into_iter()
+                .take(MAX_PAGE_SIZE as usize)
+                .map(|key| {
+                    ink::env::get_contract_storage::<RawBytes, RawBytes>(&RawBytes(key))
+                        .ok()
+                        .flatten()
+                        .map(|value| value.0)
+                })
+                .collect()
+        }
+
+        /// Returns the whole status of a given contribution for the caller, so the UI makes a
+        /// single call instead of `check`, `get_contributor` and `get_splits`.
+        #[ink(message)]
+        pub fn check_detailed(&self, contribution_id: ContributionId) -> ContributionView {
+            let caller = self.env().caller();
+            let Some(contribution) = self.contributions.get(contribution_id) else {
+                let submission = self.submissions.get(contribution_id);
+                return ContributionView {
+                    status: match submission {
+                        Some(_) => ContributionStatus::Pending,
+                        None => ContributionStatus::Unknown,
+                    },
+                    is_contributor: submission
+                        .map_or(false, |submission| submission.contributor == caller),
+                    reward: 0,
+                    claimable_at: None,
+                    is_claimed: false,
+                };
+            };
+
+            let split = contribution.split_of(caller);
+            ContributionView {
+                status: if contribution.is_claimed {
+                    ContributionStatus::Claimed
+                } else {
+                    ContributionStatus::Approved
+                },
+                is_contributor: split.is_some(),
+                reward: split.map_or(0, |split| contribution.share(split.bps)),
+                claimable_at: split.map(|_| contribution.approved_at),
+                is_claimed: split.map_or(false, |split| split.is_claimed),
+            }
+        }
+
+        /// Returns a given contribution with its status, claims and resolved identity, so the
+        /// UI displays it with a single call.
+        #[ink(message, selector = 0x6697A394)]
+        pub fn contribution_view(
+            &self,
+            contribution_id: ContributionId,
+        ) -> Option<ContributionDetails> {
+            let contribution = self.contributions.get(contribution_id)?;
+            Some(ContributionDetails {
+                id: contribution_id,
+                status: if contribution.is_claimed {
+                    ContributionStatus::Claimed
+                } else {
+                    ContributionStatus::Approved
+                },
+                contributor: contribution.contributor,
+                contributor_id: self.get_contributor_id(contribution.contributor),
+                reward: contribution.reward,
+                asset: contribution.asset,
+                claimed: contribution
+                    .splits
+                    .iter()
+                    .fold(0, |claimed: Balance, split| {
+                        claimed.saturating_add(self.claimed_amount(contribution_id, split.account))
+                    }),
+                splits: contribution.splits.into(),
+                approved_at: contribution.approved_at,
+                deadline: contribution.deadline,
+                tags: self.get_tags(contribution_id),
+            })
+        }
+
+        /// Returns the URI of the off-chain JSON metadata of a given contribution: the base URI
+        /// followed by its id, like the ERC-721 `tokenURI`, so explorers render it as an NFT.
+        ///
+        /// Returns an empty string if the contribution is unknown or no base URI is set.
+        #[ink(message)]
+        pub fn contribution_uri(&self, contribution_id: ContributionId) -> String {
+            let base_uri = self.metadata_base_uri.get_or_default();
+            if base_uri.is_empty() || !self.contributions.contains(contribution_id) {
+                return String::new();
+            }
+            ink::prelude::format!("{}{contribution_id}", base_uri.as_str())
+        }
+
+        /// Set the base URI of the off-chain JSON metadata of the contributions, e.g.
+        /// `ipfs://<cid>/`, an empty one disabling `contribution_uri`.
+        #[ink(message)]
+        pub fn set_metadata_base_uri(
+            &mut self,
+            base_uri: BoundedString<MAX_URI_LENGTH>,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_metadata_base_uri"))?;
+            let uri = String::from(base_uri.as_str());
+            self.metadata_base_uri.set(&base_uri);
+            let seq = self.next_seq();
+            self.env()
+                .emit_event(MetadataBaseUriUpdated { seq, base_uri: uri });
+            Ok(())
+        }
+
+        /// Returns a given account with its identity, profile and standing, so the UI displays
+        /// it with a single call.
+        #[ink(message, selector = 0x65309C78)]
+        pub fn identity_view(&self, account: AccountId) -> IdentityView {
+            IdentityView {
+                account,
+                contributor_id: self.get_contributor_id(account),
+                profile: self.profile_of(account),
+                did: self.did_of(account),
+                approvals: self.get_approvals(account),
+                points: self.points.get(account).unwrap_or_default(),
+                reputation: self.effective_reputation_of(account),
+                streak: self.streak_of(account),
+                payout_address: self.payout_address_of(account),
+            }
+        }
+
+        /// Simply returns the `AccountId` of a given contribution.
+        #[ink(message)]
+        pub fn get_contributor(&self, contribution_id: ContributionId) -> Option<AccountId> {
+            self.contributions
+                .get(contribution_id)
+                .map(|contribution| contribution.contributor)
+        }
+
+        /// Set the off-chain verifier key signing identity proofs.
+        #[ink(message)]
+        pub fn set_verifier(&mut self, verifier: Option<EcdsaPublicKey>) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_verifier"))?;
+            self.verifier.set(&verifier);
+            Ok(())
+        }
+
+        /// Simply returns the off-chain verifier key signing identity proofs.
+        #[ink(message)]
+        pub fn get_verifier(&self) -> Option<EcdsaPublicKey> {
+            self.verifier.get().flatten()
+        }
+
+        /// Bind the caller's account to a contributor identity, proven by an ECDSA `signature`
+        /// of the blake2 hash of (`caller`, `contributor_id`) made by the verifier.
+        ///
+        /// The proof stands for the owner's approval, so it bypasses the strict mode.
+        #[ink(message)]
+        pub fn register_identity_with_proof(
+            &mut self,
+            contributor_id: ContributorId,
+            signature: EcdsaSignature,
+        ) -> Result<(), DemoError> {
+            let verifier = self.get_verifier().ok_or(DemoError::NoVerifier)?;
+            let caller = self.env().caller();
+            let message_hash = self
+                .env()
+                .hash_encoded::<Blake2x256, _>(&(caller, contributor_id));
+            let signer = self
+                .env()
+                .ecdsa_recover(&signature, &message_hash)
+                .map_err(|_| DemoError::InvalidSignature)?;
+            if signer != verifier {
+                return Err(DemoError::InvalidSignature);
+            }
+            self._register_identity(contributor_id, caller)
+        }
+
+        /// Record that the GitHub account of the caller is older than the minimum account age,
+        /// proven by an ECDSA `signature` of the blake2 hash of (contract, `caller`,
+        /// `min_account_age`, `expires_at`) made by the verifier.
+        ///
+        /// Claims above the attestation threshold are only paid until `expires_at`.
+        #[ink(message)]
+        pub fn attest_account_age(
+            &mut self,
+            expires_at: Timestamp,
+            signature: EcdsaSignature,
+        ) -> Result<(), DemoError> {
+            let verifier = self.get_verifier().ok_or(DemoError::NoVerifier)?;
+            if expires_at <= self.block_timestamp() {
+                return Err(DemoError::AttestationExpired);
+            }
+            let caller = self.env().caller();
+            let min_account_age = self.config().min_account_age;
+            let message_hash = self.env().hash_encoded::<Blake2x256, _>(&(
+                self.env().account_id(),
+                caller,
+                min_account_age,
+                expires_at,
+            ));
+            let signer = self
+                .env()
+                .ecdsa_recover(&signature, &message_hash)
+                .map_err(|_| DemoError::InvalidSignature)?;
+            if signer != verifier {
+                return Err(DemoError::InvalidSignature);
+            }
+
+            self.age_attestations.insert(caller, &expires_at);
+            let seq = self.next_seq();
+            self.env().emit_event(AccountAgeAttested {
+                seq,
+                account: caller,
+                min_account_age,
+                expires_at,
+            });
+            Ok(())
+        }
+
+        /// Returns the expiry of the account age attestation of `account`, if any.
+        #[ink(message)]
+        pub fn age_attestation_of(&self, account: AccountId) -> Option<Timestamp> {
+            self.age_attestations.get(account)
+        }
+
+        /// Bind the caller's account to a contributor identity, referred by the registered
+        /// `referrer` who earns the referral bonus on the first approved contribution.
+        #[ink(message)]
+        pub fn register_identity_with_referrer(
+            &mut self,
+            contributor_id: ContributorId,
+            referrer: AccountId,
+        ) -> Result<(), DemoError> {
+            let caller = self.env().caller();
+            if referrer == caller || self.identity_of(referrer).is_none() {
+                return Err(DemoError::InvalidReferrer);
+            }
+            let is_registered = self.identity_of(caller).is_some();
+            Workflow::register_identity(self, contributor_id)?;
+
+            // Only the first identity of an account sets its referrer
+            if !is_registered {
+                self.referrers.insert(caller, &referrer);
+                let mut referrals = self.referrals_of(referrer);
+                referrals.push(caller);
+                self.referrals.insert(referrer, &referrals);
+            }
+            Ok(())
+        }
+
+        /// Simply returns the contributors referred by a given account.
+        #[ink(message)]
+        pub fn referrals_of(&self, account: AccountId) -> Vec<AccountId> {
+            self.referrals.get(account).unwrap_or_default()
+        }
+
+        /// Simply returns the referrer of a given contributor.
+        #[ink(message)]
+        pub fn referrer_of(&self, account: AccountId) -> Option<AccountId> {
+            self.referrers.get(account)
+        }
+
+        /// Simply returns the referral bonuses owed to a given referrer.
+        #[ink(message)]
+        pub fn get_referral_rewards(&self, referrer: AccountId) -> Balance {
+            self.referral_rewards.get(referrer).unwrap_or_default()
+        }
+
+        /// Claim the referral bonuses owed to the caller.
+        ///
+        /// Returns the claimed amount.
+        #[ink(message)]
+        #[modifiers(non_reentrant)]
+        pub fn claim_referral_rewards(&mut self) -> Result<Balance, DemoError> {
+            let caller = self.env().caller();
+            let amount = self.get_referral_rewards(caller);
+            if amount == 0 {
+                return Err(DemoError::NoReferralRewards);
+            }
+
+            self.referral_rewards.remove(caller);
+            self.settle(Asset::Native, amount);
+            self.pay(caller, amount, Asset::Native, Movement::Claim)?;
+            Ok(amount)
+        }
+
+        /// Fund the native reward pool with the transferred value, recorded in the ledger.
+        #[ink(message, payable)]
+        pub fn fund(&mut self) {
+            self.record(Movement::Fund, self.env().transferred_value());
+        }
+
+        /// Open the airdrop of past contributors with the Merkle root of their
+        /// (GitHub identity hash, amount) leaves, or close it with `None`.
+        ///
+        /// Closing the airdrop returns its unclaimed pool to the reward pool.
+        #[ink(message)]
+        pub fn set_airdrop_root(&mut self, root: Option<HashValue>) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_airdrop_root"))?;
+            if root.is_none() {
+                self.settle(Asset::Native, self.airdrop_pool());
+                self.airdrop_pool.set(&0);
+            }
+            self.airdrop_root.set(&root);
+            Ok(())
+        }
+
+        /// Simply returns the Merkle root of the airdrop, if open.
+        #[ink(message)]
+        pub fn airdrop_root(&self) -> Option<HashValue> {
+            self.airdrop_root.get().flatten()
+        }
+
+        /// Fund the airdrop pool with the transferred value, set aside from the reward pool.
+        #[ink(message, payable)]
+        pub fn fund_airdrop(&mut self) {
+            let value = self.env().transferred_value();
+            self.airdrop_pool
+                .set(&self.airdrop_pool().saturating_add(value));
+            self.owe(Asset::Native, value);
+            self.record(Movement::Fund, value);
+        }
+
+        /// Simply returns the native balance set aside for the airdrop.
+        #[ink(message)]
+        pub fn airdrop_pool(&self) -> Balance {
+            self.airdrop_pool.get_or_default()
+        }
+
+        /// Returns the leaf of the airdrop of `amount` to a GitHub identity hash: the Blake2x256
+        /// hash of (`github_id_hash`, `amount`).
+        #[ink(message)]
+        pub fn airdrop_leaf(&self, github_id_hash: HashValue, amount: Balance) -> HashValue {
+            self.env()
+                .hash_encoded::<Blake2x256, _>(&(github_id_hash, amount))
+        }
+
+        /// Claim once the airdrop of `amount` to the GitHub identity of the caller, with the
+        /// Merkle proof of its leaf.
+        #[ink(message)]
+        #[modifiers(non_reentrant)]
+        pub fn claim_airdrop(
+            &mut self,
+            amount: Balance,
+            proof: Vec<HashValue>,
+        ) -> Result<(), DemoError> {
+            let root = self.airdrop_root().ok_or(DemoError::AirdropUnset)?;
+            let caller = self.env().caller();
+            let Some((Platform::GitHub, github_id_hash)) = self.get_contributor_id(caller) else {
+                return Err(DemoError::IdentityNotRegistered);
+            };
+            if self.airdrop_claimed.contains(github_id_hash) {
+                return Err(DemoError::AirdropAlreadyClaimed);
+            }
+            if proof.len() > MAX_PROOF_LENGTH
+                || !merkle::verify(&root, self.airdrop_leaf(github_id_hash, amount), &proof)
+            {
+                return Err(DemoError::InvalidProof);
+            }
+            let available = self.airdrop_pool();
+            if amount > available {
+                return Err(DemoError::InsufficientPool {
+                    needed: amount,
+                    available,
+                });
+            }
+
+            self.airdrop_claimed.insert(github_id_hash, &());
+            self.airdrop_pool.set(&(available - amount));
+            self.settle(Asset::Native, amount);
+            self.pay(
+                self.payout_address_of(caller),
+                amount,
+                Asset::Native,
+                Movement::Claim,
+            )?;
+            let seq = self.next_seq();
+            self.env().emit_event(AirdropClaimed {
+                seq,
+                account: caller,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Returns `true` if the airdrop of a given GitHub identity hash is claimed.
+        #[ink(message)]
+        pub fn is_airdrop_claimed(&self, github_id_hash: HashValue) -> bool {
+            self.airdrop_claimed.contains(github_id_hash)
+        }
+
+        /// Attach the transferred value as a tip to an approved contribution, shared between
+        /// its co-authors like its reward.
+        ///
+        /// Tips are paid along with the claims of the reward, or with `claim_tips`. The caller is
+        /// credited as a sponsor of the contribution.
+        #[ink(message, payable, selector = 0xEDD26B46)]
+        pub fn tip(&mut self, contribution_id: ContributionId) -> Result<(), DemoError> {
+            let amount = self.env().transferred_value();
+            if amount == 0 {
+                return Err(DemoError::ZeroTip);
+            }
+            if !self.contributions.contains(contribution_id) {
+                return Err(DemoError::UnknownContribution);
+            }
+            let caller = self.env().caller();
+            let mut sponsors = self.sponsors_of(contribution_id);
+            let total = match sponsors.iter_mut().find(|(sponsor, _)| *sponsor == caller) {
+                Some((_, total)) => {
+                    *total = total.saturating_add(amount);
+                    *total
+                }
+                None if sponsors.len() >= MAX_SPONSORS => {
+                    return Err(DemoError::TooManySponsors);
+                }
+                None => {
+                    sponsors.push((caller, amount));
+                    amount
+                }
+            };
+            self.sponsors.insert(contribution_id, &sponsors);
+            self.tips.insert(
+                contribution_id,
+                &self.tips_of(contribution_id).saturating_add(amount),
+            );
+            self.owe(Asset::Native, amount);
+            self.record(Movement::Fund, amount);
+            let seq = self.next_seq();
+            self.env().emit_event(SponsoredBy {
+                seq,
+                id: contribution_id,
+                sponsor: caller,
+                total,
+            });
+            let seq = self.next_seq();
+            self.env().emit_event(ContributionTipped {
+                seq,
+                id: contribution_id,
+                tipper: caller,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Simply returns the sponsors of a given contribution and the total of their tips, in
+        /// the order of their first tip.
+        #[ink(message)]
+        pub fn sponsors_of(&self, contribution_id: ContributionId) -> Vec<(AccountId, Balance)> {
+            self.sponsors.get(contribution_id).unwrap_or_default()
+        }
+
+        /// Add a sponsor to (or remove it from) the sponsor allow list.
+        #[ink(message)]
+        pub fn set_allowed_sponsor(
+            &mut self,
+            account: AccountId,
+            allowed: bool,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_allowed_sponsor"))?;
+            if allowed {
+                self.allowed_sponsors.insert(account, &());
+            } else {
+                self.allowed_sponsors.remove(account);
+            }
+            let seq = self.next_seq();
+            self.env().emit_event(SponsorAllowListUpdated {
+                seq,
+                account,
+                allowed,
+            });
+            Ok(())
+        }
+
+        /// Returns `true` if the account is on the sponsor allow list.
+        #[ink(message)]
+        pub fn is_allowed_sponsor(&self, account: AccountId) -> bool {
+            self.allowed_sponsors.contains(account)
+        }
+
+        /// Approve contribution with the transferred value as its native reward, so that a
+        /// one-off sponsor funds and approves it at once.
+        ///
+        /// The reward is fully backed: it is not drawn from the active campaign. The storage
+        /// deposit of the approval is taken off the transferred value.
+        #[ink(message, payable)]
+        pub fn sponsor_and_approve(
+            &mut self,
+            contribution_id: ContributionId,
+            contributor: AccountId,
+        ) -> Result<(), DemoError> {
+            if !self.is_allowed_sponsor(self.env().caller()) {
+                return Err(DemoError::CallerIsNotSponsor);
+            }
+            let needed = self.approval_deposit();
+            let reward = self
+                .env()
+                .transferred_value()
+                .checked_sub(needed)
+                .ok_or(DemoError::InsufficientDeposit { needed })?;
+            if reward == 0 {
+                return Err(DemoError::ZeroReward);
+            }
+            self.record_approval(
+                contribution_id,
+                vec![(contributor, MAX_BPS)],
+                MAX_DIFFICULTY,
+                Some(reward),
+                true,
+            )?;
+            self.record(Movement::Fund, reward);
+            Ok(())
+        }
+
+        /// Claim the caller's share of the tips of a contribution, independently of its reward.
+        ///
+        /// Returns the paid amount.
+        #[ink(message)]
+        #[modifiers(non_reentrant)]
+        pub fn claim_tips(
+            &mut self,
+            contribution_id: ContributionId,
+        ) -> Result<Balance, DemoError> {
+            let caller = self.env().caller();
+            let split = self
+                .contributions
+                .get(contribution_id)
+                .ok_or(DemoError::UnknownContribution)?
+                .split_of(caller)
+                .ok_or(DemoError::CallerIsNotContributor)?;
+            if self.is_denied(caller) {
+                return Err(DemoError::ContributorDenied);
+            }
+            match self.pay_tips(contribution_id, caller, split.bps)? {
+                0 => Err(DemoError::NoTips),
+                amount => Ok(amount),
+            }
+        }
+
+        /// Simply returns the total tips attached to a given contribution.
+        #[ink(message)]
+        pub fn tips_of(&self, contribution_id: ContributionId) -> Balance {
+            self.tips.get(contribution_id).unwrap_or_default()
+        }
+
+        /// Returns the share of the tips of a given contribution left to pay to `account`.
+        #[ink(message)]
+        pub fn pending_tips_of(
+            &self,
+            contribution_id: ContributionId,
+            account: AccountId,
+        ) -> Balance {
+            self.contributions
+                .get(contribution_id)
+                .and_then(|contribution| contribution.split_of(account))
+                .map_or(0, |split| {
+                    mul_div(
+                        self.tips_of(contribution_id),
+                        split.bps.into(),
+                        MAX_BPS.into(),
+                    )
+                    .saturating_sub(
+                        self.paid_tips
+                            .get((contribution_id, account))
+                            .unwrap_or_default(),
+                    )
+                })
+        }
+
+        /// Returns a page of the ledger of the native reward pool, oldest first, with the index
+        /// of each entry.
+        ///
+        /// Only the latest 1000 entries are kept. At most 100 entries are returned per page.
+        #[ink(message)]
+        pub fn ledger_page(&self, offset: u32, limit: u32) -> Vec<(u32, LedgerEntry)> {
+            let count = self.ledger_count.get_or_default();
+            let start = offset.max(count.saturating_sub(LEDGER_CAPACITY));
+            let end = offset.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+            (start..end)
+                .filter_map(|index| {
+                    self.ledger
+                        .get(index % LEDGER_CAPACITY)
+                        .map(|entry| (index, entry))
+                })
+                .collect()
+        }
+
+        /// Returns a page of the approved contributions, in approval order, for off-chain backups,
+        /// with the amount claimed by each co-author in the order of the splits.
+        ///
+        /// At most 100 contributions are returned per page.
+        #[ink(message)]
+        pub fn export_page(
+            &self,
+            offset: u32,
+            limit: u32,
+        ) -> Result<Vec<(ContributionId, Contribution, Vec<Balance>)>, DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("export_page"))?;
+            let end = offset
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.contribution_count.get_or_default());
+            Ok((offset..end)
+                .filter_map(|index| self.contribution_ids.get(index))
+                .filter_map(|id| {
+                    self.contributions.get(id).map(|contribution| {
+                        let claimed = contribution
+                            .splits
+                            .iter()
+                            .map(|split| self.claimed_amount(id, split.account))
+                            .collect();
+                        (id, contribution, claimed)
+                    })
+                })
+                .collect())
+        }
+
+        /// Returns the hash chaining a page of the approved contributions in approval order onto
+        /// the `root` of the previous pages, to verify the integrity of an off-chain backup.
+        ///
+        /// The first page starts from the zero hash and at most 100 contributions are chained
+        /// per page, like `export_page`: the root of the last page is the one of the whole state.
+        #[ink(message)]
+        pub fn state_root(&self, root: HashValue, offset: u32, limit: u32) -> HashValue {
+            let end = offset
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.contribution_count.get_or_default());
+            (offset..end)
+                .filter_map(|index| self.contribution_ids.get(index))
+                .filter_map(|id| {
+                    self.contributions
+                        .get(id)
+                        .map(|contribution| (id, contribution))
+                })
+                .fold(root, |root, entry| {
+                    self.env().hash_encoded::<Blake2x256, _>(&(root, entry))
+                })
+        }
+
+        /// Remove settled contributions from the storage to reclaim their deposit, chaining them
+        /// and their sponsors into the archive root.
+        ///
+        /// Fails while the tips of a contribution are not fully paid to its co-authors.
+        ///
+        /// Anyone can archive a contribution approved more than `PUBLIC_ARCHIVE_DELAY` blocks ago,
+        /// the owner at any time.
+        #[ink(message)]
+        pub fn archive(&mut self, contribution_ids: Vec<ContributionId>) -> Result<(), DemoError> {
+            if contribution_ids.len() > MAX_PAGE_SIZE as usize {
+                return Err(DemoError::TooManyContributions);
+            }
+            let is_owner = Ownable::owner(self) == Some(self.env().caller());
+            for contribution_id in contribution_ids {
+                let contribution = self
+                    .contributions
+                    .get(contribution_id)
+                    .ok_or(DemoError::UnknownContribution)?;
+                if !contribution.is_claimed {
+                    return Err(DemoError::ContributionNotSettled);
+                }
+                let archivable_at = contribution
+                    .approved_at
+                    .saturating_add(PUBLIC_ARCHIVE_DELAY);
+                if !is_owner && self.block_number() < archivable_at {
+                    return Err(DemoError::ArchiveTooEarly);
+                }
+                if contribution
+                    .splits
+                    .iter()
+                    .any(|split| self.pending_tips_of(contribution_id, split.account) > 0)
+                {
+                    return Err(DemoError::TipsUnpaid);
+                }
+                transition(self.status_of(contribution_id), Action::Archive)?;
+
+                let sponsors = self.sponsors_of(contribution_id);
+                let archive_root = self.env().hash_encoded::<Blake2x256, _>(&(
+                    self.archive_root(),
+                    contribution_id,
+                    &contribution,
+                    &sponsors,
+                ));
+                self.archive_root.set(&archive_root);
+                // The rounding dust of the shares of the tips is no longer owed
+                let mut unpaid_tips = self.tips.take(contribution_id).unwrap_or_default();
+                for split in contribution.splits.iter() {
+                    let paid = self
+                        .paid_tips
+                        .take((contribution_id, split.account))
+                        .unwrap_or_default();
+                    unpaid_tips = unpaid_tips.saturating_sub(paid);
+                }
+                self.settle(Asset::Native, unpaid_tips);
+                self.sponsors.remove(contribution_id);
+                self.contributions.remove(contribution_id);
+                self.tags.remove(contribution_id);
+                self.archived.insert(contribution_id, &());
+                let seq = self.next_seq();
+                self.env().emit_event(ContributionArchived {
+                    seq,
+                    id: contribution_id,
+                    archive_root,
+                });
+            }
+            Ok(())
+        }
+
+        /// Import a chunk of contributions exported from a previous deployment, as returned by
+        /// `export_page`, crediting the approvals of their co-authors.
+        ///
+        /// Contributions already stored or archived are skipped, so a chunk can be imported
+        /// again. Their splits are checked like on approval, they are detached from the
+        /// campaigns of the previous deployment and their claim deadline is rebased on the
+        /// current block, keeping its claim period. Returns the number of imported
+        /// contributions.
+        #[ink(message)]
+        pub fn import(
+            &mut self,
+            contributions: Vec<(ContributionId, Contribution, Vec<Balance>)>,
+        ) -> Result<u32, DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("import"))?;
+            if self.import_finalized() {
+                return Err(DemoError::ImportFinalized);
+            }
+            if contributions.len() > MAX_PAGE_SIZE as usize {
+                return Err(DemoError::TooManyContributions);
+            }
+            let mut imported = 0;
+            for (contribution_id, mut contribution, claimed) in contributions {
+                if self.contributions.contains(contribution_id) || self.is_archived(contribution_id)
+                {
+                    continue;
+                }
+                let splits: Vec<(AccountId, u16)> = contribution
+                    .splits
+                    .iter()
+                    .map(|split| (split.account, split.bps))
+                    .collect();
+                if self.validate_splits(&splits)? != contribution.contributor
+                    || claimed.len() != splits.len()
+                    || contribution
+                        .splits
+                        .iter()
+                        .zip(claimed.iter())
+                        .any(|(split, claimed)| *claimed > contribution.share(split.bps))
+                {
+                    return Err(DemoError::InvalidSplits);
+                }
+                // The campaigns and block numbers of the previous deployment mean nothing here
+                let approved_at = self.block_number();
+                contribution.deadline = contribution.deadline.map(|deadline| {
+                    approved_at.saturating_add(deadline.saturating_sub(contribution.approved_at))
+                });
+                contribution.approved_at = approved_at;
+                contribution.campaign_id = None;
+
+                let mut owed: Balance = 0;
+                for (split, claimed) in contribution.splits.iter().zip(claimed) {
+                    if claimed > 0 {
+                        self.claimed_amounts
+                            .insert((contribution_id, split.account), &claimed);
+                    }
+                    if !split.is_claimed {
+                        owed = owed
+                            .saturating_add(contribution.share(split.bps).saturating_sub(claimed));
+                    }
+                }
+                if !self.is_in_usd(&contribution) {
+                    self.owe_reward(contribution.asset, owed);
+                }
+                self.track_deadline(&contribution);
+                for split in contribution.splits.iter() {
+                    self.approvals
+                        .insert(split.account, &(self.get_approvals(split.account) + 1));
+                    self.update_level(split.account);
+                    self.mint_points(split.account, POINTS_PER_APPROVAL);
+                    self.index_by_identity(split.account, contribution_id);
+                }
+                self.contributions.insert(contribution_id, &contribution);
+                if !contribution.is_claimed {
+                    self.add_unclaimed(contribution_id);
+                }
+                let contribution_count = self.contribution_count.get_or_default();
+                self.contribution_ids
+                    .insert(contribution_count, &contribution_id);
+                self.contribution_count.set(&(contribution_count + 1));
+                imported += 1;
+            }
+            Ok(imported)
+        }
+
+        /// Enable or disable publishing each approval as a `system.remark_with_event` chain
+        /// remark, visible on block explorers without decoding contract events.
+        ///
+        /// Only available with the `call-runtime` feature, as not every chain allows contracts
+        /// to call the runtime.
+        #[ink(message)]
+        pub fn set_remark_approvals(&mut self, remark_approvals: bool) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_remark_approvals"))?;
+            if !cfg!(feature = "call-runtime") {
+                return Err(DemoError::CallRuntimeUnavailable);
+            }
+            self.remark_approvals.set(&remark_approvals);
+            Ok(())
+        }
+
+        /// Returns `true` if approvals are published as chain remarks.
+        #[ink(message)]
+        pub fn is_remark_approvals(&self) -> bool {
+            self.remark_approvals.get_or_default()
+        }
+
+        /// Put the clock of every deadline (claims, vesting, challenges, disputes, sessions...)
+        /// `blocks` blocks and `millis` milliseconds ahead of the chain, so that a live demo
+        /// shows their expiry without waiting.
+        ///
+        /// Only available with the `sandbox` feature, which production builds do not enable.
+        #[cfg(feature = "sandbox")]
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_time_offset(
+            &mut self,
+            blocks: BlockNumber,
+            millis: Timestamp,
+        ) -> Result<(), DemoError> {
+            self.time_offset.set(&(blocks, millis));
+            let seq = self.next_seq();
+            self.env().emit_event(TimeOffsetUpdated {
+                seq,
+                blocks,
+                millis,
+            });
+            Ok(())
+        }
+
+        /// Simply returns the blocks and milliseconds the sandbox clock is ahead of the chain.
+        #[cfg(feature = "sandbox")]
+        #[ink(message)]
+        pub fn time_offset(&self) -> (BlockNumber, Timestamp) {
+            self.time_offset.get_or_default()
+        }
+
+        /// Lock the import of contributions for good.
+        #[ink(message)]
+        pub fn finalize_import(&mut self) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("finalize_import"))?;
+            self.import_finalized.set(&true);
+            Ok(())
+        }
+
+        /// Returns `true` if importing contributions is locked.
+        #[ink(message)]
+        pub fn import_finalized(&self) -> bool {
+            self.import_finalized.get_or_default()
+        }
+
+        /// Simply returns the hash chaining every archived contribution, in archival order.
+        #[ink(message)]
+        pub fn archive_root(&self) -> HashValue {
+            self.archive_root.get_or_default()
+        }
+
+        /// Returns `true` if a given contribution is archived.
+        #[ink(message)]
+        pub fn is_archived(&self, contribution_id: ContributionId) -> bool {
+            self.archived.contains(contribution_id)
+        }
+
+        /// Returns the violated invariants of the contract state, if any, for sanity checks
+        /// after upgrades or in end-to-end tests.
+        ///
+        /// The solvency is checked on every call, the contributions on a page of at most 100
+        /// of them in approval order, like `export_page`.
+        #[ink(message)]
+        pub fn self_check(&self, offset: u32, limit: u32) -> Vec<InvariantViolation> {
+            let mut violations = Vec::new();
+            let liabilities = self.liabilities_of(Asset::Native);
+            let balance = self.env().balance();
+            if liabilities > balance {
+                violations.push(InvariantViolation::Insolvent {
+                    liabilities,
+                    balance,
+                });
+            }
+            let end = offset
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.contribution_count.get_or_default());
+            for index in offset..end {
+                let Some(contribution_id) = self.contribution_ids.get(index) else {
+                    violations.push(InvariantViolation::MissingIndex { index });
+                    continue;
+                };
+                match self.contributions.get(contribution_id) {
+                    None if !self.is_archived(contribution_id) => {
+                        violations.push(InvariantViolation::MissingContribution { contribution_id })
+                    }
+                    Some(contribution)
+                        if contribution.is_claimed
+                            != contribution.splits.iter().all(|split| split.is_claimed) =>
+                    {
+                        violations.push(InvariantViolation::ClaimStatusMismatch { contribution_id })
+                    }
+                    _ => {}
+                }
+            }
+            violations
+        }
+
+        /// Repair the known inconsistencies reported by `self_check` on the same page: the claim
+        /// status of the contributions is recomputed from their splits.
+        ///
+        /// Returns the number of repaired contributions.
+        #[ink(message)]
+        pub fn repair(&mut self, offset: u32, limit: u32) -> Result<u32, DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("repair"))?;
+            let mut repaired = 0;
+            for violation in self.self_check(offset, limit) {
+                let InvariantViolation::ClaimStatusMismatch { contribution_id } = violation else {
+                    continue;
+                };
+                if let Some(mut contribution) = self.contributions.get(contribution_id) {
+                    contribution.is_claimed =
+                        contribution.splits.iter().all(|split| split.is_claimed);
+                    self.contributions.insert(contribution_id, &contribution);
+                    if contribution.is_claimed {
+                        self.remove_unclaimed(contribution_id);
+                    } else {
+                        self.add_unclaimed(contribution_id);
+                    }
+                    repaired += 1;
+                }
+            }
+            Ok(repaired)
+        }
+
+        /// Accept a PSP22 token as campaign reward.
+        #[ink(message)]
+        pub fn add_reward_asset(&mut self, token: AccountId) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("add_reward_asset"))?;
+            self.reward_assets.insert(token, &());
+            Ok(())
+        }
+
+        /// Stop accepting a PSP22 token as reward of new campaigns.
+        #[ink(message)]
+        pub fn remove_reward_asset(&mut self, token: AccountId) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("remove_reward_asset"))?;
+            self.reward_assets.remove(token);
+            Ok(())
+        }
+
+        /// Returns `true` if a PSP22 token is accepted as campaign reward.
+        #[ink(message)]
+        pub fn is_reward_asset(&self, token: AccountId) -> bool {
+            self.reward_assets.contains(token)
+        }
+
+        /// Returns a page of the ids of the contributions with shares left to claim, in no
+        /// particular order, so keepers monitor them without scanning every contribution.
+        ///
+        /// At most `MAX_PAGE_SIZE` ids are returned per page.
+        #[ink(message)]
+        pub fn unclaimed_page(&self, offset: u32, limit: u32) -> Vec<ContributionId> {
+            let end = offset
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.total_unclaimed());
+            (offset..end)
+                .filter_map(|index| self.unclaimed_ids.get(index))
+                .collect()
+        }
+
+        /// Simply returns the number of contributions with shares left to claim.
+        #[ink(message)]
+        pub fn total_unclaimed(&self) -> u32 {
+            self.unclaimed_count.get_or_default()
+        }
+
+        /// Simply returns the rewards owed and not claimed yet in a given asset.
+        #[ink(message)]
+        pub fn liabilities_of(&self, asset: Asset) -> Balance {
+            self.liabilities.get(asset).unwrap_or_default()
+        }
+
+        /// Returns the metadata of the asset rewards are currently paid in, to format amounts.
+        ///
+        /// The symbol and decimals of a PSP22 token are queried from its metadata extension.
+        #[ink(message)]
+        pub fn reward_asset_info(&self) -> AssetInfo {
+            let asset = self.active_asset();
+            let Asset::PSP22(token) = asset else {
+                return AssetInfo {
+                    asset,
+                    symbol: None,
+                    decimals: None,
+                };
+            };
+            let token: ink::contract_ref!(PSP22Metadata) = token.into();
+            AssetInfo {
+                asset,
+                symbol: token.token_symbol(),
+                decimals: Some(token.token_decimals()),
+            }
+        }
+
+        /// Simply returns the `AccountId` bound to a given contributor identity.
+        ///
+        /// The identity registry is queried when set.
+        #[ink(message)]
+        pub fn get_identity(&self, contributor_id: ContributorId) -> Option<AccountId> {
+            match self.get_identity_registry() {
+                Some(identity_registry) => {
+                    let identity_registry: ink::contract_ref!(IdentityRegistry) =
+                        identity_registry.into();
+                    identity_registry.get_identity(contributor_id)
+                }
+                None => self.identities.get(contributor_id),
+            }
+        }
+
+        /// Set the shared `IdentityRegistry` contract managing the contributor identities.
+        ///
+        /// Identities are then registered on the registry instead of this contract.
+        #[ink(message)]
+        pub fn set_identity_registry(
+            &mut self,
+            identity_registry: Option<AccountId>,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_identity_registry"))?;
+            self.identity_registry.set(&identity_registry);
+            Ok(())
+        }
+
+        /// Simply returns the shared `IdentityRegistry` contract, if any.
+        #[ink(message)]
+        pub fn get_identity_registry(&self) -> Option<AccountId> {
+            self.identity_registry.get().flatten()
+        }
+
+        /// Set the `RewardVault` contract holding the rewards.
+        ///
+        /// Claimed rewards are then credited on the vault, and contributors withdraw them from it.
+        #[ink(message)]
+        pub fn set_reward_vault(
+            &mut self,
+            reward_vault: Option<AccountId>,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_reward_vault"))?;
+            self.reward_vault.set(&reward_vault);
+            Ok(())
+        }
+
+        /// Simply returns the `RewardVault` contract holding the rewards, if any.
+        #[ink(message)]
+        pub fn get_reward_vault(&self) -> Option<AccountId> {
+            self.reward_vault.get().flatten()
+        }
+
+        /// Set the `RewardStrategy` contract computing the rewards.
+        ///
+        /// The reward of a contribution is then computed by the strategy on approval, before
+        /// being drawn from the budget of the active campaign.
+        #[ink(message)]
+        pub fn set_reward_strategy(
+            &mut self,
+            reward_strategy: Option<AccountId>,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_reward_strategy"))?;
+            self.reward_strategy.set(&reward_strategy);
+            Ok(())
+        }
+
+        /// Set (or unset) the account scoring the difficulty of contributions before approval.
+        #[ink(message)]
+        pub fn set_difficulty_oracle(
+            &mut self,
+            difficulty_oracle: Option<AccountId>,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_difficulty_oracle"))?;
+            self.difficulty_oracle.set(&difficulty_oracle);
+            Ok(())
+        }
+
+        /// Simply returns the account scoring the difficulty of contributions, if any.
+        #[ink(message)]
+        pub fn get_difficulty_oracle(&self) -> Option<AccountId> {
+            self.difficulty_oracle.get().flatten()
+        }
+
+        /// Push the difficulty `score` (up to 100) of a contribution not approved yet.
+        ///
+        /// The score weights its reward on approval, overriding the approved difficulty.
+        #[ink(message)]
+        pub fn push_difficulty(
+            &mut self,
+            contribution_id: ContributionId,
+            score: u8,
+        ) -> Result<(), DemoError> {
+            if self.get_difficulty_oracle() != Some(self.env().caller()) {
+                return Err(DemoError::CallerIsNotOracle);
+            }
+            if let Some(contribution) = self.contributions.get(contribution_id) {
+                return Err(DemoError::ContributionAlreadyApproved {
+                    contributor: contribution.contributor,
+                });
+            }
+            self.difficulties.insert(contribution_id, &score);
+            Ok(())
+        }
+
+        /// Simply returns the difficulty score of a given contribution pushed by the oracle.
+        #[ink(message)]
+        pub fn difficulty_of(&self, contribution_id: ContributionId) -> Option<u8> {
+            self.difficulties.get(contribution_id)
+        }
+
+        /// Simply returns the `RewardStrategy` contract computing the rewards, if any.
+        #[ink(message)]
+        pub fn get_reward_strategy(&self) -> Option<AccountId> {
+            self.reward_strategy.get().flatten()
+        }
+
+        /// Set the PSP34 booster collection whose holders get their claims multiplied by
+        /// `multiplier_bps` (e.g. 15000 for 1.5x).
+        ///
+        /// Boosts are paid from the contract balance, on top of the rewards.
+        #[ink(message)]
+        pub fn set_booster(
+            &mut self,
+            collection: Option<AccountId>,
+            multiplier_bps: u16,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_booster"))?;
+            if multiplier_bps < MAX_BPS {
+                return Err(DemoError::InvalidConfig);
+            }
+            self.booster
+                .set(&collection.map(|collection| (collection, multiplier_bps)));
+            Ok(())
+        }
+
+        /// Set the number of approvals reaching each contributor level, in ascending order
+        /// (e.g. 1, 5, 20 and 50 approvals for levels 1 to 4).
+        ///
+        /// Levels are never lowered: a contributor keeps the level it reached.
+        #[ink(message)]
+        pub fn set_level_thresholds(&mut self, thresholds: Vec<u32>) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_level_thresholds"))?;
+            if thresholds.len() > MAX_LEVELS
+                || thresholds.first() == Some(&0)
+                || thresholds.windows(2).any(|pair| pair[0] >= pair[1])
+            {
+                return Err(DemoError::InvalidConfig);
+            }
+            self.level_thresholds.set(&thresholds);
+            Ok(())
+        }
+
+        /// Simply returns the number of approvals reaching each contributor level.
+        #[ink(message)]
+        pub fn level_thresholds(&self) -> Vec<u32> {
+            self.level_thresholds.get_or_default()
+        }
+
+        /// Simply returns the highest level reached by a given contributor.
+        #[ink(message)]
+        pub fn level_of(&self, account: AccountId) -> u8 {
+            self.levels.get(account).unwrap_or_default()
+        }
+
+        /// Simply returns the PSP34 booster collection and its multiplier, if any.
+        #[ink(message)]
+        pub fn get_booster(&self) -> Option<(AccountId, u16)> {
+            self.booster.get().flatten()
+        }
+
+        /// Subscribe an `OnContributionApproved` contract to approvals.
+        #[ink(message)]
+        pub fn add_subscriber(&mut self, subscriber: AccountId) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("add_subscriber"))?;
+            let mut subscribers = self.get_subscribers();
+            if subscribers.contains(&subscriber) {
+                return Ok(());
+            }
+            if subscribers.len() >= MAX_SUBSCRIBERS {
+                return Err(DemoError::TooManySubscribers);
+            }
+            subscribers.push(subscriber);
+            self.subscribers.set(&subscribers);
+            Ok(())
+        }
+
+        /// Unsubscribe a contract from approvals.
+        #[ink(message)]
+        pub fn remove_subscriber(&mut self, subscriber: AccountId) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("remove_subscriber"))?;
+            let mut subscribers = self.get_subscribers();
+            subscribers.retain(|account| *account != subscriber);
+            self.subscribers.set(&subscribers);
+            Ok(())
+        }
+
+        /// Simply returns the contracts notified of approvals.
+        #[ink(message)]
+        pub fn get_subscribers(&self) -> Vec<AccountId> {
+            self.subscribers.get_or_default()
+        }
+
+        /// Simply returns the reward settings.
+        #[ink(message)]
+        pub fn config(&self) -> Config {
+            self.config.get_or_default()
+        }
+
+        /// Set all the reward settings at once.
+        #[ink(message)]
+        pub fn set_config(&mut self, config: Config) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_config"))?;
+            self.update_config(|current| *current = config.clone())?;
+            let seq = self.next_seq();
+            self.env().emit_event(ConfigUpdated { seq, config });
+            Ok(())
+        }
+
+        /// Set the message of an error in a given language, or remove it with `None`.
+        ///
+        /// The `code` is the index of the `DemoError` variant, i.e. the first byte of its
+        /// encoding.
+        #[ink(message)]
+        pub fn set_error_message(
+            &mut self,
+            code: u32,
+            lang: LanguageCode,
+            message: Option<BoundedString<MAX_ERROR_MESSAGE_LENGTH>>,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_error_message"))?;
+            let count = self.error_message_count.get_or_default();
+            let exists = self.error_messages.contains((code, lang));
+            match message {
+                Some(message) => {
+                    if !exists {
+                        if count >= MAX_ERROR_MESSAGES {
+                            return Err(DemoError::TooManyErrorMessages);
+                        }
+                        self.error_message_count.set(&(count + 1));
+                    }
+                    self.error_messages.insert((code, lang), &message);
+                }
+                None => {
+                    if exists {
+                        self.error_message_count.set(&(count - 1));
+                    }
+                    self.error_messages.remove((code, lang));
+                }
+            }
+            let seq = self.next_seq();
+            self.env()
+                .emit_event(ErrorMessageUpdated { seq, code, lang });
+            Ok(())
+        }
+
+        /// Simply returns the message of an error in a given language, if any.
+        #[ink(message)]
+        pub fn error_message(&self, code: u32, lang: LanguageCode) -> Option<String> {
+            self.error_messages.get((code, lang)).map(String::from)
+        }
+
+        /// Set the reward paid for the next approved contributions.
+        #[ink(message)]
+        pub fn set_reward(&mut self, reward: Balance) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_reward"))?;
+            self.update_config(|config| config.reward = reward)
+        }
+
+        /// Simply returns the reward paid for the next approved contributions.
+        #[ink(message, selector = 0xD759B94D)]
+        pub fn get_reward(&self) -> Balance {
+            self.config().reward
+        }
+
+        /// Returns the storage deposit to attach to `approve`, or to any other approving message
+        /// for each approved contribution: the estimated storage of an approval at the deposit
+        /// per byte.
+        #[ink(message, selector = 0x8E0ECFE5)]
+        pub fn approval_deposit(&self) -> Balance {
+            APPROVAL_STORAGE_BYTES.saturating_mul(self.config().deposit_per_byte)
+        }
+
+        /// Returns the reward paid for the next approved contributions, halved every halving
+        /// period since the start of the active campaign (or since the first block without
+        /// campaign).
+        #[ink(message, selector = 0xDFC67E5B)]
+        pub fn current_reward(&self) -> Balance {
+            let reward = self.get_reward();
+            let halving_period = self.config().halving_period;
+            if halving_period == 0 {
+                return reward;
+            }
+            let start = self
+                .active_campaign()
+                .and_then(|campaign_id| self.campaigns.get(campaign_id))
+                .map_or(0, |campaign| campaign.start);
+            let halvings = self.block_number().saturating_sub(start) / halving_period;
+            reward.checked_shr(halvings).unwrap_or(0)
+        }
+
+        /// Set the minimum native payout of a claim, smaller payouts are kept as dust.
+        #[ink(message)]
+        pub fn set_min_claim(&mut self, min_claim: Balance) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_min_claim"))?;
+            self.update_config(|config| config.min_claim = min_claim)
+        }
+
+        /// Simply returns the minimum native payout of a claim.
+        #[ink(message)]
+        pub fn get_min_claim(&self) -> Balance {
+            self.config().min_claim
+        }
+
+        /// Set the bonus (in basis points of the reward) granted on a first contribution.
+        #[ink(message)]
+        pub fn set_bonus_bps(&mut self, bonus_bps: u16) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_bonus_bps"))?;
+            self.update_config(|config| config.bonus_bps = bonus_bps)
+        }
+
+        /// Simply returns the bonus (in basis points of the reward) granted on a first contribution.
+        #[ink(message)]
+        pub fn get_bonus_bps(&self) -> u16 {
+            self.config().bonus_bps
+        }
+
+        /// Set how the reward is weighted by the difficulty of a contribution.
+        #[ink(message)]
+        pub fn set_reward_mode(&mut self, reward_mode: RewardMode) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_reward_mode"))?;
+            self.update_config(|config| config.reward_mode = reward_mode)
+        }
+
+        /// Simply returns how the reward is weighted by the difficulty of a contribution.
+        #[ink(message)]
+        pub fn get_reward_mode(&self) -> RewardMode {
+            self.config().reward_mode
+        }
+
+        /// Simply returns the number of approved contributions of a given contributor.
+        #[ink(message)]
+        pub fn get_approvals(&self, contributor: AccountId) -> u32 {
+            self.approvals.get(contributor).unwrap_or_default()
+        }
+
+        /// Returns the reputation of a given contributor (in millionths of KUDOS points): its
+        /// KUDOS points, each halving every `REPUTATION_HALF_LIFE` blocks since it was minted.
+        ///
+        /// The raw score is the `balance_of` KUDOS points of the contributor.
+        #[ink(message)]
+        pub fn effective_reputation_of(&self, account: AccountId) -> Balance {
+            let (reputation, updated_at) = self.reputations.get(account).unwrap_or_default();
+            decay(
+                reputation,
+                self.block_number().saturating_sub(updated_at).into(),
+                REPUTATION_HALF_LIFE.into(),
+            )
+        }
+
+        /// Create a reward campaign: rewards are only paid for approvals between the `start`
+        /// and `end` blocks, until the `budget` is exhausted.
+        #[ink(message)]
+        pub fn create_campaign(
+            &mut self,
+            start: BlockNumber,
+            end: BlockNumber,
+            budget: Balance,
+        ) -> Result<CampaignId, DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("create_campaign"))?;
+            self._create_campaign(start, end, budget, Asset::Native, false)
+        }
+
+        /// Create a reward campaign paying its rewards in a given `asset`, either the native
+        /// currency or a registered PSP22 token.
+        #[ink(message)]
+        pub fn create_campaign_with_asset(
+            &mut self,
+            start: BlockNumber,
+            end: BlockNumber,
+            budget: Balance,
+            asset: Asset,
+        ) -> Result<CampaignId, DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("create_campaign_with_asset"))?;
+            self._create_campaign(start, end, budget, asset, false)
+        }
+
+        /// Create a reward campaign whose budget and rewards are denominated in USD cents,
+        /// paid in a given `asset` at its price on claim.
+        #[ink(message)]
+        pub fn create_usd_campaign(
+            &mut self,
+            start: BlockNumber,
+            end: BlockNumber,
+            budget: Balance,
+            asset: Asset,
+        ) -> Result<CampaignId, DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("create_usd_campaign"))?;
+            if self.get_usd_pricing().is_none() {
+                return Err(DemoError::UsdPricingUnset);
+            }
+            self._create_campaign(start, end, budget, asset, true)
+        }
+
+        /// Set (or unset) the conversion of rewards denominated in USD cents.
+        #[ink(message)]
+        pub fn set_usd_pricing(
+            &mut self,
+            usd_pricing: Option<UsdPricing>,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_usd_pricing"))?;
+            if let Some(usd_pricing) = &usd_pricing {
+                if usd_pricing.unit == 0 || usd_pricing.fallback_price == 0 {
+                    return Err(DemoError::InvalidConfig);
+                }
+            }
+            self.usd_pricing.set(&usd_pricing);
+            Ok(())
+        }
+
+        /// Simply returns the conversion of rewards denominated in USD cents, if any.
+        #[ink(message)]
+        pub fn get_usd_pricing(&self) -> Option<UsdPricing> {
+            self.usd_pricing.get().flatten()
+        }
+
+        /// Simply returns a given campaign.
+        #[ink(message)]
+        pub fn get_campaign(&self, campaign_id: CampaignId) -> Option<Campaign> {
+            self.campaigns.get(campaign_id)
+        }
+
+        /// Returns the id of the campaign currently running with some budget left.
+        #[ink(message)]
+        pub fn active_campaign(&self) -> Option<CampaignId> {
+            let campaign_id = self.next_campaign_id.get_or_default().checked_sub(1)?;
+            let campaign = self.campaigns.get(campaign_id)?;
+            let now = self.block_number();
+            (campaign.start <= now
+                && now <= campaign.end
+                && campaign.remaining > 0
+                && !self.campaign_summaries.contains(campaign_id))
+            .then_some(campaign_id)
+        }
+
+        /// Freeze a campaign that is over, recording its summary for good: its budget no
+        /// longer changes, even when its rewards expire.
+        #[ink(message)]
+        pub fn finalize_campaign(
+            &mut self,
+            campaign_id: CampaignId,
+        ) -> Result<CampaignSummary, DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("finalize_campaign"))?;
+            let campaign = self
+                .campaigns
+                .get(campaign_id)
+                .ok_or(DemoError::UnknownCampaign)?;
+            if self.campaign_summaries.contains(campaign_id) {
+                return Err(DemoError::CampaignFinalized);
+            }
+            // A campaign not started yet or out of budget is not active, but not over either
+            if self.block_number() <= campaign.end {
+                return Err(DemoError::CampaignNotOver);
+            }
+            let top = self.campaign_tops.get(campaign_id);
+            let summary = CampaignSummary {
+                total_paid: campaign.budget.saturating_sub(campaign.remaining),
+                contributors: self
+                    .campaign_contributors
+                    .get(campaign_id)
+                    .unwrap_or_default(),
+                top_contributor: top.map(|(account, _)| account),
+                top_reward: top.map_or(0, |(_, reward)| reward),
+                finalized_at: self.block_number(),
+            };
+            self.campaign_summaries.insert(campaign_id, &summary);
+            let seq = self.next_seq();
+            self.env().emit_event(CampaignFinalized {
+                seq,
+                id: campaign_id,
+                summary: summary.clone(),
+            });
+            Ok(summary)
+        }
+
+        /// Simply returns the summary of a given campaign, if finalized.
+        #[ink(message)]
+        pub fn campaign_summary(&self, campaign_id: CampaignId) -> Option<CampaignSummary> {
+            self.campaign_summaries.get(campaign_id)
+        }
+
+        /// Returns the budget left in the active campaign.
+        #[ink(message)]
+        pub fn remaining_budget(&self) -> Balance {
+            self.active_campaign()
+                .and_then(|campaign_id| self.campaigns.get(campaign_id))
+                .map_or(0, |campaign| campaign.remaining)
+        }
+
+        /// Add an account to (or remove it from) the allow list.
+        #[ink(message)]
+        pub fn set_allowed(&mut self, account: AccountId, allowed: bool) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_allowed"))?;
+            if allowed {
+                self.allowed.insert(account, &());
+            } else {
+                self.allowed.remove(account);
+            }
+            let seq = self.next_seq();
+            self.env().emit_event(AllowListUpdated {
+                seq,
+                account,
+                allowed,
+            });
+            Ok(())
+        }
+
+        /// Add an account to (or remove it from) the deny list.
+        #[ink(message)]
+        pub fn set_denied(&mut self, account: AccountId, denied: bool) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_denied"))?;
+            self._set_denied(account, denied);
+            Ok(())
+        }
+
+        /// Ban a contributor from future approvals and claims, with a reason code. Their past
+        /// contributions and claims are kept.
+        #[ink(message)]
+        pub fn ban_contributor(&mut self, account: AccountId, reason: u8) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("ban_contributor"))?;
+            self._set_denied(account, true);
+            self.ban_reasons.insert(account, &reason);
+            let seq = self.next_seq();
+            self.env().emit_event(ContributorBanned {
+                seq,
+                account,
+                reason,
+            });
+            Ok(())
+        }
+
+        /// Lift the ban of a contributor.
+        #[ink(message)]
+        pub fn unban(&mut self, account: AccountId) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("unban"))?;
+            self._set_denied(account, false);
+            self.ban_reasons.remove(account);
+            let seq = self.next_seq();
+            self.env().emit_event(ContributorUnbanned { seq, account });
+            Ok(())
+        }
+
+        /// Returns the reason code of the ban of a given account, if banned.
+        #[ink(message)]
+        pub fn banned(&self, account: AccountId) -> Option<u8> {
+            if !self.is_denied(account) {
+                return None;
+            }
+            self.ban_reasons.get(account)
+        }
+
+        /// Allow (or disallow) an account to be bound to many contributor identities, for
+        /// legitimate shared accounts.
+        #[ink(message)]
+        pub fn set_shared(&mut self, account: AccountId, shared: bool) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_shared"))?;
+            if shared {
+                self.shared.insert(account, &());
+            } else {
+                self.shared.remove(account);
+            }
+            let seq = self.next_seq();
+            self.env().emit_event(SharedAccountUpdated {
+                seq,
+                account,
+                shared,
+            });
+            Ok(())
+        }
+
+        /// Returns `true` if the account can be bound to many contributor identities.
+        #[ink(message)]
+        pub fn is_shared(&self, account: AccountId) -> bool {
+            self.shared.contains(account)
+        }
+
+        /// Simply returns the (first) contributor identity bound to a given account.
+        ///
+        /// The identity registry is queried when set.
+        #[ink(message)]
+        pub fn get_contributor_id(&self, account: AccountId) -> Option<ContributorId> {
+            self.identity_of(account)
+        }
+
+        /// Attach a public profile to the caller's identity.
+        #[ink(message)]
+        pub fn set_profile(&mut self, profile: Profile) -> Result<(), DemoError> {
+            let caller = self.env().caller();
+            if self.identity_of(caller).is_none() {
+                return Err(DemoError::IdentityNotRegistered);
+            }
+            let is_valid_uri = |uri: &Option<BoundedString<MAX_URI_LENGTH>>| {
+                uri.as_ref().map_or(true, |uri| !uri.is_empty())
+            };
+            if profile.display_name.is_empty()
+                || !is_valid_uri(&profile.avatar_uri)
+                || !is_valid_uri(&profile.website)
+            {
+                return Err(DemoError::InvalidProfile);
+            }
+            self.profiles.insert(caller, &profile);
+            Ok(())
+        }
+
+        /// Link a DID (e.g. a KILT `did:kilt:` DID) to the caller's identity, replacing the
+        /// previous one, unverified until the owner attests it with `verify_did`.
+        #[ink(message)]
+        pub fn link_did(
+            &mut self,
+            did_uri: BoundedString<MAX_URI_LENGTH>,
+        ) -> Result<(), DemoError> {
+            let caller = self.env().caller();
+            if self.identity_of(caller).is_none() {
+                return Err(DemoError::IdentityNotRegistered);
+            }
+            let mut parts = did_uri.splitn(3, ':');
+            if parts.next() != Some("did")
+                || parts.next().map_or(true, str::is_empty)
+                || parts.next().map_or(true, str::is_empty)
+            {
+                return Err(DemoError::InvalidDid);
+            }
+            let uri = String::from(did_uri.as_str());
+            self.dids.insert(
+                caller,
+                &Did {
+                    uri: did_uri,
+                    is_verified: false,
+                },
+            );
+            let seq = self.next_seq();
+            self.env().emit_event(DidLinked {
+                seq,
+                account: caller,
+                uri,
+            });
+            Ok(())
+        }
+
+        /// Attest that `account` controls its linked DID, once checked off-chain (e.g. with a
+        /// KILT credential). The `did_uri` must be the linked one, so a DID linked meanwhile is
+        /// not attested by mistake.
+        #[ink(message)]
+        pub fn verify_did(
+            &mut self,
+            account: AccountId,
+            did_uri: BoundedString<MAX_URI_LENGTH>,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("verify_did"))?;
+            let mut did = self
+                .did_of(account)
+                .filter(|did| did.uri == did_uri)
+                .ok_or(DemoError::InvalidDid)?;
+            did.is_verified = true;
+            self.dids.insert(account, &did);
+            let seq = self.next_seq();
+            self.env().emit_event(DidVerified { seq, account });
+            Ok(())
+        }
+
+        /// Simply returns the DID linked to a given account, if any.
+        #[ink(message)]
+        pub fn did_of(&self, account: AccountId) -> Option<Did> {
+            self.dids.get(account)
+        }
+
+        /// Send the future rewards of the caller's identity to another address, e.g. a cold
+        /// wallet.
+        ///
+        /// The change takes effect from the next block, so that a hijacked session cannot
+        /// redirect a claim made in the same block.
+        #[ink(message, selector = 0xC8DB9B86)]
+        pub fn set_payout_address(&mut self, address: AccountId) -> Result<(), DemoError> {
+            let caller = self.env().caller();
+            let contributor_id = self
+                .identity_of(caller)
+                .ok_or(DemoError::IdentityNotRegistered)?;
+            self.ensure_valid_account(address)?;
+            self.payout_targets.insert(address, &contributor_id);
+            let effective_at = self.block_number().saturating_add(1);
+            self.payouts.insert(
+                caller,
+                &Payout {
+                    previous: self.payout_address_of(caller),
+                    next: address,
+                    effective_at,
+                },
+            );
+            let seq = self.next_seq();
+            self.env().emit_event(PayoutAddressChanged {
+                seq,
+                account: caller,
+                payout: address,
+                effective_at,
+            });
+            Ok(())
+        }
+
+        /// Returns the address the rewards of a given account are currently paid to: the
+        /// account itself unless it set a payout address.
+        #[ink(message, selector = 0x1AE5B453)]
+        pub fn payout_address_of(&self, account: AccountId) -> AccountId {
+            match self.payouts.get(account) {
+                Some(payout) if self.block_number() >= payout.effective_at => payout.next,
+                Some(payout) => payout.previous,
+                None => account,
+            }
+        }
+
+        /// Opt the caller's identity in to (or out of) the payout of its rewards on approval,
+        /// sparing the claim transaction.
+        #[ink(message)]
+        pub fn set_auto_claim(&mut self, enabled: bool) -> Result<(), DemoError> {
+            let contributor_id = self
+                .identity_of(self.env().caller())
+                .ok_or(DemoError::IdentityNotRegistered)?;
+            if enabled {
+                self.auto_claims.insert(contributor_id, &());
+            } else {
+                self.auto_claims.remove(contributor_id);
+            }
+            let seq = self.next_seq();
+            self.env().emit_event(AutoClaimUpdated {
+                seq,
+                contributor_id,
+                enabled,
+            });
+            Ok(())
+        }
+
+        /// Returns `true` if the rewards of a given identity are paid on approval.
+        #[ink(message)]
+        pub fn is_auto_claim(&self, contributor_id: ContributorId) -> bool {
+            self.auto_claims.contains(contributor_id)
+        }
+
+        /// Returns the identity a suspected duplicate account was the payout address of, if
+        /// the account is flagged.
+        #[ink(message)]
+        pub fn suspected_duplicate_of(&self, account: AccountId) -> Option<ContributorId> {
+            self.suspected_duplicates.get(account)
+        }
+
+        /// Clear a suspected duplicate account once manually reviewed, lifting the limit of
+        /// its claims.
+        #[ink(message)]
+        pub fn clear_suspected_duplicate(&mut self, account: AccountId) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("clear_suspected_duplicate"))?;
+            if self.suspected_duplicates.take(account).is_none() {
+                return Ok(());
+            }
+            self.suspect_claims.remove(account);
+            let seq = self.next_seq();
+            self.env()
+                .emit_event(SuspectedDuplicateCleared { seq, account });
+            Ok(())
+        }
+
+        /// Simply returns the public profile of a given account, if any.
+        #[ink(message)]
+        pub fn profile_of(&self, account: AccountId) -> Option<Profile> {
+            self.profiles.get(account)
+        }
+
+        /// Set the public key the caller's identity receives encrypted memos with.
+        #[ink(message)]
+        pub fn set_encryption_pubkey(
+            &mut self,
+            pubkey: EncryptionPublicKey,
+        ) -> Result<(), DemoError> {
+            let caller = self.env().caller();
+            if self.identity_of(caller).is_none() {
+                return Err(DemoError::IdentityNotRegistered);
+            }
+            self.encryption_pubkeys.insert(caller, &pubkey);
+            Ok(())
+        }
+
+        /// Simply returns the encryption public key of a given account.
+        #[ink(message)]
+        pub fn encryption_pubkey_of(&self, account: AccountId) -> Option<EncryptionPublicKey> {
+            self.encryption_pubkeys.get(account)
+        }
+
+        /// Set the project indexing the approval and claim events, so explorers subscribe to
+        /// the activity of a single project.
+        #[ink(message)]
+        pub fn set_project_id(&mut self, project_id: ProjectId) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_project_id"))?;
+            self.project_id.set(&project_id);
+            Ok(())
+        }
+
+        /// Simply returns the project indexing the approval and claim events.
+        #[ink(message)]
+        pub fn get_project_id(&self) -> ProjectId {
+            self.project_id.get_or_default()
+        }
+
+        /// Returns the topic indexing a given event of a project.
+        ///
+        /// ink! prefixes each topic with its event and field names, so the topic differs for
+        /// each kind of event.
+        #[ink(message)]
+        pub fn topic_for_project(&self, project_id: ProjectId, event: ProjectEvent) -> Hash {
+            let prefix: &[u8] = match event {
+                ProjectEvent::Approval => b"Demo::ContributionApproval::project",
+                ProjectEvent::AlreadyApproved => b"Demo::AlreadyApproved::project",
+                ProjectEvent::Claim => b"Demo::ContributionClaim::project",
+            };
+            let mut prefixed = Vec::from(prefix);
+            prefixed.extend_from_slice(project_id.as_ref());
+            Hash::from(self.env().hash_bytes::<Blake2x256>(&prefixed))
+        }
+
+        /// Set the share (in basis points) of a maintainer in the fees of a project, or remove
+        /// the maintainer with a zero share. The shares of a project sum to at most 10000 bps.
+        #[ink(message)]
+        pub fn set_maintainer_share(
+            &mut self,
+            project_id: ProjectId,
+            maintainer: AccountId,
+            bps: u16,
+        ) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_maintainer_share"))?;
+            self.ensure_valid_account(maintainer)?;
+            let mut shares = self.maintainer_shares_of(project_id);
+            shares.retain(|(account, _)| *account != maintainer);
+            if bps > 0 {
+                if shares.len() >= MAX_MAINTAINERS {
+                    return Err(DemoError::TooManyMaintainers);
+                }
+                shares.push((maintainer, bps));
+            }
+            let total_bps = shares
+                .iter()
+                .try_fold(0u16, |total, (_, bps)| total.checked_add(*bps));
+            if total_bps.map_or(true, |total_bps| total_bps > MAX_BPS) {
+                return Err(DemoError::InvalidSplits);
+            }
+            self.maintainer_shares.insert(project_id, &shares);
+            let seq = self.next_seq();
+            self.env().emit_event(MaintainerShareUpdated {
+                seq,
+                project: project_id,
+                maintainer,
+                bps,
+            });
+            Ok(())
+        }
+
+        /// Simply returns the maintainers of a given project and their share of its fees.
+        #[ink(message)]
+        pub fn maintainer_shares_of(&self, project_id: ProjectId) -> Vec<(AccountId, u16)> {
+            self.maintainer_shares.get(project_id).unwrap_or_default()
+        }
+
+        /// Deposit the transferred value as fees of a project, to be distributed to its
+        /// maintainers.
+        #[ink(message, payable)]
+        pub fn deposit_project_fees(&mut self, project_id: ProjectId) -> Result<(), DemoError> {
+            let amount = self.env().transferred_value();
+            if amount == 0 {
+                return Err(DemoError::ZeroDeposit);
+            }
+            self.project_fees.insert(
+                project_id,
+                &self.project_fees_of(project_id).saturating_add(amount),
+            );
+            self.owe(Asset::Native, amount);
+            self.record(Movement::Fund, amount);
+            let seq = self.next_seq();
+            self.env().emit_event(ProjectFeesDeposited {
+                seq,
+                project: project_id,
+                depositor: self.env().caller(),
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Simply returns the fees of a given project not distributed yet.
+        #[ink(message)]
+        pub fn project_fees_of(&self, project_id: ProjectId) -> Balance {
+            self.project_fees.get(project_id).unwrap_or_default()
+        }
+
+        /// Distribute the fees of a project to its maintainers according to their shares.
+        ///
+        /// Anyone can call it. The rounding dust and the share left unassigned are carried over
+        /// to the next distribution. Returns the amount distributed.
+        #[ink(message)]
+        #[modifiers(non_reentrant)]
+        pub fn distribute_fees(&mut self, project_id: ProjectId) -> Result<Balance, DemoError> {
+            let shares = self.maintainer_shares_of(project_id);
+            if shares.is_empty() {
+                return Err(DemoError::NoMaintainers);
+            }
+            let fees = self.project_fees_of(project_id);
+            let mut distributed: Balance = 0;
+            for (maintainer, bps) in shares {
+                let amount = mul_div(fees, bps.into(), MAX_BPS.into());
+                if amount == 0 {
+                    continue;
+                }
+                self.pay(maintainer, amount, Asset::Native, Movement::Fee)?;
+                distributed = distributed.saturating_add(amount);
+            }
+            let dust = fees.saturating_sub(distributed);
+            self.project_fees.insert(project_id, &dust);
+            self.settle(Asset::Native, distributed);
+            let seq = self.next_seq();
+            self.env().emit_event(FeesDistributed {
+                seq,
+                project: project_id,
+                amount: distributed,
+                dust,
+            });
+            Ok(distributed)
+        }
+
+        /// Simply returns the canonical algorithm hashing identities.
+        #[ink(message)]
+        pub fn hash_algorithm(&self) -> HashAlgorithm {
+            self.hash_algorithm.get_or_default()
+        }
+
+        /// Returns the identity hash of a handle with the canonical algorithm.
+        #[ink(message)]
+        pub fn hash_identity(&self, handle: String) -> HashValue {
+            self.hash_algorithm().hash(handle.as_bytes())
+        }
+
+        /// Returns the salted identity hash of a handle: the Blake2x256 hash of (`handle`,
+        /// `salt`).
+        ///
+        /// Privacy-conscious contributors register it as identity instead of the hash of their
+        /// handle, and share the salt with the bot only.
+        #[ink(message)]
+        pub fn salted_identity_hash(&self, handle: String, salt: HashValue) -> HashValue {
+            self.env()
+                .hash_encoded::<Blake2x256, _>(&(handle.as_str(), salt))
+        }
+
+        /// Reveal the handle behind the caller's salted identity.
+        #[ink(message)]
+        pub fn reveal_identity(
+            &mut self,
+            preimage: String,
+            salt: HashValue,
+        ) -> Result<(), DemoError> {
+            let caller = self.env().caller();
+            let contributor_id = self
+                .identity_of(caller)
+                .ok_or(DemoError::IdentityNotRegistered)?;
+            if self.salted_identity_hash(preimage.clone(), salt) != contributor_id.1 {
+                return Err(DemoError::InvalidPreimage);
+            }
+            let preimage: BoundedString<MAX_HANDLE_LENGTH> =
+                BoundedString::try_from(preimage).map_err(|_| DemoError::InvalidPreimage)?;
+            self.revealed.insert(contributor_id, &preimage);
+            let seq = self.next_seq();
+            self.env().emit_event(IdentityRevealed {
+                seq,
+                contributor_id,
+                handle: preimage,
+            });
+            Ok(())
+        }
+
+        /// Simply returns the revealed handle of a given contributor identity, if any.
+        #[ink(message)]
+        pub fn revealed_handle(&self, contributor_id: ContributorId) -> Option<String> {
+            self.revealed.get(contributor_id).map(String::from)
+        }
+
+        /// Enable or disable the strict mode where only allowed accounts can register identities.
+        #[ink(message)]
+        pub fn set_strict_mode(&mut self, strict_mode: bool) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_strict_mode"))?;
+            self.strict_mode.set(&strict_mode);
+            Ok(())
+        }
+
+        /// Returns `true` if the account is on the allow list.
+        #[ink(message)]
+        pub fn is_allowed(&self, account: AccountId) -> bool {
+            self.allowed.contains(account)
+        }
+
+        /// Returns `true` if the account is on the deny list.
+        #[ink(message)]
+        pub fn is_denied(&self, account: AccountId) -> bool {
+            self.denied.contains(account)
+        }
+
+        /// Returns `true` if only allowed accounts can register identities.
+        #[ink(message)]
+        pub fn is_strict_mode(&self) -> bool {
+            self.strict_mode.get_or_default()
+        }
+
+        /// Set the length of an epoch in blocks (zero disables streaks).
+        #[ink(message)]
+        pub fn set_epoch_length(&mut self, epoch_length: BlockNumber) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_epoch_length"))?;
+            self.update_config(|config| config.epoch_length = epoch_length)
+        }
+
+        /// Simply returns the length of an epoch in blocks.
+        #[ink(message)]
+        pub fn get_epoch_length(&self) -> BlockNumber {
+            self.config().epoch_length
+        }
+
+        /// Set the bonus (in basis points of the reward) granted per consecutive epoch of a streak.
+        #[ink(message)]
+        pub fn set_streak_bonus_bps(&mut self, streak_bonus_bps: u16) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_streak_bonus_bps"))?;
+            self.update_config(|config| config.streak_bonus_bps = streak_bonus_bps)
+        }
+
+        /// Simply returns the bonus (in basis points of the reward) granted per consecutive epoch.
+        #[ink(message)]
+        pub fn get_streak_bonus_bps(&self) -> u16 {
+            self.config().streak_bonus_bps
+        }
+
+        /// Returns the number of consecutive epochs with an approved contribution of a given
+        /// contributor, up to the current epoch.
+        #[ink(message)]
+        pub fn streak_of(&self, account: AccountId) -> u32 {
+            let Some(epoch) = self.current_epoch() else {
+                return 0;
+            };
+            match self.streaks.get(account) {
+                Some(streak) if streak.last_epoch + 1 >= epoch => streak.length,
+                _ => 0,
+            }
+        }
+
+        /// Set the delay between scheduling and executing a sweep (in milliseconds).
+        #[ink(message)]
+        pub fn set_sweep_timelock(&mut self, sweep_timelock: Timestamp) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_sweep_timelock"))?;
+            self.sweep_timelock.set(&sweep_timelock);
+            Ok(())
+        }
+
+        /// Simply returns the delay between scheduling and executing a sweep (in milliseconds).
+        #[ink(message)]
+        pub fn get_sweep_timelock(&self) -> Timestamp {
+            self.sweep_timelock.get().unwrap_or(DEFAULT_SWEEP_TIMELOCK)
+        }
+
+        /// Schedule a drain of the whole contract balance to `to`, executable once the sweep
+        /// timelock is over.
+        #[ink(message)]
+        pub fn schedule_sweep(&mut self, to: AccountId) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("schedule_sweep"))?;
+            if self.get_scheduled_sweep().is_some() {
+                return Err(DemoError::SweepAlreadyScheduled);
+            }
+
+            let executable_at = self
+                .block_timestamp()
+                .saturating_add(self.get_sweep_timelock());
+            self.sweep.set(&Some(Sweep { to, executable_at }));
+            let seq = self.next_seq();
+            self.env().emit_event(SweepScheduled {
+                seq,
+                to,
+                executable_at,
+            });
+            Ok(())
+        }
+
+        /// Cancel the scheduled sweep.
+        #[ink(message)]
+        pub fn cancel_sweep(&mut self) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("cancel_sweep"))?;
+            let sweep = self
+                .get_scheduled_sweep()
+                .ok_or(DemoError::NoSweepScheduled)?;
+            self.sweep.set(&None);
+            let seq = self.next_seq();
+            self.env().emit_event(SweepCancelled { seq, to: sweep.to });
+            Ok(())
+        }
+
+        /// Drain the contract balance (above the existential deposit) once the timelock of the
+        /// scheduled sweep is over.
+        #[ink(message)]
+        #[modifiers(non_reentrant)]
+        pub fn execute_sweep(&mut self) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("execute_sweep"))?;
+            self._execute_sweep()
+        }
+
+        /// Executes the scheduled sweep, see `execute_sweep`.
+        fn _execute_sweep(&mut self) -> Result<(), DemoError> {
+            let sweep = self
+                .get_scheduled_sweep()
+                .ok_or(DemoError::NoSweepScheduled)?;
+            if !Due::At(sweep.executable_at).is_due(self.now()) {
+                return Err(DemoError::SweepTimelocked);
+            }
+
+            let amount = self
+                .env()
+                .balance()
+                .saturating_sub(self.env().minimum_balance());
+            self.env()
+                .transfer(sweep.to, amount)
+                .map_err(|_| DemoError::TransferFailed)?;
+            self.sweep.set(&None);
+            self.record(Movement::Withdraw, amount);
+            let seq = self.next_seq();
+            self.env().emit_event(SweepExecuted {
+                seq,
+                to: sweep.to,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Simply returns the scheduled sweep.
+        #[ink(message)]
+        pub fn get_scheduled_sweep(&self) -> Option<Sweep> {
+            self.sweep.get().flatten()
+        }
+
+        /// Claim the caller's intent to take over the ownership, proving it can sign calls to
+        /// the contract (e.g. as a multisig or a proxy).
+        #[ink(message)]
+        pub fn claim_ownership_intent(&mut self) -> Result<(), DemoError> {
+            let caller = self.env().caller();
+            self.ensure_valid_account(caller)?;
+            self.ownership_intents.insert(caller, &());
+            let seq = self.next_seq();
+            self.env().emit_event(OwnershipIntentClaimed {
+                seq,
+                account: caller,
+            });
+            Ok(())
+        }
+
+        /// Returns `true` if `account` claimed its intent to take over the ownership.
+        #[ink(message)]
+        pub fn has_ownership_intent(&self, account: AccountId) -> bool {
+            self.ownership_intents.contains(account)
+        }
+
+        /// Transfer the ownership to `new_owner`, only once it claimed its intent to take it
+        /// over, so the ownership cannot be lost to an account nobody signs for.
+        ///
+        /// The unchecked `Ownable::transfer_ownership` stays available for the handover of a
+        /// fresh deployment by its factory and for the owners who know the new owner signs:
+        /// only this message gives the guarantee above.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn transfer_ownership_checked(
+            &mut self,
+            new_owner: AccountId,
+        ) -> Result<(), DemoError> {
+            if self.ownership_intents.take(new_owner).is_none() {
+                return Err(DemoError::OwnershipIntentMissing);
+            }
+            Ok(ownable::Internal::_transfer_ownership(
+                self,
+                Some(new_owner),
+            )?)
+        }
+
+        /// Schedule the termination of the contract in favour of `beneficiary`, executable
+        /// once the sweep timelock is over.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn schedule_termination(&mut self, beneficiary: AccountId) -> Result<(), DemoError> {
+            let executable_at = self
+                .block_timestamp()
+                .saturating_add(self.get_sweep_timelock());
+            self.termination.set(&Some(Sweep {
+                to: beneficiary,
+                executable_at,
+            }));
+            let seq = self.next_seq();
+            self.env().emit_event(TerminationScheduled {
+                seq,
+                beneficiary,
+                executable_at,
+            });
+            Ok(())
+        }
+
+        /// Cancel the scheduled termination.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn cancel_termination(&mut self) -> Result<(), DemoError> {
+            self.get_scheduled_termination()
+                .ok_or(DemoError::NoTerminationScheduled)?;
+            self.termination.set(&None);
+            Ok(())
+        }
+
+        /// Terminate the contract, sending its whole balance to `beneficiary`, once the
+        /// timelock of the scheduled termination is over.
+        ///
+        /// Fails while rewards are owed in any asset, unless `force` is set, only native
+        /// contribution rewards are owed and every one of them is past its claim deadline.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn terminate(&mut self, beneficiary: AccountId, force: bool) -> Result<(), DemoError> {
+            let termination = self
+                .get_scheduled_termination()
+                .filter(|termination| termination.to == beneficiary)
+                .ok_or(DemoError::NoTerminationScheduled)?;
+            if !Due::At(termination.executable_at).is_due(self.now()) {
+                return Err(DemoError::TerminationTimelocked);
+            }
+            let is_settled = if force {
+                self.owes_expired_rewards_only()
+            } else {
+                self.indebted_assets.get_or_default() == 0
+            };
+            if !is_settled {
+                return Err(DemoError::OutstandingLiabilities);
+            }
+            self.env().terminate_contract(beneficiary)
+        }
+
+        /// Enable or disable governance, letting KUDOS points holders change the settings
+        /// through proposals.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_governance(&mut self, governance: bool) -> Result<(), DemoError> {
+            self.governance.set(&governance);
+            Ok(())
+        }
+
+        /// Returns `true` if governance is enabled.
+        #[ink(message)]
+        pub fn is_governance(&self) -> bool {
+            self.governance.get_or_default()
+        }
+
+        /// Propose a change of the settings, open to votes for `VOTING_PERIOD` blocks.
+        ///
+        /// Only KUDOS points holders can propose.
+        #[ink(message)]
+        pub fn propose(&mut self, action: ProposalAction) -> Result<ProposalId, DemoError> {
+            if !self.is_governance() {
+                return Err(DemoError::GovernanceDisabled);
+            }
+            let proposer = self.env().caller();
+            if self.points.get(proposer).unwrap_or_default() == 0 {
+                return Err(DemoError::NoVotingPower);
+            }
+
+            let id = self.proposal_count.get_or_default();
+            let proposal = Proposal {
+                action,
+                proposer,
+                end: self.block_number().saturating_add(VOTING_PERIOD),
+                votes_for: 0,
+                votes_against: 0,
+                executed: false,
+            };
+            self.proposals.insert(id, &proposal);
+            self.proposal_count.set(&(id + 1));
+            let seq = self.next_seq();
+            self.env().emit_event(ProposalCreated { seq, id, proposer });
+            Ok(id)
+        }
+
+        /// Vote for (or against) a proposal, weighted by the caller's KUDOS points.
+        #[ink(message)]
+        pub fn vote(&mut self, id: ProposalId, support: bool) -> Result<(), DemoError> {
+            if !self.is_governance() {
+                return Err(DemoError::GovernanceDisabled);
+            }
+            let mut proposal = self.proposals.get(id).ok_or(DemoError::UnknownProposal)?;
+            if self.block_number() > proposal.end {
+                return Err(DemoError::VotingClosed);
+            }
+            let voter = self.env().caller();
+            if self.votes.contains((id, voter)) {
+                return Err(DemoError::AlreadyVoted);
+            }
+            let weight = self.points.get(voter).unwrap_or_default();
+            if weight == 0 {
+                return Err(DemoError::NoVotingPower);
+            }
+
+            if support {
+                proposal.votes_for = proposal.votes_for.saturating_add(weight);
+            } else {
+                proposal.votes_against = proposal.votes_against.saturating_add(weight);
+            }
+            self.proposals.insert(id, &proposal);
+            self.votes.insert((id, voter), &());
+            let seq = self.next_seq();
+            self.env().emit_event(Voted {
+                seq,
+                id,
+                voter,
+                support,
+                weight,
+            });
+            Ok(())
+        }
+
+        /// Execute a proposal once its vote is over, if it got more votes for than against
+        /// and at least `QUORUM_BPS` of the KUDOS points supply voted.
+        #[ink(message)]
+        pub fn execute(&mut self, id: ProposalId) -> Result<(), DemoError> {
+            if !self.is_governance() {
+                return Err(DemoError::GovernanceDisabled);
+            }
+            let mut proposal = self.proposals.get(id).ok_or(DemoError::UnknownProposal)?;
+            if self.block_number() <= proposal.end {
+                return Err(DemoError::VotingNotClosed);
+            }
+            if proposal.executed || proposal.votes_for <= proposal.votes_against {
+                return Err(DemoError::ProposalNotExecutable);
+            }
+            let quorum = mul_div(
+                self.points_supply.get_or_default(),
+                QUORUM_BPS.into(),
+                MAX_BPS.into(),
+            );
+            if proposal.votes_for.saturating_add(proposal.votes_against) < quorum {
+                return Err(DemoError::QuorumNotReached);
+            }
+
+            proposal.executed = true;
+            self.proposals.insert(id, &proposal);
+            match proposal.action {
+                ProposalAction::SetConfig(config) => {
+                    self.update_config(|current| *current = config.clone())?;
+                    let seq = self.next_seq();
+                    self.env().emit_event(ConfigUpdated { seq, config });
+                }
+                ProposalAction::SetDenied { account, denied } => self._set_denied(account, denied),
+                ProposalAction::SetRole { account, role } => self._set_role(account, role),
+                ProposalAction::SetPermission {
+                    role,
+                    selector,
+                    allowed,
+                } => self._set_permission(role, selector, allowed),
+            }
+            let seq = self.next_seq();
+            self.env().emit_event(ProposalExecuted { seq, id });
+            Ok(())
+        }
+
+        /// Simply returns a given governance proposal.
+        #[ink(message)]
+        pub fn get_proposal(&self, id: ProposalId) -> Option<Proposal> {
+            self.proposals.get(id)
+        }
+
+        /// Returns the sequence number of the last emitted event, so that event consumers can
+        /// detect the events they missed.
+        ///
+        /// Every event but the PSP22 `Transfer` of KUDOS points carries its sequence number,
+        /// starting from 1.
+        #[ink(message)]
+        pub fn latest_seq(&self) -> u64 {
+            self.event_seq.get_or_default()
+        }
+
+        /// Simply returns the scheduled termination.
+        #[ink(message)]
+        pub fn get_scheduled_termination(&self) -> Option<Sweep> {
+            self.termination.get().flatten()
+        }
+
+        /// Returns the latest actions of a given contributor with their block, oldest first.
+        ///
+        /// Only the latest `ACTIVITY_LOG_CAPACITY` actions are kept.
+        #[ink(message)]
+        pub fn activity_of(&self, account: AccountId) -> Vec<(BlockNumber, Activity)> {
+            self.activities.get(account).unwrap_or_default()
+        }
+
+        /// Returns the contributions approved at or after a given block, oldest first.
+        ///
+        /// Only the latest `APPROVAL_LOG_CAPACITY` approvals are kept.
+        #[ink(message)]
+        pub fn approvals_since(&self, block: BlockNumber) -> Vec<ContributionId> {
+            self.approval_log
+                .get_or_default()
+                .iter()
+                .filter(|(approved_at, _)| *approved_at >= block)
+                .map(|(_, contribution_id)| *contribution_id)
+                .collect()
+        }
+
+        /// Dry-run `approve`: reproduces all the checks it does without modifying the state,
+        /// with the transferred value as the storage deposit.
+        #[ink(message, payable)]
+        pub fn can_approve(
+            &self,
+            contribution_id: ContributionId,
+            contributor: AccountId,
+        ) -> Result<(), DemoError> {
+            self.ensure_approver(ink::selector_bytes!("Workflow::approve"))?;
+            let needed = self.approval_deposit();
+            if self.env().transferred_value() < needed {
+                return Err(DemoError::InsufficientDeposit { needed });
+            }
+            self.check_approval(contribution_id, &[(contributor, MAX_BPS)])
+                .map(|_| ())
+        }
+
+        /// Set the maximum number of approvals per contributor in a campaign (zero means no cap).
+        #[ink(message)]
+        pub fn set_contributor_cap(&mut self, contributor_cap: u32) -> Result<(), DemoError> {
+            self.ensure_permitted(ink::selector_bytes!("set_contributor_cap"))?;
+            self.update_config(|config| config.contributor_cap = contributor_cap)
+        }
+
+        /// Simply returns the maximum number of approvals per contributor in a campaign.
+        #[ink(message)]
+        pub fn get_contributor_cap(&self) -> u32 {
+            self.config().contributor_cap
+        }
+
+        /// Simply returns the number of approved contributions of a contributor in a campaign.
+        #[ink(message)]
+        pub fn get_campaign_approvals(
+            &self,
+            campaign_id: CampaignId,
+            contributor: AccountId,
+        ) -> u32 {
+            self.campaign_approvals
+                .get((campaign_id, contributor))
+                .unwrap_or_default()
+        }
+
+        /// Returns `true` if the receipt was issued on a claim.
+        #[ink(message, selector = 0x8A576109)]
+        pub fn verify_receipt(&self, receipt: HashValue) -> bool {
+            self.receipts.contains(receipt)
+        }
+
+        /// Validates and saves the reward settings changed by `update`.
+        fn update_config(&mut self, update: impl FnOnce(&mut Config)) -> Result<(), DemoError> {
+            let mut config = self.config();
+            update(&mut config);
+            if config.bonus_bps > MAX_BPS
+                || config.streak_bonus_bps > MAX_BPS
+                || config.relayer_fee_bps > MAX_BPS
+                || config.sweep_bounty_bps > MAX_BPS
+            {
+                return Err(DemoError::InvalidConfig);
+            }
+            self.config.set(&config);
+            Ok(())
+        }
+
+        /// Returns the (first) contributor identity bound to `account`, asking the identity
+        /// registry when set.
+        fn identity_of(&self, account: AccountId) -> Option<ContributorId> {
+            match self.get_identity_registry() {
+                Some(identity_registry) => {
+                    let identity_registry: ink::contract_ref!(IdentityRegistry) =
+                        identity_registry.into();
+                    identity_registry.get_contributor_id(account)
+                }
+                None => self.accounts.get(account),
+            }
+        }
+
+        /// Binds `account` to a contributor identity.
+        fn _register_identity(
+            &mut self,
+            contributor_id: ContributorId,
+            account: AccountId,
+        ) -> Result<(), DemoError> {
+            if self.get_identity_registry().is_some() {
+                return Err(DemoError::IdentityRegistryInUse);
+            }
+            self.ensure_valid_account(account)?;
+            if self.identities.contains(contributor_id) {
+                return Err(DemoError::IdentityAlreadyRegistered);
+            }
+            if self.accounts.contains(account) && !self.is_shared(account) {
+                return Err(DemoError::AccountAlreadyRegistered);
+            }
+
+            self.identities.insert(contributor_id, &account);
+            if !self.accounts.contains(account) {
+                self.accounts.insert(account, &contributor_id);
+            }
+            self.log_activity(account, Activity::Registered);
+            let seq = self.next_seq();
+            self.env().emit_event(IdentityRegistered {
+                seq,
+                contributor_id,
+                caller: account,
+            });
+            if let Some(other) = self
+                .payout_targets
+                .get(account)
+                .filter(|other| *other != contributor_id)
+            {
+                self.suspected_duplicates.insert(account, &other);
+                let seq = self.next_seq();
+                self.env().emit_event(SuspectedDuplicate {
+                    seq,
+                    account,
+                    contributor_id,
+                    other,
+                });
+            }
+            Ok(())
+        }
+
+        /// Records an approved contribution shared by its co-authors, rewarded from the active
+        /// campaign.
+        ///
+        /// The reward is weighted by `difficulty`, unless a fixed `reward` is given.
+        fn _approve(
+            &mut self,
+            contribution_id: ContributionId,
+            splits: Vec<(AccountId, u16)>,
+            difficulty: u8,
+            reward: Option<Balance>,
+        ) -> Result<(), DemoError> {
+            self.record_approval(contribution_id, splits, difficulty, reward, false)
+        }
+
+        /// Records an approved contribution shared by its co-authors, see `_approve`.
+        ///
+        /// A `sponsored` reward is paid in the native currency transferred by its sponsor,
+        /// outside of any campaign.
+        fn record_approval(
+            &mut self,
+            contribution_id: ContributionId,
+            splits: Vec<(AccountId, u16)>,
+            difficulty: u8,
+            reward: Option<Balance>,
+            sponsored: bool,
+        ) -> Result<(), DemoError> {
+            self.check_approval(contribution_id, &splits)?;
+            let contribution =
+                self.prepare_approval(contribution_id, &splits, difficulty, reward, sponsored)?;
+            self.write_approval(contribution_id, splits, contribution);
+            Ok(())
+        }
+
+        /// Computes the contribution recorded by an approval whose splits are checked, and
+        /// publishes its remark: nothing fails once it returns.
+        fn prepare_approval(
+            &self,
+            contribution_id: ContributionId,
+            splits: &[(AccountId, u16)],
+            difficulty: u8,
+            reward: Option<Balance>,
+            sponsored: bool,
+        ) -> Result<Contribution, DemoError> {
+            let Some(&(contributor, _)) = splits.first() else {
+                return Err(DemoError::InvalidSplits);
+            };
+            let campaign_id = if sponsored {
+                None
+            } else {
+                self.active_campaign()
+            };
+            let difficulty = self.difficulty_of(contribution_id).unwrap_or(difficulty);
+            let streak = self
+                .next_streak(contributor)
+                .map_or_else(|| self.streak_of(contributor), |streak| streak.length);
+            let reward = reward.unwrap_or_else(|| {
+                mul_div(
+                    self.reward_of(contributor, streak),
+                    self.weight_bps(difficulty),
+                    MAX_BPS.into(),
+                )
+            });
+            let mut contribution = Contribution {
+                contributor,
+                asset: if sponsored {
+                    Asset::Native
+                } else {
+                    self.active_asset()
+                },
+                campaign_id,
+                reward,
+                is_claimed: false,
+                splits: BoundedVec::try_from(
+                    splits
+                        .iter()
+                        .map(|&(account, bps)| Split {
+                            account,
+                            bps,
+                            is_claimed: false,
+                        })
+                        .collect::<Vec<_>>(),
+                )
+                .map_err(|_| DemoError::InvalidSplits)?,
+                approved_at: self.block_number(),
+                deadline: match self.config().claim_ttl {
+                    0 => None,
+                    claim_ttl => Some(self.block_number().saturating_add(claim_ttl)),
+                },
+            };
+            // The reward is fixed on approval, as it is owed and drawn from the campaign budget
+            if !sponsored {
+                if let Some(reward_strategy) = self.get_reward_strategy() {
+                    let reward_strategy: ink::contract_ref!(RewardStrategy) =
+                        reward_strategy.into();
+                    contribution.reward = reward_strategy.compute(contribution.clone());
+                }
+                contribution.reward = self.campaign_grant(contribution.reward);
+            }
+            // Nothing fails past the remark, so that a failed approval writes nothing (e.g. in
+            // `approve_batch`)
+            if self.is_remark_approvals() {
+                self.remark_approval(contribution_id, contributor, contribution.reward)?;
+            }
+            Ok(contribution)
+        }
+
+        /// Writes an approval prepared by `prepare_approval`.
+        fn write_approval(
+            &mut self,
+            contribution_id: ContributionId,
+            splits: Vec<(AccountId, u16)>,
+            contribution: Contribution,
+        ) {
+            let contributor = contribution.contributor;
+            let campaign_id = contribution.campaign_id;
+            self.submissions.remove(contribution_id);
+            self.rejections.remove(contribution_id);
+            for (account, _) in splits.iter() {
+                self.update_streak(*account);
+            }
+            if let Some(campaign_id) = campaign_id {
+                self.draw_from_campaign(contribution.reward);
+                for (account, _) in splits.iter() {
+                    let approvals = self.get_campaign_approvals(campaign_id, *account);
+                    self.campaign_approvals
+                        .insert((campaign_id, *account), &(approvals + 1));
+                }
+            }
+            self.contributions.insert(contribution_id, &contribution);
+            self.add_unclaimed(contribution_id);
+            if let Some(campaign_id) = contribution.campaign_id {
+                self.tally_campaign_rewards(campaign_id, &contribution);
+            }
+            if !self.is_in_usd(&contribution) {
+                self.owe_reward(contribution.asset, contribution.reward);
+            }
+            self.track_deadline(&contribution);
+            let contribution_count = self.contribution_count.get_or_default();
+            self.contribution_ids
+                .insert(contribution_count, &contribution_id);
+            self.contribution_count.set(&(contribution_count + 1));
+            let referees: Vec<AccountId> = splits
+                .iter()
+                .map(|&(account, _)| account)
+                .filter(|account| self.get_approvals(*account) == 0)
+                .collect();
+            for (account, _) in splits.iter() {
+                self.approvals
+                    .insert(account, &(self.get_approvals(*account) + 1));
+                self.update_level(*account);
+                self.log_activity(*account, Activity::Approved(contribution_id));
+                self.index_by_identity(*account, contribution_id);
+            }
+            self.log_approval(contribution_id);
+            let seq = self.next_seq();
+            self.env().emit_event(ContributionApproval {
+                project: self.get_project_id(),
+                seq,
+                id: contribution_id,
+                contributor,
+            });
+            for (account, _) in splits {
+                self.mint_points(account, POINTS_PER_APPROVAL);
+            }
+            for referee in referees {
+                self.reward_referrer(referee);
+            }
+            self.notify_subscribers(contribution_id, contributor, contribution.reward);
+            self.auto_claim(contribution_id, &contribution);
+        }
+
+        /// Pays the shares of an approved contribution to the co-authors opted in to the
+        /// payout on approval.
+        ///
+        /// Only native rewards are paid, from the pool and while it covers the whole payout
+        /// keeping the contract alive. `_claim` makes its transfers before its writes, so a
+        /// refused payout (e.g. below the existential deposit of a fresh payout address)
+        /// leaves the share to claim. Any other share is left to claim too.
+        fn auto_claim(&mut self, contribution_id: ContributionId, contribution: &Contribution) {
+            if contribution.asset != Asset::Native
+                || self.is_in_usd(contribution)
+                || self.get_reward_vault().is_some()
+            {
+                return;
+            }
+            for split in contribution.splits.iter() {
+                let is_auto_claim = self
+                    .identity_of(split.account)
+                    .map_or(false, |contributor_id| self.is_auto_claim(contributor_id));
+                if !is_auto_claim {
+                    continue;
+                }
+                // The liabilities cover the share, the dust and the tips, but not the boost
+                let share = contribution.share(split.bps);
+                let boosted = mul_div(share, self.boost_bps(split.account).into(), MAX_BPS.into());
+                let needed = self
+                    .liabilities_of(Asset::Native)
+                    .saturating_add(boosted.saturating_sub(share))
+                    .saturating_add(self.env().minimum_balance());
+                if self.env().balance() >= needed {
+                    let _ = self._claim(contribution_id, split.account, None, None, None);
+                }
+            }
+        }
+
+        /// Publishes the compact (`contribution_id`, `contributor`, `reward`) payload of an
+        /// approval as a `system.remark_with_event` chain remark.
+        #[cfg(feature = "call-runtime")]
+        fn remark_approval(
+            &self,
+            contribution_id: ContributionId,
+            contributor: AccountId,
+            reward: Balance,
+        ) -> Result<(), DemoError> {
+            use scale::Encode;
+
+            let remark = (contribution_id, contributor, reward).encode();
+            self.env()
+                .call_runtime(&RuntimeCall::System(SystemCall::RemarkWithEvent { remark }))
+                .map_err(|_| DemoError::CallRuntimeFailed)
+        }
+
+        #[cfg(not(feature = "call-runtime"))]
+        fn remark_approval(
+            &self,
+            _contribution_id: ContributionId,
+            _contributor: AccountId,
+            _reward: Balance,
+        ) -> Result<(), DemoError> {
+            Err(DemoError::CallRuntimeUnavailable)
+        }
+
+        /// Claims `amount` of the share of `caller` in the reward of a given contribution, or
+        /// the whole share left if `None`, paying the relayer fee to `relayer` if any.
+        ///
+        /// Fails if less than `min_out` of the reward asset would be paid to `caller`.
+        fn _claim(
+            &mut self,
+            contribution_id: ContributionId,
+            caller: AccountId,
+            amount: Option<Balance>,
+            relayer: Option<AccountId>,
+            min_out: Option<Balance>,
+        ) -> Result<HashValue, DemoError> {
+            let mut contribution = self
+                .contributions
+                .get(contribution_id)
+                .ok_or(DemoError::UnknownContribution)?;
+            let is_expired = contribution.is_expired(self.block_number());
+            let claimed = self.claimed_amount(contribution_id, caller);
+            let split = contribution
+                .split_of(caller)
+                .ok_or(DemoError::CallerIsNotContributor)?;
+            if self.is_denied(caller) {
+                return Err(DemoError::ContributorDenied);
+            }
+            if is_expired {
+                return Err(DemoError::ClaimDeadlinePassed);
+            }
+            if split.is_claimed {
+                return Err(DemoError::AlreadyClaimed);
+            }
+
+            let bps = split.bps;
+            let remaining = contribution.share(bps).saturating_sub(claimed);
+            let amount = amount.unwrap_or(remaining);
+            if amount > remaining {
+                return Err(DemoError::AmountExceedsShare { remaining });
+            }
+            let attestation_threshold = self.config().attestation_threshold;
+            if attestation_threshold > 0
+                && amount > attestation_threshold
+                && self
+                    .age_attestation_of(caller)
+                    .map_or(true, |expires_at| expires_at <= self.block_timestamp())
+            {
+                return Err(DemoError::AttestationRequired);
+            }
+            let duplicate_claim_threshold = self.config().duplicate_claim_threshold;
+            if duplicate_claim_threshold > 0 && self.suspected_duplicates.contains(caller) {
+                let suspect_claimed = self
+                    .suspect_claims
+                    .get(caller)
+                    .unwrap_or_default()
+                    .saturating_add(amount);
+                if suspect_claimed > duplicate_claim_threshold {
+                    return Err(DemoError::DuplicateUnderReview);
+                }
+            }
+            if let Some(split) = contribution
+                .splits
+                .iter_mut()
+                .find(|split| split.account == caller)
+            {
+                split.is_claimed = amount == remaining;
+            }
+            contribution.is_claimed = contribution.splits.iter().all(|split| split.is_claimed);
+            let action = if contribution.is_claimed {
+                Action::ClaimLast
+            } else {
+                Action::Claim
+            };
+            transition(self.status_of(contribution_id), action)?;
+            let in_usd = self.is_in_usd(&contribution);
+            let fee = relayer.map_or(0, |_| {
+                mul_div(amount, self.config().relayer_fee_bps.into(), MAX_BPS.into())
+            });
+            let boosted = mul_div(amount, self.boost_bps(caller).into(), MAX_BPS.into());
+            let (boosted, fee) = if in_usd {
+                (self.usd_to_units(boosted), self.usd_to_units(fee))
+            } else {
+                (boosted, fee)
+            };
+            if amount > 0 && boosted - fee < min_out.unwrap_or_default() {
+                return Err(DemoError::SlippageExceeded);
+            }
+            if contribution.asset == Asset::Native && !in_usd {
+                self.ensure_boost_covered(boosted.saturating_sub(amount))?;
+            }
+
+            // Every check passed: only the transfers fail from now on, so they come first. A
+            // failed tip transfer writes nothing, and once the tips reach the payout address
+            // the payout to the same address is only refused by an insufficient pool.
+            self.pay_tips(contribution_id, caller, bps)?;
+            if amount > 0 {
+                self.pay_claim(caller, boosted - fee, contribution.asset)?;
+                self.record_earnings(caller, boosted - fee);
+                if let (Some(relayer), true) = (relayer, fee > 0) {
+                    self.pay(relayer, fee, contribution.asset, Movement::Fee)?;
+                }
+                if !in_usd {
+                    self.settle_reward(contribution.asset, amount);
+                }
+            }
+            if duplicate_claim_threshold > 0 && self.suspected_duplicates.contains(caller) {
+                let suspect_claimed = self
+                    .suspect_claims
+                    .get(caller)
+                    .unwrap_or_default()
+                    .saturating_add(amount);
+                self.suspect_claims.insert(caller, &suspect_claimed);
+            }
+            self.contributions.insert(contribution_id, &contribution);
+            if contribution.is_claimed {
+                self.remove_unclaimed(contribution_id);
+                if self.is_open_ended_reward(&contribution) {
+                    self.open_ended_rewards
+                        .set(&self.open_ended_rewards.get_or_default().saturating_sub(1));
+                }
+            }
+            self.claimed_amounts
+                .insert((contribution_id, caller), &claimed.saturating_add(amount));
+            self.log_activity(caller, Activity::Claimed(contribution_id));
+
+            let receipt = self.env().hash_encoded::<Blake2x256, _>(&(
+                contribution_id,
+                caller,
+                amount,
+                self.block_number(),
+            ));
+            self.receipts.insert(receipt, &contribution_id);
+            let seq = self.next_seq();
+            self.env().emit_event(ContributionClaim {
+                project: self.get_project_id(),
+                seq,
+                id: contribution_id,
+                contributor: caller,
+                receipt,
+            });
+            Ok(receipt)
+        }
+
+        /// Returns the deterministic id of a contribution and the hash it is derived from.
+        fn preimage_of(
+            &self,
+            repo: &str,
+            kind: ContributionKind,
+            number: u64,
+        ) -> (ContributionId, HashValue) {
+            let preimage = self
+                .env()
+                .hash_encoded::<Blake2x256, _>(&(repo, kind, number));
+            let mut id = [0u8; 8];
+            id.copy_from_slice(&preimage[..8]);
+            (ContributionId::from_le_bytes(id), preimage)
+        }
+
+        /// Returns the sequence number of the next emitted event.
+        fn next_seq(&mut self) -> u64 {
+            let seq = self.latest_seq() + 1;
+            self.event_seq.set(&seq);
+            seq
+        }
+
+        /// Adds `account` to (or removes it from) the deny list.
+        fn _set_denied(&mut self, account: AccountId, denied: bool) {
+            if denied {
+                self.denied.insert(account, &());
+            } else {
+                self.denied.remove(account);
+            }
+            let seq = self.next_seq();
+            self.env().emit_event(DenyListUpdated {
+                seq,
+                account,
+                denied,
+            });
+        }
+
+        /// Assigns a role to an account, or removes its role with `None`.
+        fn _set_role(&mut self, account: AccountId, role: Option<RoleId>) {
+            if let Some(role) = role {
+                self.roles.insert(account, &role);
+            } else {
+                self.roles.remove(account);
+            }
+            let seq = self.next_seq();
+            self.env().emit_event(RoleSet { seq, account, role });
+        }
+
+        /// Permits (or forbids) a role to call the owner message of a given `selector`.
+        fn _set_permission(&mut self, role: RoleId, selector: [u8; 4], allowed: bool) {
+            if allowed {
+                self.permissions.insert((role, selector), &());
+            } else {
+                self.permissions.remove((role, selector));
+            }
+            let seq = self.next_seq();
+            self.env().emit_event(PermissionSet {
+                seq,
+                role,
+                selector,
+                allowed,
+            });
+        }
+
+        /// Returns the multiplier (in basis points) of the claims of `account`: the booster
+        /// multiplier if it holds a booster NFT, 1x otherwise.
+        fn boost_bps(&self, account: AccountId) -> u16 {
+            let Some((collection, multiplier_bps)) = self.get_booster() else {
+                return MAX_BPS;
+            };
+            let collection: ink::contract_ref!(openbrush::contracts::traits::psp34::PSP34) =
+                collection.into();
+            if collection.balance_of(account) > 0 {
+                multiplier_bps
+            } else {
+                MAX_BPS
+            }
+        }
+
+        /// Fails if the native pool left after the liabilities does not cover `boost`, the part
+        /// of a boosted payout no one is owed.
+        fn ensure_boost_covered(&self, boost: Balance) -> Result<(), DemoError> {
+            let available = self
+                .env()
+                .balance()
+                .saturating_sub(self.liabilities_of(Asset::Native));
+            if boost > available {
+                return Err(DemoError::InsufficientPool {
+                    needed: boost,
+                    available,
+                });
+            }
+            Ok(())
+        }
+
+        /// Returns `true` if only native contribution rewards are owed, past their claim
+        /// deadline: tips, stakes, fees, dust and the other assets are still owed.
+        fn owes_expired_rewards_only(&self) -> bool {
+            let liabilities = self.liabilities_of(Asset::Native);
+            liabilities == self.reward_liabilities.get_or_default()
+                && self.indebted_assets.get_or_default() == u32::from(liabilities > 0)
+                && self.open_ended_rewards.get_or_default() == 0
+                && self.block_number() > self.latest_deadline.get_or_default()
+        }
+
+        /// Notifies the subscribers of an approval, ignoring their failures.
+        fn notify_subscribers(
+            &self,
+            contribution_id: ContributionId,
+            contributor: AccountId,
+            reward: Balance,
+        ) {
+            use ink::codegen::TraitCallBuilder;
+
+            for subscriber in self.get_subscribers() {
+                let mut subscriber: ink::contract_ref!(OnContributionApproved) = subscriber.into();
+                let _ = subscriber
+                    .call_mut()
+                    .on_contribution_approved(contribution_id, contributor, reward)
+                    .try_invoke();
+            }
+        }
+
+        /// Grants the referral bonus to the referrer of `referee`, if any, from the budget of
+        /// the active campaign.
+        fn reward_referrer(&mut self, referee: AccountId) {
+            let Some(referrer) = self.referrer_of(referee) else {
+                return;
+            };
+            // Referral bonuses are only paid in the native currency
+            if self.active_asset() != Asset::Native {
+                return;
+            }
+            let amount = self.draw_from_campaign(self.config().referral_bonus);
+            if amount == 0 {
+                return;
+            }
+            self.owe(Asset::Native, amount);
+            self.referral_rewards.insert(
+                referrer,
+                &self.get_referral_rewards(referrer).saturating_add(amount),
+            );
+            let seq = self.next_seq();
+            self.env().emit_event(ReferralRewarded {
+                seq,
+                referrer,
+                referee,
+                amount,
+            });
+        }
+
+        /// The checks done by `record_approval` on the shared contribution before writing
+        /// anything.
+        ///
+        /// Returns the main contributor, the first of the `splits`.
+        fn check_approval(
+            &self,
+            contribution_id: ContributionId,
+            splits: &[(AccountId, u16)],
+        ) -> Result<AccountId, DemoError> {
+            let contributor = self.check_splits(contribution_id, splits)?;
+            transition(self.status_of(contribution_id), Action::Approve)?;
+            Ok(contributor)
+        }
+
+        /// The checks of `check_approval` but the status of the contribution, for the proposed
+        /// approvals whose transition is checked by their caller.
+        fn check_splits(
+            &self,
+            contribution_id: ContributionId,
+            splits: &[(AccountId, u16)],
+        ) -> Result<AccountId, DemoError> {
+            let contributor = self.validate_splits(splits)?;
+            for (account, _) in splits.iter() {
+                self.ensure_approvable(contribution_id, *account)?;
+            }
+            self.ensure_not_self_approval(self.env().caller(), splits)?;
+            Ok(contributor)
+        }
+
+        /// Fails unless the splits share the whole reward between distinct valid accounts.
+        ///
+        /// Returns the first contributor.
+        fn validate_splits(&self, splits: &[(AccountId, u16)]) -> Result<AccountId, DemoError> {
+            let Some(&(contributor, _)) = splits.first() else {
+                return Err(DemoError::InvalidSplits);
+            };
+            if splits.len() > MAX_SPLITS {
+                return Err(DemoError::InvalidSplits);
+            }
+            let total_bps = splits
+                .iter()
+                .try_fold(0u16, |total, (_, bps)| total.checked_add(*bps));
+            if total_bps != Some(MAX_BPS) {
+                return Err(DemoError::InvalidSplits);
+            }
+            for (index, (account, _)) in splits.iter().enumerate() {
+                if splits[..index].iter().any(|(other, _)| other == account) {
+                    return Err(DemoError::InvalidSplits);
+                }
+                self.ensure_valid_account(*account)?;
+            }
+            Ok(contributor)
+        }
+
+        /// The checks done by `approve` once the caller is authorized.
+        fn ensure_approvable(
+            &self,
+            contribution_id: ContributionId,
+            contributor: AccountId,
+        ) -> Result<(), DemoError> {
+            self.ensure_valid_account(contributor)?;
+            if self.is_denied(contributor) {
+                return Err(DemoError::ContributorDenied);
+            }
+            if let Some(contribution) = self.contributions.get(contribution_id) {
+                return Err(DemoError::ContributionAlreadyApproved {
+                    contributor: contribution.contributor,
+                });
+            }
+            if self.is_archived(contribution_id) {
+                return Err(DemoError::ContributionArchived);
+            }
+            let contributor_cap = self.get_contributor_cap();
+            if let Some(campaign_id) = self.active_campaign() {
+                if contributor_cap > 0
+                    && self.get_campaign_approvals(campaign_id, contributor) >= contributor_cap
+                {
+                    return Err(DemoError::ContributorCapReached);
+                }
+            }
+            Ok(())
+        }
+
+        /// Fails if `approver` approves a contribution paying their own account (directly or
+        /// through a payout address) while the self-approval setting forbids it.
+        fn ensure_not_self_approval(
+            &self,
+            approver: AccountId,
+            splits: &[(AccountId, u16)],
+        ) -> Result<(), DemoError> {
+            let is_owner = Some(approver) == self.owner();
+            let is_allowed = match self.config().self_approval {
+                SelfApproval::Allowed => true,
+                SelfApproval::OwnerOnly => is_owner,
+                SelfApproval::Forbidden => false,
+            };
+            // Redeemed vouchers and finalized proposals are not approved by their caller
+            let is_approver = is_owner
+                || self.session_expiry(approver).is_some()
+                || self.role_of(approver).is_some();
+            if is_allowed || !is_approver {
+                return Ok(());
+            }
+            if splits.iter().any(|(account, _)| {
+                *account == approver || self.payout_address_of(*account) == approver
+            }) {
+                return Err(DemoError::SelfApprovalForbidden);
+            }
+            Ok(())
+        }
+
+        /// Records the review of a contribution by the caller for `contributor`.
+        fn record_review(
+            &mut self,
+            contribution_id: ContributionId,
+            contributor: AccountId,
+            current: u32,
+            required: u32,
+        ) {
+            let approver = self.env().caller();
+            self.reviews.insert((contribution_id, approver), &());
+            let seq = self.next_seq();
+            self.env().emit_event(EndorsementRecorded {
+                seq,
+                id: contribution_id,
+                approver,
+                contributor,
+                current,
+                required,
+            });
+        }
+
+        /// Returns the lifecycle status of a given contribution.
+        fn status_of(&self, contribution_id: ContributionId) -> Status {
+            if self.is_archived(contribution_id) {
+                return Status::Archived;
+            }
+            if let Some(contribution) = self.contributions.get(contribution_id) {
+                return if contribution.is_claimed {
+                    Status::Settled
+                } else {
+                    Status::Approved
+                };
+            }
+            if self.proposed_approvals.contains(contribution_id) {
+                return if self.disputes.contains(contribution_id) {
+                    Status::Disputed
+                } else {
+                    Status::Proposed
+                };
+            }
+            if self.submissions.contains(contribution_id) {
+                return Status::Submitted;
+            }
+            Status::Unknown
+        }
+
+        /// Returns the current block number, ahead of the chain with the `sandbox` feature.
+        fn block_number(&self) -> BlockNumber {
+            #[cfg(feature = "sandbox")]
+            let offset = self.time_offset().0;
+            #[cfg(not(feature = "sandbox"))]
+            let offset = 0;
+            self.env().block_number().saturating_add(offset)
+        }
+
+        /// Returns the current timestamp, ahead of the chain with the `sandbox` feature.
+        fn block_timestamp(&self) -> Timestamp {
+            #[cfg(feature = "sandbox")]
+            let offset = self.time_offset().1;
+            #[cfg(not(feature = "sandbox"))]
+            let offset = 0;
+            self.env().block_timestamp().saturating_add(offset)
+        }
+
+        /// Returns the current block number and timestamp, to check deadlines against.
+        fn now(&self) -> Now {
+            Now {
+                block: self.block_number(),
+                timestamp: self.block_timestamp(),
+            }
+        }
+
+        /// Pays the share of the tips of a contribution left to a co-author with `bps`.
+        ///
+        /// Returns the paid amount.
+        fn pay_tips(
+            &mut self,
+            contribution_id: ContributionId,
+            account: AccountId,
+            bps: u16,
+        ) -> Result<Balance, DemoError> {
+            let share = mul_div(self.tips_of(contribution_id), bps.into(), MAX_BPS.into());
+            let paid = self
+                .paid_tips
+                .get((contribution_id, account))
+                .unwrap_or_default();
+            let amount = share.saturating_sub(paid);
+            if amount == 0 {
+                return Ok(0);
+            }
+            self.pay(
+                self.payout_address_of(account),
+                amount,
+                Asset::Native,
+                Movement::Claim,
+            )?;
+            self.paid_tips.insert((contribution_id, account), &share);
+            self.settle(Asset::Native, amount);
+            self.record_earnings(account, amount);
+            Ok(amount)
+        }
+
+        /// Adds an amount paid to an account to its earnings of the current era.
+        fn record_earnings(&mut self, account: AccountId, amount: Balance) {
+            let era = self.current_era();
+            let mut eras = self.eras_of(account);
+            if eras.last() != Some(&era) {
+                eras.push(era);
+                self.earning_eras.insert(account, &eras);
+            }
+            let earnings = self.earnings_of(account, era);
+            self.earnings
+                .insert((account, era), &earnings.saturating_add(amount));
+        }
+
+        /// Adds a contribution to the index of the unclaimed contributions.
+        fn add_unclaimed(&mut self, contribution_id: ContributionId) {
+            if self.unclaimed_indexes.contains(contribution_id) {
+                return;
+            }
+            let count = self.total_unclaimed();
+            self.unclaimed_ids.insert(count, &contribution_id);
+            self.unclaimed_indexes.insert(contribution_id, &count);
+            self.unclaimed_count.set(&(count + 1));
+        }
+
+        /// Removes a contribution from the index of the unclaimed contributions, moving the last
+        /// one to its place.
+        fn remove_unclaimed(&mut self, contribution_id: ContributionId) {
+            let Some(index) = self.unclaimed_indexes.take(contribution_id) else {
+                return;
+            };
+            let last = self.total_unclaimed() - 1;
+            if let Some(last_id) = self.unclaimed_ids.take(last) {
+                if index != last {
+                    self.unclaimed_ids.insert(index, &last_id);
+                    self.unclaimed_indexes.insert(last_id, &index);
+                }
+            }
+            self.unclaimed_count.set(&last);
+        }
+
+        /// Removes a proposed approval, finalized or rejected.
+        fn remove_pending_approval(&mut self, contribution_id: ContributionId) {
+            self.proposed_approvals.remove(contribution_id);
+            let mut pending_approvals = self.pending_approvals.get_or_default();
+            pending_approvals.retain(|id| *id != contribution_id);
+            self.pending_approvals.set(&pending_approvals);
+        }
+
+        /// Pays the poke tip to the caller, if the contract balance covers it on top of the
+        /// native liabilities.
+        fn pay_tip(&mut self) -> Result<(), DemoError> {
+            let tip = self.config().poke_tip;
+            if tip > 0
+                && self.env().balance() >= self.liabilities_of(Asset::Native).saturating_add(tip)
+            {
+                self.env()
+                    .transfer(self.env().caller(), tip)
+                    .map_err(|_| DemoError::TransferFailed)?;
+                self.record(Movement::Fee, tip);
+            }
+            Ok(())
+        }
+
+        /// Ensures the caller can call the owner message `selector`, see `can_call`.
+        fn ensure_permitted(&self, selector: [u8; 4]) -> Result<(), DemoError> {
+            if !self.can_call(self.env().caller(), selector) {
+                return Err(OwnableError::CallerIsNotOwner.into());
+            }
+            Ok(())
+        }
+
+        /// Ensures the caller can approve contributions (see `ensure_can_approve`) or call the
+        /// approver message `selector` (see `can_call`).
+        fn ensure_approver(&self, selector: [u8; 4]) -> Result<(), DemoError> {
+            let caller = self.env().caller();
+            if self.can_call(caller, selector) {
+                return Ok(());
+            }
+            self.ensure_can_approve(caller)
+        }
+
+        /// Ensures `account` is the owner, an allowlisted proxy not forwarding signers or
+        /// holds a live session key.
+        fn ensure_can_approve(&self, account: AccountId) -> Result<(), DemoError> {
+            if Some(account) == self.owner() || self.proxies.get(account) == Some(false) {
+                return Ok(());
+            }
+            match self.session_expiry(account) {
+                Some(expires_at) if self.block_number() <= expires_at => Ok(()),
+                Some(_) => Err(DemoError::SessionExpired),
+                None => Err(OwnableError::CallerIsNotOwner.into()),
+            }
+        }
+
+        /// Runs `approve`, paying the storage deposit of every contribution it approves from
+        /// the value transferred by the caller and refunding the excess.
+        ///
+        /// Fails once `approve` is done if the value does not cover the deposits, reverting the
+        /// whole call.
+        fn with_approval_deposit<T>(
+            &mut self,
+            approve: impl FnOnce(&mut Self) -> Result<T, DemoError>,
+        ) -> Result<T, DemoError> {
+            let approved_before = self.contribution_count.get_or_default();
+            let outcome = approve(self)?;
+            let approved = self
+                .contribution_count
+                .get_or_default()
+                .saturating_sub(approved_before);
+            let needed = self.approval_deposit().saturating_mul(approved.into());
+            let transferred = self.env().transferred_value();
+            if transferred < needed {
+                return Err(DemoError::InsufficientDeposit { needed });
+            }
+            if transferred > needed {
+                self.env()
+                    .transfer(self.env().caller(), transferred - needed)
+                    .map_err(|_| DemoError::TransferFailed)?;
+            }
+            Ok(outcome)
+        }
+
+        /// Records a review of a contribution by the caller, approving it once `required`
+        /// reviews agree, see `review`.
+        fn _review(
+            &mut self,
+            contribution_id: ContributionId,
+            contributor: AccountId,
+            required: u32,
+        ) -> Result<(), DemoError> {
+            self.ensure_approvable(contribution_id, contributor)?;
+            transition(self.status_of(contribution_id), Action::Approve)?;
+            let caller = self.env().caller();
+            if self.reviews.contains((contribution_id, caller)) {
+                return Err(DemoError::AlreadyReviewed);
+            }
+            if let Some(mut candidates) = self.conflict_of(contribution_id) {
+                let current = match candidates
+                    .iter_mut()
+                    .find(|(candidate, _)| *candidate == contributor)
+                {
+                    Some((_, count)) => {
+                        *count += 1;
+                        *count
+                    }
+                    None => {
+                        candidates.push((contributor, 1));
+                        1
+                    }
+                };
+                self.conflicts.insert(contribution_id, &candidates);
+                self.record_review(contribution_id, contributor, current, required);
+                return Ok(());
+            }
+            let current = match self.reviews_of(contribution_id) {
+                Some((reviewed, count)) if reviewed != contributor => {
+                    let candidates = vec![(reviewed, count), (contributor, 1)];
+                    self.review_tallies.remove(contribution_id);
+                    self.conflicts.insert(contribution_id, &candidates);
+                    self.record_review(contribution_id, contributor, 1, required);
+                    let seq = self.next_seq();
+                    self.env().emit_event(ConflictDetected {
+                        seq,
+                        id: contribution_id,
+                        candidates,
+                    });
+                    return Ok(());
+                }
+                Some((_, count)) => count + 1,
+                None => 1,
+            };
+
+            self.record_review(contribution_id, contributor, current, required);
+            if current < required {
+                self.review_tallies
+                    .insert(contribution_id, &(contributor, current));
+                return Ok(());
+            }
+            self.review_tallies.remove(contribution_id);
+            self._approve(
+                contribution_id,
+                vec![(contributor, MAX_BPS)],
+                MAX_DIFFICULTY,
+                None,
+            )
+        }
+
+        /// Approves a proposed approval and refunds the stake of its proposer.
+        fn accept_approval(
+            &mut self,
+            contribution_id: ContributionId,
+            proposed: ProposedApproval,
+        ) -> Result<(), DemoError> {
+            let splits = vec![(proposed.contributor, MAX_BPS)];
+            self.check_splits(contribution_id, &splits)?;
+            let contribution =
+                self.prepare_approval(contribution_id, &splits, MAX_DIFFICULTY, None, false)?;
+            // The stake is refunded before any write, so that a failed approval writes nothing
+            self.env()
+                .transfer(proposed.proposer, proposed.stake)
+                .map_err(|_| DemoError::TransferFailed)?;
+            self.record(Movement::Refund, proposed.stake);
+            self.settle(Asset::Native, proposed.stake);
+            self.remove_pending_approval(contribution_id);
+            self.write_approval(contribution_id, splits, contribution);
+            Ok(())
+        }
+
+        /// Drops a proposed approval and slashes its stake into the reward pool.
+        fn reject_approval(&mut self, contribution_id: ContributionId, proposed: ProposedApproval) {
+            self.remove_pending_approval(contribution_id);
+            self.settle(Asset::Native, proposed.stake);
+            let seq = self.next_seq();
+            self.env().emit_event(ApprovalChallenged {
+                seq,
+                id: contribution_id,
+                stake: proposed.stake,
+            });
+        }
+
+        /// Returns a pseudo-random index below `len`, from the block number and timestamp
+        /// salted by the draw nonce.
+        fn draw_index(&mut self, len: usize) -> usize {
+            let nonce = self.draw_nonce.get_or_default();
+            self.draw_nonce.set(&(nonce + 1));
+            let seed = self.env().hash_encoded::<Blake2x256, _>(&(
+                self.block_number(),
+                self.block_timestamp(),
+                nonce,
+            ));
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&seed[..8]);
+            (u64::from_le_bytes(bytes) % len as u64) as usize
+        }
+
+        /// Removes a contribution from the related contributions of its link target.
+        fn unlink_from_target(&mut self, contribution_id: ContributionId) {
+            let Some((_, target)) = self.relation_of(contribution_id) else {
+                return;
+            };
+            let mut related = self.related_to(target);
+            related.retain(|id| *id != contribution_id);
+            self.related.insert(target, &related);
+        }
+
+        /// Ensures `account` can hold rewards: it is neither the zero account nor the contract.
+        fn ensure_valid_account(&self, account: AccountId) -> Result<(), DemoError> {
+            if account == AccountId::from([0u8; 32]) {
+                return Err(DemoError::ZeroAccount);
+            }
+            if account == self.env().account_id() {
+                return Err(DemoError::ContractAccount);
+            }
+            Ok(())
+        }
+
+        /// Returns the reward of the next contribution of `contributor`, on a `streak` of
+        /// epochs including it.
+        ///
+        /// First-time contributors get `bonus_bps` on top of the reward, and contributors on a
+        /// streak get `streak_bonus_bps` per consecutive epoch.
+        fn reward_of(&self, contributor: AccountId, streak: u32) -> Balance {
+            let reward = self.current_reward();
+            let streak_epochs = streak.saturating_sub(1);
+            let mut bonus_bps =
+                Balance::from(self.get_streak_bonus_bps()).saturating_mul(streak_epochs.into());
+            if self.get_approvals(contributor) == 0 {
+                bonus_bps = bonus_bps.saturating_add(self.get_bonus_bps().into());
+            }
+            let bonus = reward.saturating_mul(bonus_bps) / Balance::from(MAX_BPS);
+            reward.saturating_add(bonus)
+        }
+
+        /// Returns the weight (in basis points of the reward) of a contribution of `difficulty`.
+        fn weight_bps(&self, difficulty: u8) -> Balance {
+            let difficulty = Balance::from(difficulty.min(MAX_DIFFICULTY));
+            let max_bps = Balance::from(MAX_BPS);
+            let max_difficulty = Balance::from(MAX_DIFFICULTY);
+            match self.get_reward_mode() {
+                RewardMode::Flat => max_bps,
+                RewardMode::Quadratic => mul_div(
+                    difficulty * difficulty,
+                    max_bps,
+                    max_difficulty * max_difficulty,
+                ),
+                RewardMode::Sqrt => isqrt(mul_div(difficulty, max_bps * max_bps, max_difficulty)),
+            }
+        }
+
+        /// Pays `amount` of `asset` to `account`.
+        ///
+        /// PSP22 tokens are transferred from the contract, the native currency from the contract
+        /// balance, recorded in the ledger as `movement`, either to `account` or to the reward
+        /// vault crediting it.
+        fn pay(
+            &mut self,
+            account: AccountId,
+            amount: Balance,
+            asset: Asset,
+            movement: Movement,
+        ) -> Result<(), DemoError> {
+            if let Asset::PSP22(token) = asset {
+                let mut token: ink::contract_ref!(openbrush::contracts::traits::psp22::PSP22) =
+                    token.into();
+                return Ok(token.transfer(account, amount, Vec::new())?);
+            }
+            let available = self.env().balance();
+            if amount > available {
+                return Err(DemoError::InsufficientPool {
+                    needed: amount,
+                    available,
+                });
+            }
+            match self.get_reward_vault() {
+                Some(reward_vault) => {
+                    use ink::codegen::TraitCallBuilder;
+
+                    let mut reward_vault: ink::contract_ref!(RewardVault) = reward_vault.into();
+                    let credited = reward_vault
+                        .call_mut()
+                        .credit(account, amount)
+                        .transferred_value(amount)
+                        .try_invoke();
+                    if !matches!(credited, Ok(Ok(Ok(())))) {
+                        return Err(DemoError::TransferFailed);
+                    }
+                }
+                None => self
+                    .env()
+                    .transfer(account, amount)
+                    .map_err(|_| DemoError::TransferFailed)?,
+            }
+            self.record(movement, amount);
+            Ok(())
+        }
+
+        /// Pays `payout` of `asset` claimed by `caller` to its payout address, keeping native
+        /// payouts as dust while the dust of `caller` stays below the minimum claim.
+        fn pay_claim(
+            &mut self,
+            caller: AccountId,
+            payout: Balance,
+            asset: Asset,
+        ) -> Result<(), DemoError> {
+            if asset != Asset::Native {
+                return self.pay(
+                    self.payout_address_of(caller),
+                    payout,
+                    asset,
+                    Movement::Claim,
+                );
+            }
+            let dust = self.dust_of(caller);
+            let total = dust.saturating_add(payout);
+            if total < self.config().min_claim {
+                self.dust.insert(caller, &total);
+                self.owe(Asset::Native, payout);
+                return Ok(());
+            }
+            let payout_address = self.payout_address_of(caller);
+            self.pay(payout_address, total, Asset::Native, Movement::Claim)?;
+            self.dust.remove(caller);
+            self.settle(Asset::Native, dust);
+            if self.config().emit_transfer_events {
+                self.env().emit_event(Transfer {
+                    from: Some(self.env().account_id()),
+                    to: Some(payout_address),
+                    value: total,
+                });
+            }
+            Ok(())
+        }
+
+        /// Appends a movement of the native reward pool to the ledger, overwriting the oldest
+        /// entry when full.
+        fn record(&mut self, movement: Movement, amount: Balance) {
+            let count = self.ledger_count.get_or_default();
+            self.ledger.insert(
+                count % LEDGER_CAPACITY,
+                &LedgerEntry {
+                    movement,
+                    amount,
+                    balance: self.env().balance(),
+                    block: self.block_number(),
+                },
+            );
+            self.ledger_count.set(&count.saturating_add(1));
+        }
+
+        /// Creates a reward campaign, denominated in USD cents if `in_usd`.
+        fn _create_campaign(
+            &mut self,
+            start: BlockNumber,
+            end: BlockNumber,
+            budget: Balance,
+            asset: Asset,
+            in_usd: bool,
+        ) -> Result<CampaignId, DemoError> {
+            if start > end {
+                return Err(DemoError::InvalidCampaign);
+            }
+            if let Asset::PSP22(token) = asset {
+                if !self.is_reward_asset(token) {
+                    return Err(DemoError::UnknownRewardAsset);
+                }
+            }
+            if self.active_campaign().is_some() {
+                return Err(DemoError::CampaignAlreadyActive);
+            }
+
+            let id = self.next_campaign_id.get_or_default();
+            let campaign = Campaign {
+                start,
+                end,
+                budget,
+                remaining: budget,
+                asset,
+                in_usd,
+            };
+            self.campaigns.insert(id, &campaign);
+            self.next_campaign_id.set(&(id + 1));
+            let seq = self.next_seq();
+            self.env().emit_event(CampaignCreated {
+                seq,
+                id,
+                start,
+                end,
+                budget,
+            });
+            Ok(id)
+        }
+
+        /// Returns the current epoch, if streaks are enabled.
+        fn current_epoch(&self) -> Option<BlockNumber> {
+            self.block_number().checked_div(self.get_epoch_length())
+        }
+
+        /// Mints non-transferable KUDOS points to `account`.
+        fn mint_points(&mut self, account: AccountId, value: Balance) {
+            let balance = self.points.get(account).unwrap_or_default();
+            self.points.insert(account, &balance.saturating_add(value));
+            self.points_supply
+                .set(&self.points_supply.get_or_default().saturating_add(value));
+            let reputation = self
+                .effective_reputation_of(account)
+                .saturating_add(value.saturating_mul(REPUTATION_PRECISION));
+            self.reputations
+                .insert(account, &(reputation, self.block_number()));
+            self.env().emit_event(Transfer {
+                from: None,
+                to: Some(account),
+                value,
+            });
+        }
+
+        /// Raises the level of `account` to the thresholds its approvals reach.
+        fn update_level(&mut self, account: AccountId) {
+            let approvals = self.get_approvals(account);
+            let level = self
+                .level_thresholds()
+                .iter()
+                .take_while(|threshold| **threshold <= approvals)
+                .count() as u8;
+            if level <= self.level_of(account) {
+                return;
+            }
+            self.levels.insert(account, &level);
+            let seq = self.next_seq();
+            self.env().emit_event(LevelUp {
+                seq,
+                account,
+                level,
+            });
+        }
+
+        /// Returns the streak of `account` extended to the current epoch, or restarted after a
+        /// gap, `None` if it is already extended or there is no epoch.
+        fn next_streak(&self, account: AccountId) -> Option<Streak> {
+            let epoch = self.current_epoch()?;
+            match self.streaks.get(account) {
+                Some(streak) if streak.last_epoch == epoch => None,
+                Some(streak) if streak.last_epoch + 1 == epoch => Some(Streak {
+                    last_epoch: epoch,
+                    length: streak.length + 1,
+                }),
+                _ => Some(Streak {
+                    last_epoch: epoch,
+                    length: 1,
+                }),
+            }
+        }
+
+        /// Extends the streak of `account` to the current epoch, or restarts it after a gap.
+        fn update_streak(&mut self, account: AccountId) {
+            let Some(streak) = self.next_streak(account) else {
+                return;
+            };
+            self.streaks.insert(account, &streak);
+            let seq = self.next_seq();
+            self.env().emit_event(StreakUpdated {
+                seq,
+                account,
+                streak: streak.length,
+            });
+        }
+
+        /// Appends an approval to the log, pruning the oldest entry when full.
+        fn log_approval(&mut self, contribution_id: ContributionId) {
+            let mut approval_log = self.approval_log.get_or_default();
+            if approval_log.len() >= APPROVAL_LOG_CAPACITY {
+                approval_log.remove(0);
+            }
+            approval_log.push((self.block_number(), contribution_id));
+            self.approval_log.set(&approval_log);
+        }
+
+        /// Appends an action of `account` to its activity log, dropping the oldest one when full.
+        fn log_activity(&mut self, account: AccountId, activity: Activity) {
+            let mut activities = self.activity_of(account);
+            if activities.len() >= ACTIVITY_LOG_CAPACITY {
+                activities.remove(0);
+            }
+            activities.push((self.block_number(), activity));
+            self.activities.insert(account, &activities);
+        }
+
+        /// Appends a contribution to the contributions of the identity bound to `account`, if
+        /// any.
+        fn index_by_identity(&mut self, account: AccountId, contribution_id: ContributionId) {
+            let Some(contributor_id) = self.get_contributor_id(account) else {
+                return;
+            };
+            let count = self
+                .identity_contribution_counts
+                .get(contributor_id)
+                .unwrap_or_default();
+            self.identity_contributions
+                .insert((contributor_id, count), &contribution_id);
+            self.identity_contribution_counts
+                .insert(contributor_id, &(count + 1));
+        }
+
+        /// Returns the reward asset of the active campaign, or the native currency.
+        fn active_asset(&self) -> Asset {
+            self.active_campaign()
+                .and_then(|campaign_id| self.campaigns.get(campaign_id))
+                .map_or(Asset::Native, |campaign| campaign.asset)
+        }
+
+        /// Returns whether the reward of `contribution` is denominated in USD cents.
+        ///
+        /// Such rewards are not recorded as liabilities, as their amount of the reward asset
+        /// is only known on claim.
+        fn is_in_usd(&self, contribution: &Contribution) -> bool {
+            contribution
+                .campaign_id
+                .and_then(|campaign_id| self.campaigns.get(campaign_id))
+                .map_or(false, |campaign| campaign.in_usd)
+        }
+
+        /// Converts `cents` to the smallest units of the reward asset, at the price of the feed
+        /// or at the fallback price when the feed price is stale or missing.
+        fn usd_to_units(&self, cents: Balance) -> Balance {
+            use ink::codegen::TraitCallBuilder;
+
+            let Some(usd_pricing) = self.get_usd_pricing() else {
+                return 0;
+            };
+            let feed: ink::contract_ref!(PriceFeed) = usd_pricing.feed.into();
+            let now = self.block_timestamp();
+            let price = match feed
+                .call()
+                .get_latest_price(String::from(usd_pricing.pair.as_str()))
+                .try_invoke()
+            {
+                Ok(Ok(Some((updated_at, price))))
+                    if price > 0 && now.saturating_sub(updated_at) <= usd_pricing.max_age =>
+                {
+                    price
+                }
+                _ => usd_pricing.fallback_price,
+            };
+            mul_div(cents, usd_pricing.unit.saturating_mul(USD_CENT), price)
+        }
+
+        /// Records `amount` of `asset` as owed as the reward of a contribution.
+        fn owe_reward(&mut self, asset: Asset, amount: Balance) {
+            if asset == Asset::Native {
+                self.reward_liabilities.set(
+                    &self
+                        .reward_liabilities
+                        .get_or_default()
+                        .saturating_add(amount),
+                );
+            }
+            self.owe(asset, amount);
+        }
+
+        /// Records `amount` of `asset` as no longer owed as the reward of a contribution.
+        fn settle_reward(&mut self, asset: Asset, amount: Balance) {
+            if asset == Asset::Native {
+                self.reward_liabilities.set(
+                    &self
+                        .reward_liabilities
+                        .get_or_default()
+                        .saturating_sub(amount),
+                );
+            }
+            self.settle(asset, amount);
+        }
+
+        /// Returns `true` if a contribution owes a native reward that never expires.
+        fn is_open_ended_reward(&self, contribution: &Contribution) -> bool {
+            contribution.asset == Asset::Native
+                && contribution.deadline.is_none()
+                && contribution.reward > 0
+                && !self.is_in_usd(contribution)
+        }
+
+        /// Records the claim deadline of an unclaimed contribution, for `terminate`.
+        fn track_deadline(&mut self, contribution: &Contribution) {
+            if contribution.is_claimed {
+                return;
+            }
+            if self.is_open_ended_reward(contribution) {
+                self.open_ended_rewards
+                    .set(&self.open_ended_rewards.get_or_default().saturating_add(1));
+            }
+            if let Some(deadline) = contribution.deadline {
+                if contribution.asset == Asset::Native
+                    && deadline > self.latest_deadline.get_or_default()
+                {
+                    self.latest_deadline.set(&deadline);
+                }
+            }
+        }
+
+        /// Records `amount` of `asset` as owed to contributors.
+        fn owe(&mut self, asset: Asset, amount: Balance) {
+            let liabilities = self.liabilities_of(asset);
+            if liabilities == 0 && amount > 0 {
+                self.indebted_assets
+                    .set(&self.indebted_assets.get_or_default().saturating_add(1));
+            }
+            self.liabilities
+                .insert(asset, &liabilities.saturating_add(amount));
+        }
+
+        /// Records `amount` of `asset` as no longer owed to contributors.
+        fn settle(&mut self, asset: Asset, amount: Balance) {
+            let liabilities = self.liabilities_of(asset);
+            if liabilities > 0 && amount >= liabilities {
+                self.indebted_assets
+                    .set(&self.indebted_assets.get_or_default().saturating_sub(1));
+            }
+            self.liabilities
+                .insert(asset, &liabilities.saturating_sub(amount));
+        }
+
+        /// Adds the shares of an approved contribution to the rewards its co-authors were
+        /// granted in a campaign.
+        fn tally_campaign_rewards(&mut self, campaign_id: CampaignId, contribution: &Contribution) {
+            for split in contribution.splits.iter() {
+                let granted = self.campaign_rewards.get((campaign_id, split.account));
+                if granted.is_none() {
+                    let contributors = self
+                        .campaign_contributors
+                        .get(campaign_id)
+                        .unwrap_or_default();
+                    self.campaign_contributors
+                        .insert(campaign_id, &(contributors + 1));
+                }
+                let granted = granted
+                    .unwrap_or_default()
+                    .saturating_add(contribution.share(split.bps));
+                self.campaign_rewards
+                    .insert((campaign_id, split.account), &granted);
+                if self
+                    .campaign_tops
+                    .get(campaign_id)
+                    .map_or(true, |(_, top_reward)| granted > top_reward)
+                {
+                    self.campaign_tops
+                        .insert(campaign_id, &(split.account, granted));
+                }
+            }
+        }
+
+        /// Returns the campaign of a contribution, unless finalized.
+        fn open_campaign_of(&self, contribution: &Contribution) -> Option<CampaignId> {
+            contribution
+                .campaign_id
+                .filter(|campaign_id| !self.campaign_summaries.contains(*campaign_id))
+        }
+
+        /// Returns the part of `reward` the budget of the active campaign covers, see
+        /// `draw_from_campaign`.
+        fn campaign_grant(&self, reward: Balance) -> Balance {
+            self.active_campaign()
+                .and_then(|campaign_id| self.campaigns.get(campaign_id))
+                .map_or(0, |campaign| reward.min(campaign.remaining))
+        }
+
+        /// Draws `reward` from the budget of the active campaign.
+        ///
+        /// Returns the reward actually granted: nothing outside of an active campaign.
+        fn draw_from_campaign(&mut self, reward: Balance) -> Balance {
+            let Some(campaign_id) = self.active_campaign() else {
+                return 0;
+            };
+            let Some(mut campaign) = self.campaigns.get(campaign_id) else {
+                return 0;
+            };
+            let granted = reward.min(campaign.remaining);
+            campaign.remaining -= granted;
+            self.campaigns.insert(campaign_id, &campaign);
+            granted
+        }
+    }
+
+    impl Workflow for Demo {
+        /// Bind the caller's account to a contributor identity.
+        #[ink(message)]
+        fn register_identity(&mut self, contributor_id: ContributorId) -> Result<(), DemoError> {
+            let caller = self.env().caller();
+            if self.is_strict_mode() && !self.is_allowed(caller) {
+                return Err(DemoError::ContributorNotAllowed);
+            }
+            self._register_identity(contributor_id, caller)
+        }
+
+        /// Approve contribution. This is triggered by a workflow run.
+        ///
+        /// It can be called by the owner or a live session key, attaching the storage deposit
+        /// of the approval (see `approval_deposit`), the excess being refunded.
+        #[ink(message, payable)]
+        fn approve(
+            &mut self,
+            contribution_id: ContributionId,
+            contributor: AccountId,
+        ) -> Result<(), DemoError> {
+            self.ensure_approver(ink::selector_bytes!("Workflow::approve"))?;
+            self.with_approval_deposit(|demo| {
+                demo._approve(
+                    contribution_id,
+                    vec![(contributor, MAX_BPS)],
+                    MAX_DIFFICULTY,
+                    None,
+                )
+            })
+        }
+
+        /// Returns `true` if the caller is a contributor of a given contribution.
+        #[ink(message)]
+        fn check(&self, contribution_id: ContributionId) -> bool {
+            let caller = self.env().caller();
+            self.contributions
+                .get(contribution_id)
+                .map_or(false, |contribution| {
+                    contribution.split_of(caller).is_some()
+                })
+        }
+
+        /// Claim the caller's share of the reward of a given contribution.
+        ///
+        /// Returns the receipt hash of (`contribution_id`, `contributor`, `amount`, `block_number`).
+        #[ink(message)]
+        #[modifiers(non_reentrant)]
+        fn claim(&mut self, contribution_id: ContributionId) -> Result<HashValue, DemoError> {
+            self._claim(contribution_id, self.env().caller(), None, None, None)
+        }
+    }
+
+    impl KudosAttestor for Demo {
+        /// Returns `true` if `account` has at least `min_count` approved contributions.
+        #[ink(message)]
+        fn has_contribution(&self, account: AccountId, min_count: u32) -> bool {
+            self.get_approvals(account) >= min_count
+        }
+    }
+
+    /// The KUDOS points: a soulbound PSP22 only minted by the contract on approvals.
+    impl openbrush::contracts::traits::psp22::PSP22 for Demo {
+        #[ink(message)]
+        fn total_supply(&self) -> Balance {
+            self.points_supply.get_or_default()
+        }
+
+        #[ink(message)]
+        fn balance_of(&self, owner: AccountId) -> Balance {
+            self.points.get(owner).unwrap_or_default()
+        }
+
+        #[ink(message)]
+        fn allowance(&self, _owner: AccountId, _spender: AccountId) -> Balance {
+            0
+        }
+
+        #[ink(message)]
+        fn transfer(
+            &mut self,
+            _to: AccountId,
+            _value: Balance,
+            _data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            Err(soulbound())
+        }
+
+        #[ink(message)]
+        fn transfer_from(
+            &mut self,
+            _from: AccountId,
+            _to: AccountId,
+            _value: Balance,
+            _data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            Err(soulbound())
+        }
+
+        #[ink(message)]
+        fn approve(&mut self, _spender: AccountId, _value: Balance) -> Result<(), PSP22Error> {
+            Err(soulbound())
+        }
+
+        #[ink(message)]
+        fn increase_allowance(
+            &mut self,
+            _spender: AccountId,
+            _delta_value: Balance,
+        ) -> Result<(), PSP22Error> {
+            Err(soulbound())
+        }
+
+        #[ink(message)]
+        fn decrease_allowance(
+            &mut self,
+            _spender: AccountId,
+            _delta_value: Balance,
+        ) -> Result<(), PSP22Error> {
+            Err(soulbound())
+        }
+    }
+
+    /// The error returned by every transferring PSP22 message.
+    fn soulbound() -> PSP22Error {
+        PSP22Error::Custom(String::from("Soulbound"))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        /// Accounts
+        /// ALICE -> contract owner
+        /// BOB -> contributor
+
+        /// Imports all the definitions from the outer scope so we can use them here.
+        use super::*;
+        use crate::types::Platform;
+        use traits::test_utils::{
+            self, advance_block, advance_blocks, contract_id, default_accounts, get_balance,
+            set_balance, set_block_timestamp, set_next_caller,
+        };
+
+        use ink::env::test::EmittedEvent;
+        type Event = <Demo as ::ink::reflect::ContractEventBase>::Type;
+
+        /// We test if the constructor does its job.
+        #[ink::test]
+        fn new_works() {
+            create_contract();
+        }
+
+        #[ink::test]
+        fn distribute_fees_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let project_id = Hash::from([0x02; 32]);
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.bob, 0);
+            set_balance(accounts.charlie, 0);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_maintainer_share(project_id, accounts.bob, 5_000),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.distribute_fees(project_id),
+                Err(DemoError::NoMaintainers)
+            );
+            assert_eq!(
+                contract.set_maintainer_share(project_id, accounts.bob, 6_000),
+                Ok(())
+            );
+            assert_eq!(
+                contract.set_maintainer_share(project_id, accounts.charlie, 5_000),
+                Err(DemoError::InvalidSplits)
+            );
+            assert_eq!(
+                contract.set_maintainer_share(project_id, accounts.charlie, 3_333),
+                Ok(())
+            );
+            assert_eq!(
+                contract.maintainer_shares_of(project_id),
+                vec![(accounts.bob, 6_000), (accounts.charlie, 3_333)]
+            );
+
+            assert_eq!(
+                contract.deposit_project_fees(project_id),
+                Err(DemoError::ZeroDeposit)
+            );
+            // The off-chain environment does not move the transferred value
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+            assert_eq!(contract.deposit_project_fees(project_id), Ok(()));
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+            assert_eq!(contract.project_fees_of(project_id), 100);
+            assert_eq!(contract.liabilities_of(Asset::Native), 100);
+
+            // The rounding dust and the unassigned share are carried over
+            set_next_caller(accounts.django);
+            assert_eq!(contract.distribute_fees(project_id), Ok(93));
+            assert_eq!(get_balance(accounts.bob), 60);
+            assert_eq!(get_balance(accounts.charlie), 33);
+            assert_eq!(contract.project_fees_of(project_id), 7);
+            assert_eq!(contract.liabilities_of(Asset::Native), 7);
+
+            // Validate `FeesDistributed` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Some(Event::FeesDistributed(FeesDistributed { amount, dust, .. })) =
+                decoded_events.last()
+            else {
+                panic!("encountered unexpected event kind: expected a FeesDistributed event")
+            };
+            assert_eq!(*amount, 93);
+            assert_eq!(*dust, 7);
+
+            // Removing a maintainer hands their share to no one
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.set_maintainer_share(project_id, accounts.charlie, 0),
+                Ok(())
+            );
+            assert_eq!(
+                contract.maintainer_shares_of(project_id),
+                vec![(accounts.bob, 6_000)]
+            );
+        }
+
+        #[ink::test]
+        fn project_topics_work() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let project_id = Hash::from([0x02; 32]);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_project_id(project_id),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_project_id(project_id), Ok(()));
+            assert_eq!(contract.get_project_id(), project_id);
+            let _ = contract.approve(1u64, accounts.bob);
+
+            // The approval event is indexed by the project topic
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let topic = contract.topic_for_project(project_id, ProjectEvent::Approval);
+            let approval_event = emitted_events
+                .first()
+                .expect("no event emitted for the approval");
+            assert!(approval_event
+                .topics
+                .iter()
+                .any(|emitted| emitted[..] == topic.as_ref()[..]));
+            assert_ne!(
+                contract.topic_for_project(Hash::from([0x03; 32]), ProjectEvent::Approval),
+                topic
+            );
+            assert_ne!(
+                contract.topic_for_project(project_id, ProjectEvent::Claim),
+                topic
+            );
+        }
+
+        #[ink::test]
+        fn new_with_hash_algorithm_works() {
+            let contract = create_contract();
+            assert_eq!(contract.hash_algorithm(), HashAlgorithm::Blake2x256);
+
+            let contract = Demo::new_with_hash_algorithm(HashAlgorithm::Keccak256);
+            assert_eq!(contract.hash_algorithm(), HashAlgorithm::Keccak256);
+            assert_eq!(
+                contract.hash_identity(String::from("kudos-ink")),
+                HashAlgorithm::Keccak256.hash(b"kudos-ink")
+            );
+            assert_ne!(
+                contract.hash_identity(String::from("kudos-ink")),
+                HashAlgorithm::Blake2x256.hash(b"kudos-ink")
+            );
+        }
+
+        #[ink::test]
+        fn has_contribution_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            assert!(contract.has_contribution(accounts.bob, 0));
+            assert!(!contract.has_contribution(accounts.bob, 1));
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve(1u64, accounts.bob), Ok(()));
+            assert_eq!(contract.approve(2u64, accounts.bob), Ok(()));
+            assert!(contract.has_contribution(accounts.bob, 2));
+            assert!(!contract.has_contribution(accounts.bob, 3));
+            assert!(!contract.has_contribution(accounts.charlie, 1));
+        }
+
+        #[ink::test]
+        fn approve_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let contribution_id = 1u64;
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve(contribution_id, accounts.bob), Ok(()));
+
+            // Validate `ContributionApproval` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            assert_eq!(2, emitted_events.len());
+            let decoded_events = decode_events(emitted_events);
+            let Event::ContributionApproval(ContributionApproval {
+                id, contributor, ..
+            }) = decoded_events[0]
+            else {
+                panic!("encountered unexpected event kind: expected a ContributionApproval event")
+            };
+            assert_eq!(id, contribution_id);
+            assert_eq!(contributor, accounts.bob);
+
+            let maybe_contributor = contract.get_contributor(contribution_id);
+            assert_eq!(maybe_contributor, Some(accounts.bob));
+
+            // Approve it again returns an error
+            assert_eq!(
+                contract.approve(contribution_id, accounts.charlie),
+                Err(DemoError::ContributionAlreadyApproved {
+                    contributor: accounts.bob
+                })
+            );
+        }
+
+        #[ink::test]
+        fn latest_seq_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            assert_eq!(contract.latest_seq(), 0);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1u64, accounts.bob);
+            assert_eq!(contract.latest_seq(), 1);
+            let _ = contract.set_denied(accounts.charlie, true);
+            assert_eq!(contract.latest_seq(), 2);
+
+            // Events carry their sequence number
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Event::ContributionApproval(ContributionApproval { seq, .. }) = decoded_events[0]
+            else {
+                panic!("encountered unexpected event kind: expected a ContributionApproval event")
+            };
+            assert_eq!(seq, 1);
+            let Event::DenyListUpdated(DenyListUpdated { seq, .. }) = decoded_events[2] else {
+                panic!("encountered unexpected event kind: expected a DenyListUpdated event")
+            };
+            assert_eq!(seq, 2);
+        }
+
+        #[ink::test]
+        fn approve_with_nonce_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.nonce_of(accounts.alice), 0);
+            assert_eq!(contract.approve_with_nonce(1u64, accounts.bob, 0), Ok(()));
+            assert_eq!(contract.nonce_of(accounts.alice), 1);
+
+            // Replayed and skipped nonces fail
+            assert_eq!(
+                contract.approve_with_nonce(2u64, accounts.bob, 0),
+                Err(DemoError::InvalidNonce)
+            );
+            assert_eq!(
+                contract.approve_with_nonce(2u64, accounts.bob, 2),
+                Err(DemoError::InvalidNonce)
+            );
+            assert_eq!(contract.approve_with_nonce(2u64, accounts.bob, 1), Ok(()));
+            assert_eq!(contract.nonce_of(accounts.alice), 2);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.approve_with_nonce(3u64, accounts.bob, 0),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn only_contract_owner_can_approve() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let contribution_id = 1u64;
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.approve(contribution_id, accounts.alice),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn approve_and_register_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let contributor_id = (Platform::GitHub, [0x01; 32]);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.approve_and_register(1u64, contributor_id, accounts.bob),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.approve_and_register(1u64, contributor_id, accounts.bob),
+                Ok(())
+            );
+            assert_eq!(contract.get_identity(contributor_id), Some(accounts.bob));
+            assert_eq!(contract.get_contributor(1u64), Some(accounts.bob));
+
+            // The identity is not bound when the approval fails
+            let contributor_id = (Platform::GitHub, [0x02; 32]);
+            assert_eq!(
+                contract.approve_and_register(1u64, contributor_id, accounts.charlie),
+                Err(DemoError::ContributionAlreadyApproved {
+                    contributor: accounts.bob
+                })
+            );
+            assert_eq!(contract.get_identity(contributor_id), None);
+
+            // The contribution is not approved when the identity is taken
+            assert_eq!(
+                contract.approve_and_register(
+                    2u64,
+                    (Platform::GitHub, [0x01; 32]),
+                    accounts.charlie
+                ),
+                Err(DemoError::IdentityAlreadyRegistered)
+            );
+            assert_eq!(contract.get_contributor(2u64), None);
+        }
+
+        #[ink::test]
+        fn approve_idempotent_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.approve_idempotent(1u64, accounts.bob),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve_idempotent(1u64, accounts.bob), Ok(()));
+            assert_eq!(contract.approve_idempotent(1u64, accounts.bob), Ok(()));
+            assert_eq!(contract.get_approvals(accounts.bob), 1);
+
+            // Validate `AlreadyApproved` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Some(Event::AlreadyApproved(AlreadyApproved {
+                id, contributor, ..
+            })) = decoded_events.last()
+            else {
+                panic!("encountered unexpected event kind: expected a AlreadyApproved event")
+            };
+            assert_eq!((*id, *contributor), (1u64, accounts.bob));
+
+            assert_eq!(
+                contract.approve_idempotent(1u64, accounts.charlie),
+                Err(DemoError::ContributionAlreadyApproved {
+                    contributor: accounts.bob
+                })
+            );
+        }
+
+        #[ink::test]
+        fn permissions_work() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let selector = ink::selector_bytes!("set_reward");
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_permission(1, selector, true),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+            assert_eq!(
+                contract.set_role(accounts.bob, Some(1)),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_role(accounts.bob, Some(1)), Ok(()));
+            assert_eq!(contract.role_of(accounts.bob), Some(1));
+            assert!(!contract.can_call(accounts.bob, selector));
+            assert_eq!(contract.set_permission(1, selector, true), Ok(()));
+            assert!(contract.is_permitted(1, selector));
+            assert!(contract.can_call(accounts.bob, selector));
+            assert!(contract.can_call(accounts.alice, selector));
+
+            // The role calls the permitted message only
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.set_reward(100), Ok(()));
+            assert_eq!(contract.get_reward(), 100);
+            assert_eq!(
+                contract.set_bonus_bps(100),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_permission(1, selector, false), Ok(()));
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_reward(200),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_permission(1, selector, true);
+            assert_eq!(contract.set_role(accounts.bob, None), Ok(()));
+            assert_eq!(contract.role_of(accounts.bob), None);
+            assert!(!contract.can_call(accounts.bob, selector));
+        }
+
+        #[ink::test]
+        fn pinned_selectors_are_dispatched() {
+            use ink::reflect::DispatchableMessageInfo;
+            use traits::selectors::{demo, workflow};
+
+            // Does not compile if no message is dispatched at one of the published selectors
+            let dispatched = [
+                <Demo as DispatchableMessageInfo<{ workflow::REGISTER_IDENTITY }>>::SELECTOR,
+                <Demo as DispatchableMessageInfo<{ workflow::APPROVE }>>::SELECTOR,
+                <Demo as DispatchableMessageInfo<{ workflow::CHECK }>>::SELECTOR,
+                <Demo as DispatchableMessageInfo<{ workflow::CLAIM }>>::SELECTOR,
+                <Demo as DispatchableMessageInfo<{ demo::SUBMIT }>>::SELECTOR,
+                <Demo as DispatchableMessageInfo<{ demo::CLAIM_PARTIAL }>>::SELECTOR,
+                <Demo as DispatchableMessageInfo<{ demo::ESTIMATE_CLAIM }>>::SELECTOR,
+                <Demo as DispatchableMessageInfo<{ demo::CLAIMED_AMOUNT }>>::SELECTOR,
+                <Demo as DispatchableMessageInfo<{ demo::CONTRIBUTION_VIEW }>>::SELECTOR,
+                <Demo as DispatchableMessageInfo<{ demo::IDENTITY_VIEW }>>::SELECTOR,
+                <Demo as DispatchableMessageInfo<{ demo::TIP }>>::SELECTOR,
+                <Demo as DispatchableMessageInfo<{ demo::GET_REWARD }>>::SELECTOR,
+                <Demo as DispatchableMessageInfo<{ demo::CURRENT_REWARD }>>::SELECTOR,
+                <Demo as DispatchableMessageInfo<{ demo::APPROVAL_DEPOSIT }>>::SELECTOR,
+                <Demo as DispatchableMessageInfo<{ demo::SET_PAYOUT_ADDRESS }>>::SELECTOR,
+                <Demo as DispatchableMessageInfo<{ demo::PAYOUT_ADDRESS_OF }>>::SELECTOR,
+                <Demo as DispatchableMessageInfo<{ demo::VERIFY_RECEIPT }>>::SELECTOR,
+            ];
+            let published = [
+                workflow::REGISTER_IDENTITY,
+                workflow::APPROVE,
+                workflow::CHECK,
+                workflow::CLAIM,
+                demo::SUBMIT,
+                demo::CLAIM_PARTIAL,
+                demo::ESTIMATE_CLAIM,
+                demo::CLAIMED_AMOUNT,
+                demo::CONTRIBUTION_VIEW,
+                demo::IDENTITY_VIEW,
+                demo::TIP,
+                demo::GET_REWARD,
+                demo::CURRENT_REWARD,
+                demo::APPROVAL_DEPOSIT,
+                demo::SET_PAYOUT_ADDRESS,
+                demo::PAYOUT_ADDRESS_OF,
+                demo::VERIFY_RECEIPT,
+            ];
+            for (dispatched, published) in dispatched.iter().zip(published) {
+                assert_eq!(*dispatched, published.to_be_bytes());
+            }
+        }
+
+        #[ink::test]
+        fn permitted_role_approves_and_creates_campaigns() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_role(accounts.charlie, Some(1));
+            let _ = contract.set_permission(1, ink::selector_bytes!("create_campaign"), true);
+            let _ = contract.set_permission(
+                1,
+                traits::selectors::workflow::APPROVE.to_be_bytes(),
+                true,
+            );
+
+            // A single permission is enough to create a native campaign
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.create_campaign(0, 10, 1_000), Ok(0));
+            assert_eq!(contract.approve(1u64, accounts.bob), Ok(()));
+            assert_eq!(
+                contract.approve_submission(2u64),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn proxies_work() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.django);
+            assert_eq!(
+                contract.approve(1u64, accounts.bob),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+            assert_eq!(
+                contract.set_proxy(accounts.django, true, false),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            // The calls of the proxy are treated as the owner's
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_proxy(accounts.django, true, false), Ok(()));
+            assert_eq!(contract.get_proxy(accounts.django), Some(false));
+            set_next_caller(accounts.django);
+            assert_eq!(contract.approve(1u64, accounts.bob), Ok(()));
+            assert_eq!(contract.set_reward(100), Ok(()));
+
+            // Unless it must forward the original signer
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_proxy(accounts.django, true, true), Ok(()));
+            set_next_caller(accounts.django);
+            assert_eq!(
+                contract.approve(2u64, accounts.bob),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+            assert_eq!(
+                contract.approve_forwarded(accounts.charlie, 2u64, accounts.bob),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+            assert_eq!(
+                contract.approve_forwarded(accounts.alice, 2u64, accounts.bob),
+                Ok(())
+            );
+
+            set_next_caller(accounts.eve);
+            assert_eq!(
+                contract.approve_forwarded(accounts.alice, 3u64, accounts.bob),
+                Err(DemoError::CallerIsNotProxy)
+            );
+
+            // The forwarded signer is the approver checked for self-approvals
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                self_approval: SelfApproval::Forbidden,
+                ..Config::default()
+            });
+            set_next_caller(accounts.django);
+            assert_eq!(
+                contract.approve_forwarded(accounts.alice, 3u64, accounts.alice),
+                Err(DemoError::SelfApprovalForbidden)
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_proxy(accounts.django, false, false), Ok(()));
+            assert_eq!(contract.get_proxy(accounts.django), None);
+        }
+
+        #[ink::test]
+        fn session_keys_work() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.grant_session(accounts.django, 5),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            advance_block();
+            assert_eq!(
+                contract.grant_session(accounts.django, 0),
+                Err(DemoError::InvalidSessionExpiry)
+            );
+            assert_eq!(contract.grant_session(accounts.django, 5), Ok(()));
+            assert_eq!(contract.session_expiry(accounts.django), Some(5));
+
+            // The session key can approve, and nothing else
+            set_next_caller(accounts.django);
+            assert_eq!(contract.approve(1u64, accounts.bob), Ok(()));
+            assert_eq!(
+                contract.set_reward(100),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+            assert_eq!(
+                contract.grant_session(accounts.eve, 5),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            advance_blocks(5);
+            assert_eq!(
+                contract.approve(2u64, accounts.bob),
+                Err(DemoError::SessionExpired)
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.grant_session(accounts.django, 10), Ok(()));
+            assert_eq!(contract.revoke_session(accounts.django), Ok(()));
+            set_next_caller(accounts.django);
+            assert_eq!(
+                contract.approve(2u64, accounts.bob),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn already_approved_contribution_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let contribution_id = 1u64;
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(contribution_id, accounts.bob);
+
+            assert_eq!(
+                contract.approve(contribution_id, accounts.bob),
+                Err(DemoError::ContributionAlreadyApproved {
+                    contributor: accounts.bob
+                })
+            );
+        }
+
+        #[ink::test]
+        fn approve_zero_or_contract_account_fails() {
+            let mut contract = create_contract();
+
+            assert_eq!(
+                contract.approve(1u64, AccountId::from([0u8; 32])),
+                Err(DemoError::ZeroAccount)
+            );
+            assert_eq!(
+                contract.approve(1u64, contract_id()),
+                Err(DemoError::ContractAccount)
+            );
+            assert_eq!(contract.get_contributor(1u64), None);
+
+            set_next_caller(AccountId::from([0u8; 32]));
+            assert_eq!(
+                contract.register_identity((Platform::GitHub, [0x01; 32])),
+                Err(DemoError::ZeroAccount)
+            );
+            set_next_caller(contract_id());
+            assert_eq!(
+                contract.register_identity((Platform::GitHub, [0x01; 32])),
+                Err(DemoError::ContractAccount)
+            );
+        }
+
+        #[ink::test]
+        fn contributor_getter_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let contribution_id = 1u64;
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(contribution_id, accounts.bob);
+
+            assert_eq!(
+                contract.get_contributor(contribution_id),
+                Some(accounts.bob)
+            );
+            assert_eq!(contract.get_contributor(2u64), None);
+        }
+
+        #[ink::test]
+        fn register_identity_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let contributor_id = (Platform::GitHub, [0x01; 32]);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(contributor_id), Ok(()));
+
+            // Validate `IdentityRegistered` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            assert_eq!(1, emitted_events.len());
+            let decoded_events = decode_events(emitted_events);
+            let Event::IdentityRegistered(IdentityRegistered {
+                contributor_id: registered_id,
+                caller,
+                ..
+            }) = decoded_events[0]
+            else {
+                panic!("encountered unexpected event kind: expected a IdentityRegistered event")
+            };
+            assert_eq!(registered_id, contributor_id);
+            assert_eq!(caller, accounts.bob);
+
+            assert_eq!(contract.get_identity(contributor_id), Some(accounts.bob));
+            assert_eq!(contract.get_identity((Platform::GitHub, [0x02; 32])), None);
+            assert_eq!(contract.get_identity((Platform::GitLab, [0x01; 32])), None);
+        }
+
+        #[ink::test]
+        fn register_identities_on_many_platforms_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_shared(accounts.bob, true), Ok(()));
+
+            set_next_caller(accounts.bob);
+            for platform in [Platform::GitHub, Platform::GitLab, Platform::Email] {
+                assert_eq!(contract.register_identity((platform, [0x01; 32])), Ok(()));
+                assert_eq!(
+                    contract.get_identity((platform, [0x01; 32])),
+                    Some(accounts.bob)
+                );
+            }
+        }
+
+        #[ink::test]
+        fn already_registered_identity_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let contributor_id = (Platform::GitHub, [0x01; 32]);
+
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(contributor_id);
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.register_identity(contributor_id),
+                Err(DemoError::IdentityAlreadyRegistered)
+            );
+        }
+
+        #[ink::test]
+        fn account_bound_to_many_identities_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let contributor_id = (Platform::GitHub, [0x01; 32]);
+
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(contributor_id);
+            assert_eq!(
+                contract.get_contributor_id(accounts.bob),
+                Some(contributor_id)
+            );
+            assert_eq!(
+                contract.register_identity((Platform::GitLab, [0x01; 32])),
+                Err(DemoError::AccountAlreadyRegistered)
+            );
+            assert_eq!(contract.get_contributor_id(accounts.charlie), None);
+
+            // Shared accounts keep their first identity
+            set_next_caller(accounts.alice);
+            let _ = contract.set_shared(accounts.bob, true);
+            assert!(contract.is_shared(accounts.bob));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.register_identity((Platform::GitLab, [0x01; 32])),
+                Ok(())
+            );
+            assert_eq!(
+                contract.get_contributor_id(accounts.bob),
+                Some(contributor_id)
+            );
+        }
+
+        #[ink::test]
+        fn only_contract_owner_can_set_shared() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_shared(accounts.bob, true),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn register_identity_fails_with_identity_registry() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.set_identity_registry(Some(accounts.django)),
+                Ok(())
+            );
+            assert_eq!(contract.get_identity_registry(), Some(accounts.django));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.register_identity((Platform::GitHub, [0x01; 32])),
+                Err(DemoError::IdentityRegistryInUse)
+            );
+        }
+
+        #[ink::test]
+        fn only_contract_owner_can_set_identity_registry() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_identity_registry(Some(accounts.django)),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn strict_mode_only_allows_allowed_accounts_to_register() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_strict_mode(true), Ok(()));
+            assert!(contract.is_strict_mode());
+            assert_eq!(contract.set_allowed(accounts.bob, true), Ok(()));
+            assert!(contract.is_allowed(accounts.bob));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.register_identity((Platform::GitHub, [0x01; 32])),
+                Ok(())
+            );
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.register_identity((Platform::GitHub, [0x02; 32])),
+                Err(DemoError::ContributorNotAllowed)
+            );
+
+            // Removed accounts cannot register anymore
+            set_next_caller(accounts.alice);
+            let _ = contract.set_allowed(accounts.bob, false);
+            assert!(!contract.is_allowed(accounts.bob));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.register_identity((Platform::GitHub, [0x03; 32])),
+                Err(DemoError::ContributorNotAllowed)
+            );
+        }
+
+        #[ink::test]
+        fn activity_of_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity((Platform::GitHub, [0x01; 32]));
+            advance_block();
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1u64, accounts.bob);
+            advance_block();
+            set_next_caller(accounts.bob);
+            let _ = contract.claim(1u64);
+            assert_eq!(
+                contract.activity_of(accounts.bob),
+                vec![
+                    (0, Activity::Registered),
+                    (1, Activity::Approved(1u64)),
+                    (2, Activity::Claimed(1u64)),
+                ]
+            );
+            assert_eq!(contract.activity_of(accounts.charlie), vec![]);
+
+            // Only the latest actions are kept
+            set_next_caller(accounts.alice);
+            for contribution_id in 2..=ACTIVITY_LOG_CAPACITY as u64 {
+                let _ = contract.approve(contribution_id, accounts.bob);
+            }
+            let activities = contract.activity_of(accounts.bob);
+            assert_eq!(activities.len(), ACTIVITY_LOG_CAPACITY);
+            assert_eq!(activities[0], (2, Activity::Claimed(1u64)));
+        }
+
+        #[ink::test]
+        fn ban_contributor_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.ban_contributor(accounts.charlie, 1),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1u64, accounts.charlie);
+            assert_eq!(contract.ban_contributor(accounts.charlie, 1), Ok(()));
+            assert_eq!(contract.banned(accounts.charlie), Some(1));
+            assert_eq!(
+                contract.approve(2u64, accounts.charlie),
+                Err(DemoError::ContributorDenied)
+            );
+
+            // Validate `ContributorBanned` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Some(Event::ContributorBanned(ContributorBanned {
+                account, reason, ..
+            })) = decoded_events.last()
+            else {
+                panic!("encountered unexpected event kind: expected a ContributorBanned event")
+            };
+            assert_eq!((*account, *reason), (accounts.charlie, 1));
+
+            // The history of the contributor is kept
+            assert_eq!(contract.get_approvals(accounts.charlie), 1);
+            assert_eq!(contract.unban(accounts.charlie), Ok(()));
+            assert_eq!(contract.banned(accounts.charlie), None);
+            assert_eq!(contract.approve(2u64, accounts.charlie), Ok(()));
+        }
+
+        #[ink::test]
+        fn denied_contributor_cannot_be_approved_or_claim() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1u64, accounts.bob);
+            assert_eq!(contract.set_denied(accounts.bob, true), Ok(()));
+            assert!(contract.is_denied(accounts.bob));
+
+            // Validate `DenyListUpdated` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Event::DenyListUpdated(DenyListUpdated {
+                account, denied, ..
+            }) = decoded_events[2]
+            else {
+                panic!("encountered unexpected event kind: expected a DenyListUpdated event")
+            };
+            assert_eq!(account, accounts.bob);
+            assert!(denied);
+
+            assert_eq!(
+                contract.approve(2u64, accounts.bob),
+                Err(DemoError::ContributorDenied)
+            );
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1u64), Err(DemoError::ContributorDenied));
+
+            // Removed accounts can claim again
+            set_next_caller(accounts.alice);
+            let _ = contract.set_denied(accounts.bob, false);
+
+            set_next_caller(accounts.bob);
+            assert!(contract.claim(1u64).is_ok());
+        }
+
+        #[ink::test]
+        fn only_contract_owner_can_manage_lists() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_allowed(accounts.bob, true),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+            assert_eq!(
+                contract.set_denied(accounts.charlie, true),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+            assert_eq!(
+                contract.set_strict_mode(true),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn can_approve_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.can_approve(1u64, accounts.bob), Ok(()));
+
+            let _ = contract.approve(1u64, accounts.bob);
+            assert_eq!(
+                contract.can_approve(1u64, accounts.bob),
+                Err(DemoError::ContributionAlreadyApproved {
+                    contributor: accounts.bob
+                })
+            );
+
+            let _ = contract.set_denied(accounts.charlie, true);
+            assert_eq!(
+                contract.can_approve(2u64, accounts.charlie),
+                Err(DemoError::ContributorDenied)
+            );
+
+            let _ = contract.set_config(Config {
+                deposit_per_byte: 2,
+                ..contract.config()
+            });
+            let needed = 2 * APPROVAL_STORAGE_BYTES;
+            assert_eq!(
+                contract.can_approve(2u64, accounts.bob),
+                Err(DemoError::InsufficientDeposit { needed })
+            );
+            // The off-chain environment does not move the transferred value
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(needed);
+            assert_eq!(contract.can_approve(2u64, accounts.bob), Ok(()));
+
+            // Session keys approve too
+            let _ = contract.grant_session(accounts.django, 10);
+            set_next_caller(accounts.django);
+            assert_eq!(contract.can_approve(2u64, accounts.bob), Ok(()));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.can_approve(2u64, accounts.bob),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        /// The compressed public key of the `0x11..11` secret key.
+        const VERIFIER: EcdsaPublicKey = [
+            0x03, 0x4f, 0x35, 0x5b, 0xdc, 0xb7, 0xcc, 0x0a, 0xf7, 0x28, 0xef, 0x3c, 0xce, 0xb9,
+            0x61, 0x5d, 0x90, 0x68, 0x4b, 0xb5, 0xb2, 0xca, 0x5f, 0x85, 0x9a, 0xb0, 0xf0, 0xb7,
+            0x04, 0x07, 0x58, 0x71, 0xaa,
+        ];
+
+        /// The signature by `VERIFIER` of (BOB, (GitHub, [0x01; 32])).
+        const PROOF: EcdsaSignature = [
+            0xd4, 0x76, 0x44, 0x53, 0x9a, 0xce, 0xc3, 0xda, 0x5e, 0x3e, 0xcf, 0x5f, 0xe8, 0x86,
+            0x3c, 0x62, 0x8a, 0x9c, 0x97, 0xe8, 0xb7, 0x1e, 0x9e, 0xa9, 0x16, 0x7a, 0x6f, 0x4f,
+            0x83, 0xc0, 0x3c, 0x32, 0x7c, 0x4f, 0x79, 0x88, 0x5a, 0x57, 0xe1, 0x63, 0x60, 0x8c,
+            0xba, 0xfb, 0x8f, 0x5d, 0x89, 0x58, 0xb8, 0x76, 0x6f, 0x0e, 0x64, 0xe9, 0x2d, 0xfe,
+            0xe4, 0x9c, 0xd4, 0x43, 0xb9, 0x33, 0x00, 0xea, 0x01,
+        ];
+
+        #[ink::test]
+        fn register_identity_with_proof_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let contributor_id = (Platform::GitHub, [0x01; 32]);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_strict_mode(true);
+            assert_eq!(contract.set_verifier(Some(VERIFIER)), Ok(()));
+            assert_eq!(contract.get_verifier(), Some(VERIFIER));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.register_identity_with_proof(contributor_id, PROOF),
+                Ok(())
+            );
+            assert_eq!(contract.get_identity(contributor_id), Some(accounts.bob));
+        }
+
+        #[ink::test]
+        fn register_identity_with_invalid_proof_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let contributor_id = (Platform::GitHub, [0x01; 32]);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.register_identity_with_proof(contributor_id, PROOF),
+                Err(DemoError::NoVerifier)
+            );
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_verifier(Some(VERIFIER));
+
+            // The proof is bound to the caller and the identity
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.register_identity_with_proof(contributor_id, PROOF),
+                Err(DemoError::InvalidSignature)
+            );
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.register_identity_with_proof((Platform::GitLab, [0x01; 32]), PROOF),
+                Err(DemoError::InvalidSignature)
+            );
+        }
+
+        #[ink::test]
+        fn only_contract_owner_can_set_verifier() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_verifier(Some(VERIFIER)),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        /// The signature by `VERIFIER` of (contract, BOB, 30, 1_000).
+        const AGE_ATTESTATION: EcdsaSignature = [
+            0x2b, 0x04, 0xa1, 0xf5, 0x21, 0xfd, 0xff, 0x74, 0xed, 0xe6, 0x80, 0x03, 0x66, 0x69,
+            0xe7, 0x61, 0xa8, 0xaa, 0x72, 0x6f, 0x7d, 0x25, 0xcc, 0x36, 0xf2, 0x55, 0xa7, 0x68,
+            0x6d, 0x66, 0xf2, 0xe2, 0x2e, 0x99, 0x76, 0x9e, 0x4c, 0x8f, 0x94, 0xd8, 0xdf, 0x20,
+            0xe6, 0x42, 0xc9, 0x33, 0xf3, 0x4d, 0x65, 0x57, 0x5a, 0xde, 0x04, 0xba, 0xea, 0x01,
+            0x76, 0x93, 0xb0, 0x0f, 0xa9, 0x21, 0xe6, 0xf0, 0x00,
+        ];
+
+        #[ink::test]
+        fn auto_claim_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let minimum_balance = ink::env::minimum_balance::<ink::env::DefaultEnvironment>();
+            set_balance(contract_id(), minimum_balance + 1_000);
+            set_balance(accounts.bob, 0);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_auto_claim(true),
+                Err(DemoError::IdentityNotRegistered)
+            );
+            let _ = contract.register_identity((Platform::GitHub, [0x01; 32]));
+            assert_eq!(contract.set_auto_claim(true), Ok(()));
+            assert!(contract.is_auto_claim((Platform::GitHub, [0x01; 32])));
+
+            // The reward is paid on approval
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(100);
+            let _ = contract.create_campaign(0, 10, 1_000);
+            assert_eq!(contract.approve(1u64, accounts.bob), Ok(()));
+            assert_eq!(get_balance(accounts.bob), 100);
+            assert_eq!(contract.claimed_amount(1u64, accounts.bob), 100);
+
+            // It is left to claim when the pool does not cover it
+            set_balance(contract_id(), minimum_balance + 50);
+            assert_eq!(contract.approve(2u64, accounts.bob), Ok(()));
+            assert_eq!(get_balance(accounts.bob), 100);
+            assert_eq!(contract.claimed_amount(2u64, accounts.bob), 0);
+
+            set_balance(contract_id(), 1_000);
+            set_next_caller(accounts.bob);
+            assert!(contract.claim(2u64).is_ok());
+            assert_eq!(get_balance(accounts.bob), 200);
+
+            assert_eq!(contract.set_auto_claim(false), Ok(()));
+            assert!(!contract.is_auto_claim((Platform::GitHub, [0x01; 32])));
+        }
+
+        #[ink::test]
+        fn failed_claim_payout_keeps_the_share_claimable() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.eve, 0);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(100);
+            let _ = contract.create_campaign(0, 10, 1_000);
+            let _ = contract.approve(1u64, accounts.bob);
+            set_next_caller(accounts.charlie);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(30);
+            let _ = contract.tip(1u64);
+
+            // The off-chain environment has no existential deposit: the payout to the fresh
+            // payout address is refused by a pool only covering the tips instead
+            set_next_caller(accounts.bob);
+            let _ = contract.set_payout_address(accounts.eve);
+            set_balance(contract_id(), 30);
+            assert_eq!(
+                contract._claim(1u64, accounts.bob, None, None, None),
+                Err(DemoError::InsufficientPool {
+                    needed: 100,
+                    available: 0
+                })
+            );
+            assert_eq!(get_balance(accounts.eve), 30);
+            assert_eq!(contract.pending_tips_of(1u64, accounts.bob), 0);
+            assert_eq!(contract.claimed_amount(1u64, accounts.bob), 0);
+            assert_eq!(contract.liabilities_of(Asset::Native), 100);
+            assert_eq!(contract.status_of(1u64), Status::Approved);
+        }
+
+        #[ink::test]
+        fn suspected_duplicate_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                reward: 100,
+                duplicate_claim_threshold: 150,
+                ..Config::default()
+            });
+            let _ = contract.create_campaign(0, 10, 1_000);
+            let _ = contract.approve(1u64, accounts.charlie);
+            let _ = contract.approve(2u64, accounts.charlie);
+
+            // Bob sends their rewards to Charlie, who registers another identity
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity((Platform::GitHub, [0x01; 32]));
+            let _ = contract.set_payout_address(accounts.charlie);
+            assert_eq!(contract.suspected_duplicate_of(accounts.charlie), None);
+
+            set_next_caller(accounts.charlie);
+            let _ = contract.register_identity((Platform::GitHub, [0x02; 32]));
+            assert_eq!(
+                contract.suspected_duplicate_of(accounts.charlie),
+                Some((Platform::GitHub, [0x01; 32]))
+            );
+
+            // Validate `SuspectedDuplicate` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Some(Event::SuspectedDuplicate(SuspectedDuplicate {
+                account,
+                contributor_id,
+                other,
+                ..
+            })) = decoded_events.last()
+            else {
+                panic!("encountered unexpected event kind: expected a SuspectedDuplicate event")
+            };
+            assert_eq!(*account, accounts.charlie);
+            assert_eq!(*contributor_id, (Platform::GitHub, [0x02; 32]));
+            assert_eq!(*other, (Platform::GitHub, [0x01; 32]));
+
+            // Claims are limited until the manual review
+            assert!(contract.claim(1u64).is_ok());
+            assert_eq!(contract.claim(2u64), Err(DemoError::DuplicateUnderReview));
+            assert_eq!(
+                contract.clear_suspected_duplicate(accounts.charlie),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.clear_suspected_duplicate(accounts.charlie), Ok(()));
+            assert_eq!(contract.suspected_duplicate_of(accounts.charlie), None);
+
+            set_next_caller(accounts.charlie);
+            assert!(contract.claim(2u64).is_ok());
+        }
+
+        #[ink::test]
+        fn attest_account_age_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                reward: 100,
+                attestation_threshold: 50,
+                min_account_age: 30,
+                ..Config::default()
+            });
+            let _ = contract.create_campaign(0, 10, 1_000);
+            let _ = contract.approve(1u64, accounts.bob);
+            let _ = contract.approve(2u64, accounts.bob);
+
+            // Claims above the threshold need an attestation
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1u64), Err(DemoError::AttestationRequired));
+            assert_eq!(
+                contract.attest_account_age(1_000, AGE_ATTESTATION),
+                Err(DemoError::NoVerifier)
+            );
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_verifier(Some(VERIFIER));
+
+            set_block_timestamp(1_000);
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.attest_account_age(1_000, AGE_ATTESTATION),
+                Err(DemoError::AttestationExpired)
+            );
+
+            // The attestation is bound to the caller
+            set_block_timestamp(500);
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.attest_account_age(1_000, AGE_ATTESTATION),
+                Err(DemoError::InvalidSignature)
+            );
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.attest_account_age(1_000, AGE_ATTESTATION), Ok(()));
+            assert_eq!(contract.age_attestation_of(accounts.bob), Some(1_000));
+
+            // Validate `AccountAgeAttested` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Some(Event::AccountAgeAttested(AccountAgeAttested {
+                account,
+                min_account_age,
+                expires_at,
+                ..
+            })) = decoded_events.last()
+            else {
+                panic!("encountered unexpected event kind: expected a AccountAgeAttested event")
+            };
+            assert_eq!(*account, accounts.bob);
+            assert_eq!(*min_account_age, 30);
+            assert_eq!(*expires_at, 1_000);
+
+            assert!(contract.claim(1u64).is_ok());
+
+            // The claims are not paid once the attestation expires
+            set_block_timestamp(1_000);
+            assert_eq!(contract.claim(2u64), Err(DemoError::AttestationRequired));
+            assert_eq!(contract.claim_partial(2u64, 50).map(|_| ()), Ok(()));
+        }
+
+        /// The account of the `0x22..22` ECDSA secret key.
+        const VOUCHER_SIGNER: [u8; 32] = [
+            0xfe, 0x52, 0x77, 0x28, 0x23, 0x14, 0x25, 0xd0, 0x43, 0xda, 0x50, 0x74, 0x71, 0x88,
+            0x49, 0x07, 0xd1, 0x2b, 0xe5, 0xe8, 0x0b, 0xe2, 0xd0, 0x16, 0xe9, 0xea, 0xca, 0x1c,
+            0xc5, 0x3f, 0x06, 0x97,
+        ];
+
+        /// The signature by `VOUCHER_SIGNER` of (contract, `voucher()`).
+        const VOUCHER_SIGNATURE: EcdsaSignature = [
+            0xf3, 0x0e, 0x4b, 0xd8, 0x09, 0x4e, 0x53, 0xa6, 0x79, 0xdd, 0xb8, 0xf5, 0x5b, 0x52,
+            0x16, 0xb0, 0x3c, 0x44, 0x62, 0x3f, 0xc4, 0x27, 0x9e, 0xf0, 0x79, 0x1f, 0x9a, 0xa1,
+            0xf6, 0x93, 0x0d, 0x49, 0x41, 0xfe, 0x15, 0xc1, 0xe7, 0x4d, 0x86, 0xd1, 0xcc, 0xc4,
+            0xff, 0xd3, 0x48, 0x53, 0xcd, 0xf2, 0x58, 0x05, 0xd2, 0xf3, 0x10, 0x57, 0xce, 0x42,
+            0xd0, 0x38, 0x55, 0x0c, 0x3e, 0x27, 0xa0, 0x18, 0x00,
+        ];
+
+        /// The voucher of a 100 reward for the contribution 1 of BOB.
+        fn voucher() -> Voucher {
+            Voucher {
+                contribution_id: 1u64,
+                contributor: default_accounts().bob,
+                amount: 100,
+                nonce: 0,
+            }
+        }
+
+        /// The signature by `VOUCHER_SIGNER` of (contract, 1, `VOUCHER_SIGNER`, 0).
+        const CLAIM_SIGNATURE: EcdsaSignature = [
+            0x3c, 0x72, 0xad, 0xdb, 0x4f, 0xdf, 0x09, 0xaf, 0x94, 0xf0, 0xc9, 0x4d, 0x7f, 0xe9,
+            0x2a, 0x38, 0x6a, 0x7e, 0x70, 0xcf, 0x8a, 0x1d, 0x85, 0x91, 0x63, 0x86, 0xbb, 0x25,
+            0x35, 0xc7, 0xb1, 0xb1, 0x73, 0x05, 0x43, 0x92, 0xf1, 0x6d, 0x57, 0x6f, 0x2a, 0x03,
+            0x09, 0x76, 0x8a, 0x9b, 0x01, 0xb7, 0x22, 0xb5, 0x7c, 0x31, 0x1c, 0x8e, 0xf5, 0xad,
+            0x9b, 0xaa, 0x80, 0xe0, 0xf2, 0x1b, 0x2b, 0x92, 0x01,
+        ];
+
+        #[ink::test]
+        fn claim_on_behalf_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let contributor = AccountId::from(VOUCHER_SIGNER);
+            set_balance(contract_id(), 1_000);
+            set_balance(contributor, 0);
+            set_balance(accounts.charlie, 0);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                reward: 100,
+                relayer_fee_bps: 1_000,
+                ..Config::default()
+            });
+            let _ = contract.create_campaign(0, 10, 1_000);
+            let _ = contract.approve(1u64, contributor);
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.claim_on_behalf(1u64, accounts.bob, CLAIM_SIGNATURE),
+                Err(DemoError::InvalidSignature)
+            );
+            assert!(contract
+                .claim_on_behalf(1u64, contributor, CLAIM_SIGNATURE)
+                .is_ok());
+            assert_eq!(get_balance(contributor), 90);
+            assert_eq!(get_balance(accounts.charlie), 10);
+            assert_eq!(contract.claim_nonce_of(contributor), 1);
+
+            // The signature cannot be replayed
+            assert_eq!(
+                contract.claim_on_behalf(1u64, contributor, CLAIM_SIGNATURE),
+                Err(DemoError::InvalidSignature)
+            );
+        }
+
+        #[ink::test]
+        fn optimistic_approval_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(accounts.charlie, 0);
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.propose_approval(1u64, accounts.bob),
+                Err(DemoError::OptimisticApprovalsDisabled)
+            );
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                approval_stake: 100,
+                challenge_period: 10,
+                ..Config::default()
+            });
+
+            set_next_caller(accounts.charlie);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(99);
+            assert_eq!(
+                contract.propose_approval(1u64, accounts.bob),
+                Err(DemoError::InsufficientStake)
+            );
+            // The off-chain environment does not move the transferred value
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+            set_balance(contract_id(), 1_000);
+            assert_eq!(contract.propose_approval(1u64, accounts.bob), Ok(()));
+            assert_eq!(
+                contract.propose_approval(1u64, accounts.bob),
+                Err(DemoError::ApprovalAlreadyProposed)
+            );
+            assert_eq!(contract.liabilities_of(Asset::Native), 100);
+
+            advance_blocks(10);
+            assert_eq!(
+                contract.finalize_approval(1u64),
+                Err(DemoError::ChallengePeriodNotOver)
+            );
+            advance_block();
+            assert_eq!(contract.finalize_approval(1u64), Ok(()));
+            assert!(contract.contributions.contains(1u64));
+            assert_eq!(get_balance(accounts.charlie), 100);
+            assert_eq!(contract.get_proposed_approval(1u64), None);
+            assert_eq!(contract.liabilities_of(Asset::Native), 0);
+        }
+
+        #[ink::test]
+        fn challenge_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                challenge_period: 10,
+                ..Config::default()
+            });
+            set_next_caller(accounts.charlie);
+            let _ = contract.propose_approval(1u64, accounts.bob);
+
+            assert_eq!(
+                contract.challenge(1u64),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.challenge(1u64), Ok(()));
+            assert_eq!(
+                contract.finalize_approval(1u64),
+                Err(DemoError::UnknownProposedApproval)
+            );
+
+            // Approvals cannot be challenged once the challenge period is over
+            set_next_caller(accounts.charlie);
+            let _ = contract.propose_approval(2u64, accounts.bob);
+            advance_blocks(11);
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.challenge(2u64),
+                Err(DemoError::ChallengePeriodOver)
+            );
+        }
+
+        #[ink::test]
+        fn dispute_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                challenge_period: 10,
+                ..Config::default()
+            });
+            set_next_caller(accounts.charlie);
+            let _ = contract.propose_approval(1u64, accounts.bob);
+            assert_eq!(contract.dispute(1u64), Err(DemoError::NoArbiters));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.add_arbiter(accounts.django),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.add_arbiter(accounts.django), Ok(()));
+            assert_eq!(contract.get_arbiters(), vec![accounts.django]);
+
+            set_next_caller(accounts.eve);
+            assert_eq!(contract.dispute(1u64), Ok(accounts.django));
+            assert_eq!(contract.dispute(1u64), Err(DemoError::AlreadyDisputed));
+            assert_eq!(
+                contract.get_dispute(1u64).map(|dispute| dispute.arbiter),
+                Some(accounts.django)
+            );
+
+            // Only the arbiter resolves a disputed approval
+            advance_blocks(11);
+            assert_eq!(
+                contract.finalize_approval(1u64),
+                Err(DemoError::ApprovalDisputed)
+            );
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.resolve_dispute(1u64, false),
+                Err(DemoError::CallerIsNotArbiter)
+            );
+            set_next_caller(accounts.django);
+            assert_eq!(contract.resolve_dispute(1u64, true), Ok(()));
+            assert_eq!(contract.get_contributor(1u64), Some(accounts.bob));
+            assert_eq!(contract.get_dispute(1u64), None);
+
+            // The owner takes over once the arbiter timed out
+            set_next_caller(accounts.charlie);
+            let _ = contract.propose_approval(2u64, accounts.bob);
+            let _ = contract.dispute(2u64);
+            advance_blocks(ARBITRATION_PERIOD + 1);
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.resolve_dispute(2u64, false), Ok(()));
+            assert_eq!(contract.get_proposed_approval(2u64), None);
+            assert_eq!(contract.get_contributor(2u64), None);
+        }
+
+        #[ink::test]
+        fn proposed_approval_cannot_be_approved_twice() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                challenge_period: 10,
+                ..Config::default()
+            });
+            set_next_caller(accounts.charlie);
+            let _ = contract.propose_approval(1u64, accounts.bob);
+
+            // The proposed approval can only be finalized, challenged or disputed
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.submit(1u64, [0x01; 32]),
+                Err(DemoError::InvalidTransition)
+            );
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.approve(1u64, accounts.bob),
+                Err(DemoError::InvalidTransition)
+            );
+
+            // A submitted contribution is approved, not proposed
+            set_next_caller(accounts.bob);
+            let _ = contract.submit(2u64, [0x02; 32]);
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.propose_approval(2u64, accounts.bob),
+                Err(DemoError::InvalidTransition)
+            );
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve(2u64, accounts.bob), Ok(()));
+            assert_eq!(contract.get_submission(2u64), None);
+        }
+
+        #[ink::test]
+        fn redeem_voucher_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.create_campaign(0, 10, 1_000);
+            let _ = contract.transfer_ownership(Some(AccountId::from(VOUCHER_SIGNER)));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.redeem_voucher(voucher(), VOUCHER_SIGNATURE),
+                Ok(())
+            );
+            assert!(contract.is_redeemed(0));
+            assert_eq!(
+                contract
+                    .contributions
+                    .get(1u64)
+                    .map(|c| (c.contributor, c.reward)),
+                Some((accounts.bob, 100))
+            );
+
+            // A voucher cannot be replayed
+            assert_eq!(
+                contract.redeem_voucher(voucher(), VOUCHER_SIGNATURE),
+                Err(DemoError::VoucherAlreadyRedeemed)
+            );
+        }
+
+        #[ink::test]
+        fn redeem_invalid_voucher_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            // The voucher is not signed by the owner
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.redeem_voucher(voucher(), VOUCHER_SIGNATURE),
+                Err(DemoError::InvalidSignature)
+            );
+
+            set_next_caller(accounts.alice);
+            let _ = contract.transfer_ownership(Some(AccountId::from(VOUCHER_SIGNER)));
+
+            // The voucher is only redeemable by its contributor, as signed
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.redeem_voucher(voucher(), VOUCHER_SIGNATURE),
+                Err(DemoError::CallerIsNotContributor)
+            );
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.redeem_voucher(
+                    Voucher {
+                        amount: 1_000,
+                        ..voucher()
+                    },
+                    VOUCHER_SIGNATURE
+                ),
+                Err(DemoError::InvalidSignature)
+            );
+            assert!(!contract.is_redeemed(0));
+        }
+
+        #[ink::test]
+        fn check_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let contribution_id = 1u64;
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(contribution_id, accounts.bob);
+
+            set_next_caller(accounts.bob);
+            assert!(contract.check(contribution_id));
+            assert!(!contract.check(2u64));
+
+            set_next_caller(accounts.charlie);
+            assert!(!contract.check(contribution_id));
+        }
+
+        #[ink::test]
+        fn claim_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let contribution_id = 1u64;
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(contribution_id, accounts.bob);
+
+            set_next_caller(accounts.bob);
+            let receipt = contract.claim(contribution_id).expect("claim failed");
+
+            // Validate `ContributionClaim` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            assert_eq!(3, emitted_events.len());
+            let decoded_events = decode_events(emitted_events);
+            let Event::ContributionClaim(ContributionClaim {
+                id,
+                contributor,
+                receipt: emitted_receipt,
+                ..
+            }) = decoded_events[2]
+            else {
+                panic!("encountered unexpected event kind: expected a ContributionClaim event")
+            };
+            assert_eq!(id, contribution_id);
+            assert_eq!(contributor, accounts.bob);
+            assert_eq!(emitted_receipt, receipt);
+
+            // Claim it again returns an error
+            assert_eq!(
+                contract.claim(contribution_id),
+                Err(DemoError::AlreadyClaimed)
+            );
+        }
+
+        #[ink::test]
+        fn claim_emits_transfer_event() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                reward: 100,
+                emit_transfer_events: true,
+                ..Config::default()
+            });
+            let _ = contract.create_campaign(0, 10, 1_000);
+            let _ = contract.approve(1u64, accounts.bob);
+
+            set_next_caller(accounts.bob);
+            assert!(contract.claim(1u64).is_ok());
+
+            // Validate `Transfer` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let transfers = decoded_events
+                .iter()
+                .filter_map(|event| match event {
+                    Event::Transfer(Transfer { from, to, value }) => Some((*from, *to, *value)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                transfers.last(),
+                Some(&(Some(contract_id()), Some(accounts.bob), 100))
+            );
+        }
+
+        #[ink::test]
+        fn estimate_claim_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                reward: 100,
+                relayer_fee_bps: 1_000,
+                min_claim: 50,
+                ..Config::default()
+            });
+            let _ = contract.create_campaign(0, 10, 1_000);
+            let _ = contract.approve(1u64, accounts.bob);
+            let _ = contract
+                .approve_with_splits(2u64, vec![(accounts.bob, 2_500), (accounts.charlie, 7_500)]);
+            assert_eq!(
+                contract.estimate_claim(1u64),
+                Err(DemoError::CallerIsNotContributor)
+            );
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.estimate_claim(3u64),
+                Err(DemoError::UnknownContribution)
+            );
+            let estimate = ClaimEstimate {
+                asset: Asset::Native,
+                remaining: 100,
+                payout: 100,
+                relayer_fee: 10,
+                available: 100,
+                is_covered: true,
+            };
+            assert_eq!(contract.estimate_claim(1u64), Ok(estimate.clone()));
+            set_balance(contract_id(), 50);
+            assert_eq!(
+                contract.estimate_claim(1u64),
+                Ok(ClaimEstimate {
+                    is_covered: false,
+                    ..estimate
+                })
+            );
+
+            // Payouts below the minimum claim are kept as dust
+            assert_eq!(
+                contract.estimate_claim(2u64),
+                Ok(ClaimEstimate {
+                    asset: Asset::Native,
+                    remaining: 25,
+                    payout: 25,
+                    relayer_fee: 2,
+                    available: 0,
+                    is_covered: true,
+                })
+            );
+
+            set_balance(contract_id(), 1_000);
+            assert!(contract.claim(1u64).is_ok());
+            assert_eq!(
+                contract.estimate_claim(1u64),
+                Err(DemoError::AlreadyClaimed)
+            );
+        }
+
+        #[ink::test]
+        fn earnings_are_totalled_per_era() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(100);
+            let _ = contract.create_campaign(0, 2 * ERA_LENGTH, 1_000);
+            let _ = contract.approve(1u64, accounts.bob);
+            let _ = contract.approve(2u64, accounts.bob);
+            set_next_caller(accounts.bob);
+            let _ = contract.claim(1u64);
+            let _ = contract.claim(2u64);
+            assert_eq!(contract.eras_of(accounts.bob), vec![0]);
+            assert_eq!(contract.earnings_of(accounts.bob, 0), 200);
+
+            advance_blocks(ERA_LENGTH);
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(3u64, accounts.bob);
+            set_next_caller(accounts.bob);
+            let _ = contract.claim(3u64);
+            assert_eq!(contract.current_era(), 1);
+            assert_eq!(contract.eras_of(accounts.bob), vec![0, 1]);
+            assert_eq!(contract.earnings_of(accounts.bob, 1), 100);
+            assert_eq!(contract.earnings_of(accounts.charlie, 1), 0);
+        }
+
+        #[ink::test]
+        fn sponsor_and_approve_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.bob, 0);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(100);
+            let _ = contract.create_campaign(0, 10, 1_000);
+
+            set_next_caller(accounts.django);
+            assert_eq!(
+                contract.sponsor_and_approve(1u64, accounts.bob),
+                Err(DemoError::CallerIsNotSponsor)
+            );
+            assert_eq!(
+                contract.set_allowed_sponsor(accounts.django, true),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_allowed_sponsor(accounts.django, true), Ok(()));
+            assert!(contract.is_allowed_sponsor(accounts.django));
+
+            set_next_caller(accounts.django);
+            assert_eq!(
+                contract.sponsor_and_approve(1u64, accounts.bob),
+                Err(DemoError::ZeroReward)
+            );
+            // The off-chain environment does not move the transferred value
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(300);
+            assert_eq!(contract.sponsor_and_approve(1u64, accounts.bob), Ok(()));
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+
+            // The reward is the sponsored value, not drawn from the campaign
+            let details = contract.contribution_view(1u64).unwrap();
+            assert_eq!((details.reward, details.asset), (300, Asset::Native));
+            assert_eq!(contract.remaining_budget(), 1_000);
+            assert_eq!(contract.liabilities_of(Asset::Native), 300);
+
+            set_next_caller(accounts.bob);
+            assert!(contract.claim(1u64).is_ok());
+            assert_eq!(get_balance(accounts.bob), 300);
+        }
+
+        #[ink::test]
+        fn sponsors_of_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1u64, accounts.bob);
+
+            // The off-chain environment does not move the transferred value
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+            set_next_caller(accounts.django);
+            let _ = contract.tip(1u64);
+            set_next_caller(accounts.eve);
+            let _ = contract.tip(1u64);
+            set_next_caller(accounts.django);
+            let _ = contract.tip(1u64);
+            assert_eq!(
+                contract.sponsors_of(1u64),
+                vec![(accounts.django, 200), (accounts.eve, 100)]
+            );
+            assert!(contract.sponsors_of(2u64).is_empty());
+
+            // Validate `SponsoredBy` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Some(Event::SponsoredBy(SponsoredBy {
+                id, sponsor, total, ..
+            })) = decoded_events.iter().rev().nth(1)
+            else {
+                panic!("encountered unexpected event kind: expected a SponsoredBy event")
+            };
+            assert_eq!((*id, *sponsor, *total), (1u64, accounts.django, 200));
+
+            // Sponsors are capped
+            for index in 0..MAX_SPONSORS - 2 {
+                set_next_caller(AccountId::from([0x10 + index as u8; 32]));
+                assert_eq!(contract.tip(1u64), Ok(()));
+            }
+            set_next_caller(AccountId::from([0xff; 32]));
+            assert_eq!(contract.tip(1u64), Err(DemoError::TooManySponsors));
+            set_next_caller(accounts.eve);
+            assert_eq!(contract.tip(1u64), Ok(()));
+        }
+
+        #[ink::test]
+        fn tip_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.bob, 0);
+            set_balance(accounts.charlie, 0);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(0);
+            let _ = contract
+                .approve_with_splits(1u64, vec![(accounts.bob, 5_000), (accounts.charlie, 5_000)]);
+
+            set_next_caller(accounts.django);
+            assert_eq!(contract.tip(1u64), Err(DemoError::ZeroTip));
+            // The off-chain environment does not move the transferred value
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+            assert_eq!(contract.tip(2u64), Err(DemoError::UnknownContribution));
+            assert_eq!(contract.tip(1u64), Ok(()));
+            assert_eq!(contract.tips_of(1u64), 100);
+            assert_eq!(contract.liabilities_of(Asset::Native), 100);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+
+            // Validate `ContributionTipped` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Some(Event::ContributionTipped(ContributionTipped {
+                id, tipper, amount, ..
+            })) = decoded_events.last()
+            else {
+                panic!("encountered unexpected event kind: expected a ContributionTipped event")
+            };
+            assert_eq!((*id, *tipper, *amount), (1u64, accounts.django, 100));
+
+            // Tips are claimed independently of the reward
+            set_next_caller(accounts.eve);
+            assert_eq!(
+                contract.claim_tips(1u64),
+                Err(DemoError::CallerIsNotContributor)
+            );
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim_tips(1u64), Ok(50));
+            assert_eq!(get_balance(accounts.bob), 50);
+            assert_eq!(contract.claim_tips(1u64), Err(DemoError::NoTips));
+
+            // Or along with the reward
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.pending_tips_of(1u64, accounts.charlie), 50);
+            assert!(contract.claim(1u64).is_ok());
+            assert_eq!(get_balance(accounts.charlie), 50);
+            assert_eq!(contract.pending_tips_of(1u64, accounts.charlie), 0);
+            assert_eq!(contract.liabilities_of(Asset::Native), 0);
+        }
+
+        #[ink::test]
+        fn claim_pays_reward() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let contribution_id = 1u64;
+            let reward = 100;
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.bob, 0);
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_reward(reward), Ok(()));
+            let _ = contract.create_campaign(0, 10, 1_000);
+            let _ = contract.approve(contribution_id, accounts.bob);
+
+            // The reward of an approved contribution is kept when the reward changes
+            let _ = contract.set_reward(reward * 2);
+            assert_eq!(contract.get_reward(), reward * 2);
+
+            set_next_caller(accounts.bob);
+            assert!(contract.claim(contribution_id).is_ok());
+            assert_eq!(get_balance(accounts.bob), reward);
+            assert_eq!(get_balance(contract_id()), 1_000 - reward);
+        }
+
+        #[ink::test]
+        fn claim_below_min_claim_accrues_dust() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.bob, 0);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                reward: 100,
+                min_claim: 150,
+                ..Config::default()
+            });
+            let _ = contract.create_campaign(0, 10, 1_000);
+            let _ = contract.approve(1u64, accounts.bob);
+            let _ = contract.approve(2u64, accounts.bob);
+
+            // The payout below the minimum claim is kept as dust, still owed to bob
+            set_next_caller(accounts.bob);
+            assert!(contract.claim(1u64).is_ok());
+            assert_eq!(get_balance(accounts.bob), 0);
+            assert_eq!(contract.dust_of(accounts.bob), 100);
+            assert_eq!(contract.liabilities_of(Asset::Native), 200);
+            assert_eq!(contract.claim_dust(), Err(DemoError::DustBelowMinimum));
+
+            // The dust is paid with the claim crossing the minimum claim
+            assert!(contract.claim(2u64).is_ok());
+            assert_eq!(get_balance(accounts.bob), 200);
+            assert_eq!(contract.dust_of(accounts.bob), 0);
+            assert_eq!(contract.liabilities_of(Asset::Native), 0);
+        }
+
+        #[ink::test]
+        fn claim_dust_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.bob, 0);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                reward: 100,
+                min_claim: 150,
+                ..Config::default()
+            });
+            let _ = contract.create_campaign(0, 10, 1_000);
+            let _ = contract.approve(1u64, accounts.bob);
+            set_next_caller(accounts.bob);
+            assert!(contract.claim(1u64).is_ok());
+
+            // The dust is claimable once the minimum claim is lowered
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_min_claim(50), Ok(()));
+            assert_eq!(contract.get_min_claim(), 50);
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim_dust(), Ok(()));
+            assert_eq!(get_balance(accounts.bob), 100);
+            assert_eq!(contract.dust_of(accounts.bob), 0);
+            assert_eq!(contract.claim_dust(), Err(DemoError::DustBelowMinimum));
+        }
+
+        #[ink::test]
+        fn set_profile_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let profile = Profile {
+                display_name: BoundedString::try_from("Bob").unwrap(),
+                avatar_uri: Some(BoundedString::try_from("ipfs://avatar").unwrap()),
+                website: None,
+            };
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_profile(profile.clone()),
+                Err(DemoError::IdentityNotRegistered)
+            );
+            let _ = contract.register_identity((Platform::GitHub, [0x01; 32]));
+            assert_eq!(
+                contract.set_profile(Profile {
+                    display_name: BoundedString::default(),
+                    ..profile.clone()
+                }),
+                Err(DemoError::InvalidProfile)
+            );
+            assert_eq!(
+                contract.set_profile(Profile {
+                    website: Some(BoundedString::default()),
+                    ..profile.clone()
+                }),
+                Err(DemoError::InvalidProfile)
+            );
+            assert_eq!(contract.set_profile(profile.clone()), Ok(()));
+            assert_eq!(contract.profile_of(accounts.bob), Some(profile));
+            assert_eq!(contract.profile_of(accounts.charlie), None);
+        }
+
+        #[ink::test]
+        fn approve_with_encrypted_memo_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let pubkey = [0x09; 32];
+            let encrypted_memo = vec![0x2a; 64];
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_encryption_pubkey(pubkey),
+                Err(DemoError::IdentityNotRegistered)
+            );
+            let _ = contract.register_identity((Platform::GitHub, [0x01; 32]));
+            assert_eq!(contract.set_encryption_pubkey(pubkey), Ok(()));
+            assert_eq!(contract.encryption_pubkey_of(accounts.bob), Some(pubkey));
+            assert_eq!(
+                contract.approve_with_encrypted_memo(1u64, accounts.bob, encrypted_memo.clone()),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.approve_with_encrypted_memo(
+                    1u64,
+                    accounts.charlie,
+                    encrypted_memo.clone()
+                ),
+                Err(DemoError::EncryptionKeyUnset)
+            );
+            for invalid_memo in [Vec::new(), vec![0x2a; MAX_ENCRYPTED_MEMO_LENGTH + 1]] {
+                assert_eq!(
+                    contract.approve_with_encrypted_memo(1u64, accounts.bob, invalid_memo),
+                    Err(DemoError::InvalidMemo)
+                );
+            }
+            assert_eq!(
+                contract.approve_with_encrypted_memo(1u64, accounts.bob, encrypted_memo.clone()),
+                Ok(())
+            );
+            assert_eq!(contract.encrypted_memo_of(1u64), Some(encrypted_memo));
+            assert_eq!(contract.encrypted_memo_of(2u64), None);
+        }
+
+        #[ink::test]
+        fn reveal_identity_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let salt = [0x07; 32];
+            let hash = contract.salted_identity_hash(String::from("bob"), salt);
+            let contributor_id = (Platform::GitHub, hash);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.reveal_identity(String::from("bob"), salt),
+                Err(DemoError::IdentityNotRegistered)
+            );
+            let _ = contract.register_identity(contributor_id);
+            assert_eq!(contract.revealed_handle(contributor_id), None);
+            assert_eq!(
+                contract.reveal_identity(String::from("bob"), [0x08; 32]),
+                Err(DemoError::InvalidPreimage)
+            );
+            assert_eq!(
+                contract.reveal_identity(String::from("alice"), salt),
+                Err(DemoError::InvalidPreimage)
+            );
+            assert_eq!(contract.reveal_identity(String::from("bob"), salt), Ok(()));
+            assert_eq!(
+                contract.revealed_handle(contributor_id),
+                Some(String::from("bob"))
+            );
+        }
+
+        #[ink::test]
+        fn set_payout_address_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.bob, 0);
+            set_balance(accounts.eve, 0);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(100);
+            let _ = contract.create_campaign(0, 10, 1_000);
+            let _ = contract.approve(1u64, accounts.bob);
+            let _ = contract.approve(2u64, accounts.bob);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_payout_address(accounts.eve),
+                Err(DemoError::IdentityNotRegistered)
+            );
+            let _ = contract.register_identity((Platform::GitHub, [0x01; 32]));
+            assert_eq!(
+                contract.set_payout_address(contract_id()),
+                Err(DemoError::ContractAccount)
+            );
+            assert_eq!(contract.set_payout_address(accounts.eve), Ok(()));
+
+            // The change takes effect from the next block
+            assert_eq!(contract.payout_address_of(accounts.bob), accounts.bob);
+            assert!(contract.claim(1u64).is_ok());
+            assert_eq!(get_balance(accounts.bob), 100);
+
+            advance_block();
+            assert_eq!(contract.payout_address_of(accounts.bob), accounts.eve);
+            assert!(contract.claim(2u64).is_ok());
+            assert_eq!(get_balance(accounts.bob), 100);
+            assert_eq!(get_balance(accounts.eve), 100);
+        }
+
+        #[ink::test]
+        fn check_for_ss58_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            // The development ALICE account
+            let alice = String::from("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY");
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(
+                1u64,
+                AccountId::from(decode_ss58(&alice).expect("invalid address")),
+            );
+            assert_eq!(contract.check_for_ss58(1u64, alice.clone()), Ok(true));
+            assert_eq!(contract.check_for_ss58(2u64, alice), Ok(false));
+            assert_eq!(
+                contract.check_for_ss58(1u64, String::from("not an address")),
+                Err(DemoError::InvalidAddress)
+            );
+        }
+
+        #[ink::test]
+        fn approve_with_preimage_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let repo = String::from("kudos-ink/demo");
+            let contribution_id =
+                contract.hash_contribution(repo.clone(), ContributionKind::PullRequest, 1);
+            assert_ne!(
+                contribution_id,
+                contract.hash_contribution(repo.clone(), ContributionKind::Issue, 1)
+            );
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.approve_with_preimage(
+                    repo.clone(),
+                    ContributionKind::PullRequest,
+                    1,
+                    accounts.bob
+                ),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.approve_with_preimage(
+                    repo.clone(),
+                    ContributionKind::PullRequest,
+                    1,
+                    accounts.bob
+                ),
+                Ok(contribution_id)
+            );
+            assert_eq!(
+                contract.approve_with_preimage(
+                    repo.clone(),
+                    ContributionKind::PullRequest,
+                    1,
+                    accounts.bob
+                ),
+                Err(DemoError::ContributionAlreadyApproved {
+                    contributor: accounts.bob
+                })
+            );
+
+            // Another preimage under the same id is rejected
+            contract.preimages.insert(contribution_id, &[0u8; 32]);
+            assert_eq!(
+                contract.approve_with_preimage(
+                    repo,
+                    ContributionKind::PullRequest,
+                    1,
+                    accounts.bob
+                ),
+                Err(DemoError::ContributionIdCollision)
+            );
+        }
+
+        #[ink::test]
+        fn claim_partial_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let contribution_id = 1u64;
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.bob, 0);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(100);
+            let _ = contract.create_campaign(0, 10, 1_000);
+            let _ = contract.approve(contribution_id, accounts.bob);
+
+            set_next_caller(accounts.bob);
+            assert!(contract.claim_partial(contribution_id, 30).is_ok());
+            assert_eq!(contract.claimed_amount(contribution_id, accounts.bob), 30);
+            assert_eq!(get_balance(accounts.bob), 30);
+            assert_eq!(
+                contract.claim_partial(contribution_id, 80),
+                Err(DemoError::AmountExceedsShare { remaining: 70 })
+            );
+
+            // Claiming the rest settles the share
+            assert!(contract.claim(contribution_id).is_ok());
+            assert_eq!(contract.claimed_amount(contribution_id, accounts.bob), 100);
+            assert_eq!(get_balance(accounts.bob), 100);
+            assert_eq!(
+                contract.claim_partial(contribution_id, 1),
+                Err(DemoError::AlreadyClaimed)
+            );
+        }
+
+        #[ink::test]
+        fn claim_deadline_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.bob, 0);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(100);
+            let campaign_id = contract.create_campaign(0, 10, 1_000).unwrap();
+            assert_eq!(
+                contract.approve_with_deadline(1u64, accounts.bob, 2),
+                Ok(())
+            );
+            assert_eq!(
+                contract.approve_with_deadline(2u64, accounts.bob, 2),
+                Ok(())
+            );
+            assert_eq!(contract.remaining_budget(), 800);
+
+            // The reward can be claimed until the deadline (included)
+            advance_blocks(2);
+            assert_eq!(
+                contract.expire(1u64),
+                Err(DemoError::ClaimDeadlineNotPassed)
+            );
+            set_next_caller(accounts.bob);
+            assert!(contract.claim(1u64).is_ok());
+            assert_eq!(get_balance(accounts.bob), 100);
+
+            // Past it, the unclaimed reward returns to the campaign budget
+            advance_block();
+            assert_eq!(contract.claim(2u64), Err(DemoError::ClaimDeadlinePassed));
+            assert_eq!(contract.expire(1u64), Err(DemoError::AlreadyClaimed));
+            assert_eq!(contract.expire(2u64), Ok(100));
+            assert_eq!(contract.expire(2u64), Err(DemoError::AlreadyClaimed));
+            assert_eq!(
+                contract.get_campaign(campaign_id).map(|c| c.remaining),
+                Some(900)
+            );
+        }
+
+        #[ink::test]
+        fn sweep_expired_page_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.charlie, 0);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                reward: 100,
+                sweep_bounty_bps: 1_000,
+                ..Config::default()
+            });
+            let campaign_id = contract.create_campaign(0, 10, 1_000).unwrap();
+            let _ = contract.approve_with_deadline(1u64, accounts.bob, 2);
+            let _ = contract.approve_with_deadline(2u64, accounts.bob, 2);
+            let _ = contract.approve_with_deadline(3u64, accounts.bob, 100);
+            assert_eq!(contract.remaining_budget(), 700);
+
+            // The keeper earns 10% of the rewards reclaimed
+            advance_blocks(3);
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.sweep_expired_page(0, 10), Ok(20));
+            assert_eq!(get_balance(accounts.charlie), 20);
+            assert_eq!(contract.total_unclaimed(), 1);
+            assert_eq!(
+                contract.get_campaign(campaign_id).map(|c| c.remaining),
+                Some(880)
+            );
+            assert_eq!(contract.sweep_expired_page(0, 10), Ok(0));
+
+            // Once the campaign is finalized, the bounty comes out of the reclaimed reward
+            advance_blocks(100);
+            set_next_caller(accounts.alice);
+            let _ = contract.finalize_campaign(campaign_id);
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.sweep_expired_page(0, 10), Ok(10));
+            assert_eq!(get_balance(accounts.charlie), 30);
+            assert_eq!(contract.liabilities_of(Asset::Native), 0);
+            assert_eq!(
+                contract.get_campaign(campaign_id).map(|c| c.remaining),
+                Some(880)
+            );
+        }
+
+        #[ink::test]
+        fn approve_with_passed_deadline_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            advance_blocks(2);
+            assert_eq!(
+                contract.approve_with_deadline(1u64, accounts.bob, 1),
+                Err(DemoError::InvalidDeadline)
+            );
+            assert_eq!(contract.expire(1u64), Err(DemoError::UnknownContribution));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.approve_with_deadline(1u64, accounts.bob, 3),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn approve_range_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.approve_range(1u64, 5u64, accounts.bob),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(3u64, accounts.charlie);
+            assert_eq!(contract.approve_range(1u64, 5u64, accounts.bob), Ok(4));
+            assert_eq!(contract.get_approvals(accounts.bob), 4);
+            assert_eq!(
+                contract.contributions.get(3u64).map(|c| c.contributor),
+                Some(accounts.charlie)
+            );
+            assert_eq!(contract.approve_range(1u64, 5u64, accounts.bob), Ok(0));
+
+            assert_eq!(
+                contract.approve_range(5u64, 1u64, accounts.bob),
+                Err(DemoError::InvalidRange)
+            );
+            assert_eq!(
+                contract.approve_range(1u64, MAX_PAGE_SIZE.into(), accounts.bob),
+                Ok(MAX_PAGE_SIZE - 5)
+            );
+            assert_eq!(
+                contract.approve_range(1u64, u64::from(MAX_PAGE_SIZE) + 1, accounts.bob),
+                Err(DemoError::TooManyContributions)
+            );
+        }
+
+        #[ink::test]
+        fn approve_batch_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let approvals = vec![
+                (1u64, accounts.bob),
+                (2u64, accounts.bob),
+                (1u64, accounts.charlie),
+                (3u64, accounts.charlie),
+            ];
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.approve_batch(approvals.clone(), 0),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            // A failed approval does not revert the batch
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.approve_batch(approvals, 0),
+                Ok(vec![
+                    Ok(()),
+                    Ok(()),
+                    Err(DemoError::ContributionAlreadyApproved {
+                        contributor: accounts.bob
+                    }),
+                    Ok(()),
+                ])
+            );
+            assert_eq!(contract.get_approvals(accounts.bob), 2);
+            assert_eq!(contract.get_approvals(accounts.charlie), 1);
+
+            assert_eq!(
+                contract.approve_batch(vec![(1u64, accounts.bob); MAX_PAGE_SIZE as usize + 1], 0),
+                Err(DemoError::TooManyContributions)
+            );
+        }
+
+        #[ink::test]
+        fn approve_with_tags_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let tag = |tag: &str| String::from(tag);
+
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.approve_with_tags(1u64, accounts.bob, vec![tag("docs"), tag("ink")]),
+                Ok(())
+            );
+            assert_eq!(
+                contract.approve_with_tags(2u64, accounts.bob, vec![tag("ink")]),
+                Ok(())
+            );
+            let _ = contract.approve(3u64, accounts.bob);
+            assert_eq!(contract.get_tags(1u64), vec![tag("docs"), tag("ink")]);
+            assert_eq!(contract.get_tags(3u64), Vec::<String>::new());
+
+            assert_eq!(
+                contract.contributions_by_tag(tag("ink"), 0, 10),
+                vec![1u64, 2u64]
+            );
+            assert_eq!(contract.contributions_by_tag(tag("ink"), 1, 10), vec![2u64]);
+            assert_eq!(contract.contributions_by_tag(tag("ink"), 0, 1), vec![1u64]);
+            assert_eq!(
+                contract.contributions_by_tag(tag("docs"), 0, 10),
+                vec![1u64]
+            );
+            assert!(contract.contributions_by_tag(tag("ui"), 0, 10).is_empty());
+        }
+
+        #[ink::test]
+        fn link_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1u64, accounts.bob);
+            let _ = contract.approve(2u64, accounts.bob);
+            let _ = contract.approve(3u64, accounts.bob);
+            assert_eq!(
+                contract.link(1u64, 1u64, Relation::DuplicateOf),
+                Err(DemoError::InvalidRelation)
+            );
+            assert_eq!(
+                contract.link(1u64, 4u64, Relation::DuplicateOf),
+                Err(DemoError::UnknownContribution)
+            );
+            assert_eq!(contract.link(1u64, 2u64, Relation::DuplicateOf), Ok(()));
+            assert_eq!(
+                contract.relation_of(1u64),
+                Some((Relation::DuplicateOf, 2u64))
+            );
+            assert_eq!(contract.related_to(2u64), vec![1u64]);
+
+            // Linking again replaces the previous link
+            assert_eq!(contract.link(1u64, 3u64, Relation::SupersededBy), Ok(()));
+            assert_eq!(
+                contract.relation_of(1u64),
+                Some((Relation::SupersededBy, 3u64))
+            );
+            assert!(contract.related_to(2u64).is_empty());
+            assert_eq!(contract.related_to(3u64), vec![1u64]);
+
+            assert_eq!(contract.unlink(1u64), Ok(()));
+            assert_eq!(contract.relation_of(1u64), None);
+            assert!(contract.related_to(3u64).is_empty());
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.link(1u64, 2u64, Relation::DuplicateOf),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn approve_with_memo_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let memo = String::from("Fix the claim of co-authors");
+            let mut memo_hash = [0u8; 32];
+            ink::env::hash_bytes::<Blake2x256>(memo.as_bytes(), &mut memo_hash);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.approve_with_memo(1u64, accounts.bob, memo_hash),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.approve_with_memo(1u64, accounts.bob, memo_hash),
+                Ok(())
+            );
+            assert!(contract.verify_memo(1u64, memo.clone()));
+            assert!(!contract.verify_memo(1u64, String::from("Another title")));
+            assert!(!contract.verify_memo(2u64, memo));
+        }
+
+        #[ink::test]
+        fn approve_with_merge_commit_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let sha = vec![0x2a; 20];
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.approve_with_merge_commit(1u64, accounts.bob, sha.clone()),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            for invalid_sha in [Vec::new(), vec![0x2a; 21], vec![0x2a; 33]] {
+                assert_eq!(
+                    contract.approve_with_merge_commit(1u64, accounts.bob, invalid_sha),
+                    Err(DemoError::InvalidCommitSha)
+                );
+            }
+            assert_eq!(
+                contract.approve_with_merge_commit(1u64, accounts.bob, sha.clone()),
+                Ok(())
+            );
+            assert_eq!(contract.merge_commit_of(1u64), Some(sha.clone()));
+            assert!(contract.verify_merge_commit(1u64, sha.clone()));
+            assert!(!contract.verify_merge_commit(1u64, vec![0x2b; 20]));
+            assert!(!contract.verify_merge_commit(2u64, sha));
+        }
+
+        #[ink::test]
+        fn submit_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let evidence = [0x01; 32];
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.submit(1u64, evidence), Ok(()));
+            assert_eq!(
+                contract.get_submission(1u64),
+                Some(Submission {
+                    contributor: accounts.bob,
+                    evidence,
+                    submitted_at: 0,
+                })
+            );
+            assert_eq!(
+                contract.check_detailed(1u64).status,
+                ContributionStatus::Pending
+            );
+            assert!(contract.check_detailed(1u64).is_contributor);
+
+            // Validate `ContributionSubmitted` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Some(Event::ContributionSubmitted(ContributionSubmitted {
+                id, contributor, ..
+            })) = decoded_events.last()
+            else {
+                panic!("encountered unexpected event kind: expected a ContributionSubmitted event")
+            };
+            assert_eq!((*id, *contributor), (1u64, accounts.bob));
+
+            // A contribution is submitted once
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.submit(1u64, [0x02; 32]),
+                Err(DemoError::AlreadySubmitted)
+            );
+
+            // Only the approver approves submissions
+            assert_eq!(
+                contract.approve_submission(1u64),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.approve_submission(2u64),
+                Err(DemoError::UnknownSubmission)
+            );
+            assert_eq!(contract.approve_submission(1u64), Ok(()));
+            assert_eq!(contract.get_submission(1u64), None);
+            assert_eq!(contract.evidence_of(1u64), Some(evidence));
+            assert_eq!(contract.get_contributor(1u64), Some(accounts.bob));
+
+            // An approved contribution cannot be submitted
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.submit(1u64, evidence),
+                Err(DemoError::ContributionAlreadyApproved {
+                    contributor: accounts.bob
+                })
+            );
+        }
+
+        #[ink::test]
+        fn endorse_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                reward: 100,
+                endorsement_bonus: 10,
+                max_endorsements: 2,
+                ..Config::default()
+            });
+            let _ = contract.create_campaign(0, 10, 1_000);
+            let _ = contract.approve(1u64, accounts.bob);
+
+            // Only registered peers endorse
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.endorse(1u64),
+                Err(DemoError::IdentityNotRegistered)
+            );
+            let _ = contract.register_identity((Platform::GitHub, [0x01; 32]));
+            assert_eq!(contract.endorse(2u64), Err(DemoError::UnknownContribution));
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity((Platform::GitHub, [0x02; 32]));
+            assert_eq!(contract.endorse(1u64), Err(DemoError::CallerIsContributor));
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.endorse(1u64), Ok(()));
+            assert!(contract.has_endorsed(1u64, accounts.charlie));
+            assert_eq!(contract.endorsements_of(1u64), 1);
+            assert_eq!(contract.remaining_budget(), 1_000 - 100 - 10);
+            assert_eq!(contract.liabilities_of(Asset::Native), 110);
+            assert_eq!(contract.endorse(1u64), Err(DemoError::AlreadyEndorsed));
+
+            // Endorsements are capped
+            set_next_caller(accounts.django);
+            let _ = contract.register_identity((Platform::GitHub, [0x03; 32]));
+            assert_eq!(contract.endorse(1u64), Ok(()));
+            set_next_caller(accounts.eve);
+            let _ = contract.register_identity((Platform::GitHub, [0x04; 32]));
+            assert_eq!(
+                contract.endorse(1u64),
+                Err(DemoError::EndorsementCapReached)
+            );
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.check_detailed(1u64).reward, 100 + 2 * 10);
+        }
+
+        #[ink::test]
+        fn review_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.review(1u64, accounts.bob),
+                Err(DemoError::ReviewsDisabled)
+            );
+            let _ = contract.set_config(Config {
+                review_quorum: 2,
+                ..Config::default()
+            });
+            let _ = contract.grant_session(accounts.django, 10);
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.review(1u64, accounts.bob),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.review(1u64, accounts.bob), Ok(()));
+            assert_eq!(contract.reviews_of(1u64), Some((accounts.bob, 1)));
+            assert_eq!(contract.get_contributor(1u64), None);
+            assert_eq!(
+                contract.review(1u64, accounts.bob),
+                Err(DemoError::AlreadyReviewed)
+            );
+
+            // Validate `EndorsementRecorded` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Some(Event::EndorsementRecorded(EndorsementRecorded {
+                id,
+                approver,
+                current,
+                required,
+                ..
+            })) = decoded_events.last()
+            else {
+                panic!("encountered unexpected event kind: expected a EndorsementRecorded event")
+            };
+            assert_eq!(
+                (*id, *approver, *current, *required),
+                (1u64, accounts.alice, 1, 2)
+            );
+
+            // The quorum approves the contribution
+            set_next_caller(accounts.django);
+            assert_eq!(contract.review(1u64, accounts.bob), Ok(()));
+            assert_eq!(contract.reviews_of(1u64), None);
+            assert_eq!(contract.get_contributor(1u64), Some(accounts.bob));
+        }
+
+        #[ink::test]
+        fn resolve_conflict_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                review_quorum: 2,
+                ..Config::default()
+            });
+            let _ = contract.grant_session(accounts.django, 10);
+            let _ = contract.grant_session(accounts.eve, 10);
+            let _ = contract.add_arbiter(accounts.frank);
+            let _ = contract.review(1u64, accounts.bob);
+
+            // Reviews for another contributor are a conflict
+            set_next_caller(accounts.django);
+            assert_eq!(contract.review(1u64, accounts.charlie), Ok(()));
+            assert_eq!(contract.reviews_of(1u64), None);
+            assert_eq!(
+                contract.conflict_of(1u64),
+                Some(vec![(accounts.bob, 1), (accounts.charlie, 1)])
+            );
+
+            // Validate `ConflictDetected` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Some(Event::ConflictDetected(ConflictDetected { id, candidates, .. })) =
+                decoded_events.last()
+            else {
+                panic!("encountered unexpected event kind: expected a ConflictDetected event")
+            };
+            assert_eq!(*id, 1u64);
+            assert_eq!(*candidates, vec![(accounts.bob, 1), (accounts.charlie, 1)]);
+
+            // The quorum no longer approves the contribution
+            set_next_caller(accounts.eve);
+            assert_eq!(contract.review(1u64, accounts.bob), Ok(()));
+            assert_eq!(contract.get_contributor(1u64), None);
+            assert_eq!(
+                contract.conflict_of(1u64),
+                Some(vec![(accounts.bob, 2), (accounts.charlie, 1)])
+            );
+
+            assert_eq!(
+                contract.resolve_conflict(1u64, accounts.bob),
+                Err(DemoError::CallerIsNotArbiter)
+            );
+            set_next_caller(accounts.frank);
+            assert_eq!(
+                contract.resolve_conflict(1u64, accounts.django),
+                Err(DemoError::ContributorMismatch)
+            );
+            assert_eq!(contract.resolve_conflict(1u64, accounts.charlie), Ok(()));
+            assert_eq!(contract.get_contributor(1u64), Some(accounts.charlie));
+            assert_eq!(contract.conflict_of(1u64), None);
+            assert_eq!(
+                contract.resolve_conflict(1u64, accounts.charlie),
+                Err(DemoError::UnknownConflict)
+            );
+        }
+
+        #[ink::test]
+        fn reject_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            let _ = contract.submit(1u64, [0x01; 32]);
+            assert_eq!(
+                contract.reject(1u64, 3),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.reject(1u64, 3), Ok(()));
+            assert_eq!(contract.get_submission(1u64), None);
+            assert_eq!(
+                contract.rejection_of(1u64),
+                Some(Rejection {
+                    reason_code: 3,
+                    rejected_by: accounts.alice,
+                    rejected_at: 0,
+                })
+            );
+
+            // Validate `ContributionRejected` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Some(Event::ContributionRejected(ContributionRejected {
+                id, reason_code, ..
+            })) = decoded_events.last()
+            else {
+                panic!("encountered unexpected event kind: expected a ContributionRejected event")
+            };
+            assert_eq!((*id, *reason_code), (1u64, 3));
+
+            // Contributions never submitted can be rejected, not approved ones
+            assert_eq!(contract.reject(2u64, 1), Ok(()));
+            let _ = contract.approve(3u64, accounts.bob);
+            assert_eq!(contract.reject(3u64, 1), Err(DemoError::InvalidTransition));
+
+            // A later approval clears the rejection
+            assert_eq!(contract.approve(1u64, accounts.bob), Ok(()));
+            assert_eq!(contract.rejection_of(1u64), None);
+        }
+
+        #[ink::test]
+        fn reject_submission_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.submit(1u64, [0x01; 32]), Ok(()));
+            assert_eq!(
+                contract.reject_submission(1u64),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.reject_submission(1u64), Ok(()));
+            assert_eq!(contract.get_submission(1u64), None);
+            assert_eq!(
+                contract.reject_submission(1u64),
+                Err(DemoError::UnknownSubmission)
+            );
+
+            // A rejected contribution can be submitted again
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.submit(1u64, [0x02; 32]), Ok(()));
+        }
+
+        #[ink::test]
+        fn approve_with_invalid_tags_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let tag = |tag: &str| String::from(tag);
+
+            set_next_caller(accounts.alice);
+            for tags in [
+                vec![tag("ink"); MAX_TAGS + 1],
+                vec![tag("ink"), tag("ink")],
+                vec![tag("")],
+                vec![tag(&"a".repeat(MAX_TAG_LENGTH + 1))],
+            ] {
+                assert_eq!(
+                    contract.approve_with_tags(1u64, accounts.bob, tags),
+                    Err(DemoError::InvalidTags)
+                );
+            }
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.approve_with_tags(1u64, accounts.bob, vec![tag("ink")]),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn referral_bonus_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.bob, 0);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                reward: 100,
+                referral_bonus: 10,
+                ..Config::default()
+            });
+            let _ = contract.create_campaign(0, 10, 1_000);
+
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity((Platform::GitHub, [0x01; 32]));
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract
+                    .register_identity_with_referrer((Platform::GitHub, [0x02; 32]), accounts.bob),
+                Ok(())
+            );
+            assert_eq!(contract.referrals_of(accounts.bob), vec![accounts.charlie]);
+            assert_eq!(contract.referrer_of(accounts.charlie), Some(accounts.bob));
+
+            // Only the first contribution of the referee grants the bonus
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1u64, accounts.charlie);
+            let _ = contract.approve(2u64, accounts.charlie);
+            assert_eq!(contract.get_referral_rewards(accounts.bob), 10);
+            assert_eq!(contract.remaining_budget(), 1_000 - 2 * 100 - 10);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim_referral_rewards(), Ok(10));
+            assert_eq!(get_balance(accounts.bob), 10);
+            assert_eq!(
+                contract.claim_referral_rewards(),
+                Err(DemoError::NoReferralRewards)
+            );
+        }
+
+        #[ink::test]
+        fn register_identity_with_invalid_referrer_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            // The referrer must be another registered account
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract
+                    .register_identity_with_referrer((Platform::GitHub, [0x02; 32]), accounts.bob),
+                Err(DemoError::InvalidReferrer)
+            );
+            let _ = contract.register_identity((Platform::GitHub, [0x01; 32]));
+            assert_eq!(
+                contract.register_identity_with_referrer(
+                    (Platform::GitLab, [0x01; 32]),
+                    accounts.charlie
+                ),
+                Err(DemoError::InvalidReferrer)
+            );
+        }
+
+        #[ink::test]
+        fn only_contract_owner_can_set_remark_approvals() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_remark_approvals(true),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            if cfg!(feature = "call-runtime") {
+                assert_eq!(contract.set_remark_approvals(true), Ok(()));
+                assert!(contract.is_remark_approvals());
+            } else {
+                assert_eq!(
+                    contract.set_remark_approvals(true),
+                    Err(DemoError::CallRuntimeUnavailable)
+                );
+                assert!(!contract.is_remark_approvals());
+            }
+        }
+
+        #[cfg(feature = "sandbox")]
+        #[ink::test]
+        fn only_contract_owner_can_set_time_offset() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_time_offset(10, 0),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve_with_deadline(1u64, accounts.bob, 5);
+            assert_eq!(
+                contract.expire(1u64),
+                Err(DemoError::ClaimDeadlineNotPassed)
+            );
+            assert_eq!(contract.set_time_offset(10, 60_000), Ok(()));
+            assert_eq!(contract.time_offset(), (10, 60_000));
+            // The deadline is passed on the sandbox clock
+            assert_eq!(contract.expire(1u64), Ok(0));
+        }
+
+        #[ink::test]
+        fn contributions_by_identity_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let contributor_id = (Platform::GitHub, [0x01; 32]);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1u64, accounts.bob);
+
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(contributor_id);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(2u64, accounts.bob);
+            let _ = contract.approve(3u64, accounts.charlie);
+            let _ = contract
+                .approve_with_splits(4u64, vec![(accounts.charlie, 5_000), (accounts.bob, 5_000)]);
+
+            // Contributions approved before the identity is bound are not found
+            assert_eq!(
+                contract.contributions_by_identity(contributor_id, 0, 10),
+                vec![2u64, 4u64]
+            );
+            assert_eq!(
+                contract.contributions_by_identity(contributor_id, 1, 10),
+                vec![4u64]
+            );
+            assert_eq!(
+                contract.contributions_by_identity(contributor_id, 0, 1),
+                vec![2u64]
+            );
+            assert!(contract
+                .contributions_by_identity((Platform::GitHub, [0x02; 32]), 0, 10)
+                .is_empty());
+        }
+
+        #[ink::test]
+        fn import_works() {
+            let accounts = default_accounts();
+            let mut previous = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = previous.set_reward(100);
+            let _ = previous.create_campaign(0, 10, 1_000);
+            let _ = previous.approve(1u64, accounts.bob);
+            let _ = previous.approve(2u64, accounts.charlie);
+            let exported = previous.export_page(0, 10).unwrap();
+
+            // Deploy the new contract under another account
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            let mut contract = create_contract();
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.import(exported.clone()),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.import(exported.clone()), Ok(2));
+            assert_eq!(contract.import(exported.clone()), Ok(0));
+            assert_eq!(contract.get_approvals(accounts.bob), 1);
+            assert_eq!(contract.liabilities_of(Asset::Native), 200);
+            // The contributions are detached from the campaigns of the previous deployment
+            assert_eq!(
+                contract
+                    .export_page(0, 10)
+                    .map(|page| page.iter().map(|(_, c, _)| c.campaign_id).collect()),
+                Ok(vec![None, None])
+            );
+
+            assert_eq!(contract.finalize_import(), Ok(()));
+            assert!(contract.import_finalized());
+            assert_eq!(contract.import(exported), Err(DemoError::ImportFinalized));
+        }
+
+        #[ink::test]
+        fn import_keeps_claimed_amounts() {
+            let accounts = default_accounts();
+            let mut previous = create_contract();
+            set_balance(contract_id(), 1_000);
+
+            set_next_caller(accounts.alice);
+            let _ = previous.set_config(Config {
+                reward: 100,
+                claim_ttl: 20,
+                ..Config::default()
+            });
+            let _ = previous.create_campaign(0, 10, 1_000);
+            let _ = previous.approve(1u64, accounts.bob);
+            set_next_caller(accounts.bob);
+            let _ = previous.claim_partial(1u64, 40);
+            set_next_caller(accounts.alice);
+            let exported = previous.export_page(0, 10).unwrap();
+            assert_eq!(exported[0].2, vec![40]);
+
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.django);
+            let mut contract = create_contract();
+            advance_blocks(5);
+
+            // The splits are checked like on approval
+            let mut invalid = exported.clone();
+            invalid[0].1.splits[0].bps = 5_000;
+            assert_eq!(contract.import(invalid), Err(DemoError::InvalidSplits));
+            let mut overclaimed = exported.clone();
+            overclaimed[0].2 = vec![200];
+            assert_eq!(contract.import(overclaimed), Err(DemoError::InvalidSplits));
+
+            // Only the share left is owed, until the rebased deadline
+            assert_eq!(contract.import(exported), Ok(1));
+            assert_eq!(contract.claimed_amount(1u64, accounts.bob), 40);
+            assert_eq!(contract.liabilities_of(Asset::Native), 60);
+            assert_eq!(
+                contract.contributions.get(1u64).and_then(|c| c.deadline),
+                Some(contract.block_number() + 20)
+            );
+        }
+
+        #[ink::test]
+        fn export_page_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let empty_root = contract.state_root([0x00; 32], 0, 10);
+            for contribution_id in [3u64, 1u64, 2u64] {
+                let _ = contract.approve(contribution_id, accounts.bob);
+            }
+            let page = contract.export_page(1, 10).unwrap();
+            assert_eq!(
+                page.iter().map(|(id, _, _)| *id).collect::<Vec<_>>(),
+                vec![1u64, 2u64]
+            );
+            assert_eq!(Some(page[0].1.clone()), contract.contributions.get(1u64));
+            assert_eq!(contract.export_page(3, 10).map(|page| page.len()), Ok(0));
+
+            // The state root changes with the contributions, and is chained page by page
+            let root = contract.state_root([0x00; 32], 0, 10);
+            assert_ne!(root, empty_root);
+            let first_page_root = contract.state_root([0x00; 32], 0, 2);
+            assert_eq!(contract.state_root(first_page_root, 2, 10), root);
+            set_next_caller(accounts.bob);
+            let _ = contract.claim(3u64);
+            assert_ne!(contract.state_root([0x00; 32], 0, 10), root);
+
+            assert_eq!(
+                contract.export_page(0, 10),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn self_check_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(100);
+            let _ = contract.create_campaign(0, 10, 1_000);
+            let _ = contract.approve(1u64, accounts.bob);
+            assert_eq!(contract.self_check(0, 10), vec![]);
+
+            set_balance(contract_id(), 50);
+            assert_eq!(
+                contract.self_check(0, 10),
+                vec![InvariantViolation::Insolvent {
+                    liabilities: 100,
+                    balance: 50
+                }]
+            );
+            set_balance(contract_id(), 1_000);
+
+            // Corrupt the claim status of the contribution
+            let mut contribution = contract.contributions.get(1u64).unwrap();
+            contribution.is_claimed = true;
+            contract.contributions.insert(1u64, &contribution);
+            assert_eq!(
+                contract.self_check(0, 10),
+                vec![InvariantViolation::ClaimStatusMismatch {
+                    contribution_id: 1u64
+                }]
+            );
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.repair(0, 10),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.repair(1, 10), Ok(0));
+            assert_eq!(contract.repair(0, 10), Ok(1));
+            assert_eq!(contract.self_check(0, 10), vec![]);
+        }
+
+        #[ink::test]
+        fn archive_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1u64, accounts.bob);
+            let _ = contract.approve(2u64, accounts.bob);
+            assert_eq!(
+                contract.archive(vec![1u64]),
+                Err(DemoError::ContributionNotSettled)
+            );
+
+            set_next_caller(accounts.bob);
+            let _ = contract.claim(1u64);
+            let _ = contract.claim(2u64);
+
+            // Only the owner can archive before the delay
+            assert_eq!(
+                contract.archive(vec![1u64]),
+                Err(DemoError::ArchiveTooEarly)
+            );
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.archive(vec![1u64]), Ok(()));
+            assert!(contract.is_archived(1u64));
+            assert_eq!(contract.contributions.get(1u64), None);
+            let archive_root = contract.archive_root();
+            assert_ne!(archive_root, [0x00; 32]);
+
+            // Archived contributions cannot be approved again
+            assert_eq!(
+                contract.approve(1u64, accounts.bob),
+                Err(DemoError::ContributionArchived)
+            );
+            assert_eq!(
+                contract.archive(vec![1u64]),
+                Err(DemoError::UnknownContribution)
+            );
+
+            advance_blocks(PUBLIC_ARCHIVE_DELAY);
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.archive(vec![2u64]), Ok(()));
+            assert_ne!(contract.archive_root(), archive_root);
+
+            // Validate `ContributionArchived` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Some(Event::ContributionArchived(ContributionArchived { id, .. })) =
+                decoded_events.last()
+            else {
+                panic!("encountered unexpected event kind: expected a ContributionArchived event")
+            };
+            assert_eq!(*id, 2u64);
+        }
+
+        #[ink::test]
+        fn archive_with_unpaid_tips_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+
+            set_next_caller(accounts.alice);
+            let _ = contract
+                .approve_with_splits(1u64, vec![(accounts.bob, 3_333), (accounts.charlie, 6_667)]);
+            set_next_caller(accounts.bob);
+            let _ = contract.claim(1u64);
+            set_next_caller(accounts.charlie);
+            let _ = contract.claim(1u64);
+
+            set_next_caller(accounts.django);
+            // The off-chain environment does not move the transferred value
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+            let _ = contract.tip(1u64);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.archive(vec![1u64]), Err(DemoError::TipsUnpaid));
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim_tips(1u64), Ok(3));
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.claim_tips(1u64), Ok(6));
+
+            // The rounding dust of the tips is settled on archive
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.liabilities_of(Asset::Native), 1);
+            assert_eq!(contract.archive(vec![1u64]), Ok(()));
+            assert_eq!(contract.liabilities_of(Asset::Native), 0);
+            assert_eq!(contract.tips_of(1u64), 0);
+            assert!(contract.sponsors_of(1u64).is_empty());
+        }
+
+        #[ink::test]
+        fn only_contract_owner_can_set_reward_vault() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_reward_vault(Some(accounts.django)),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_reward_vault(Some(accounts.django)), Ok(()));
+            assert_eq!(contract.get_reward_vault(), Some(accounts.django));
+        }
+
+        #[ink::test]
+        fn self_approval_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity((Platform::GitHub, [0x01; 32]));
+            let _ = contract.set_payout_address(accounts.django);
+            advance_block();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                self_approval: SelfApproval::Forbidden,
+                ..Config::default()
+            });
+            let _ = contract.grant_session(accounts.django, 10);
+            assert_eq!(
+                contract.approve(1u64, accounts.alice),
+                Err(DemoError::SelfApprovalForbidden)
+            );
+            assert_eq!(
+                contract.approve_with_splits(
+                    1u64,
+                    vec![(accounts.charlie, 5_000), (accounts.alice, 5_000)]
+                ),
+                Err(DemoError::SelfApprovalForbidden)
+            );
+
+            // Approvals paid to the approver through a payout address are self-approvals
+            set_next_caller(accounts.django);
+            assert_eq!(
+                contract.approve(1u64, accounts.django),
+                Err(DemoError::SelfApprovalForbidden)
+            );
+            assert_eq!(
+                contract.approve(1u64, accounts.bob),
+                Err(DemoError::SelfApprovalForbidden)
+            );
+            assert_eq!(contract.approve(1u64, accounts.charlie), Ok(()));
+
+            // The owner of a single maintainer repository can be allowed to
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                self_approval: SelfApproval::OwnerOnly,
+                ..Config::default()
+            });
+            assert_eq!(contract.approve(2u64, accounts.alice), Ok(()));
+            set_next_caller(accounts.django);
+            assert_eq!(
+                contract.approve(3u64, accounts.django),
+                Err(DemoError::SelfApprovalForbidden)
+            );
+        }
+
+        #[ink::test]
+        fn approve_charges_deposit() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.alice, 0);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                deposit_per_byte: 2,
+                ..Config::default()
+            });
+            let needed = 2 * APPROVAL_STORAGE_BYTES;
+            assert_eq!(contract.approval_deposit(), needed);
+            assert_eq!(
+                contract.approve(1u64, accounts.bob),
+                Err(DemoError::InsufficientDeposit { needed })
+            );
+
+            // The off-chain environment does not move the transferred value
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(needed);
+            assert_eq!(contract.approve(1u64, accounts.bob), Ok(()));
+            assert_eq!(get_balance(accounts.alice), 0);
+
+            // The excess is refunded
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(needed + 100);
+            assert_eq!(contract.approve(2u64, accounts.bob), Ok(()));
+            assert_eq!(get_balance(accounts.alice), 100);
+            assert_eq!(get_balance(contract_id()), 900);
+        }
+
+        #[ink::test]
+        fn approve_range_charges_deposit_per_approval() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.alice, 0);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                deposit_per_byte: 2,
+                ..Config::default()
+            });
+            let needed = 2 * APPROVAL_STORAGE_BYTES;
+            // The off-chain environment does not move the transferred value
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(2 * needed);
+            assert_eq!(
+                contract.approve_with_splits(1u64, vec![(accounts.bob, MAX_BPS)]),
+                Ok(())
+            );
+            assert_eq!(get_balance(accounts.alice), needed);
+
+            // The off-chain environment does not revert the approvals of a failed call
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(needed);
+            assert_eq!(
+                contract.approve_range(2u64, 3u64, accounts.bob),
+                Err(DemoError::InsufficientDeposit { needed: 2 * needed })
+            );
+        }
+
+        #[ink::test]
+        fn current_reward_halves() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                reward: 100,
+                halving_period: 10,
+                ..Config::default()
+            });
+            assert_eq!(contract.current_reward(), 100);
+            advance_blocks(9);
+            assert_eq!(contract.current_reward(), 100);
+            advance_block();
+            assert_eq!(contract.current_reward(), 50);
+            let _ = contract.approve(1u64, accounts.bob);
+            assert_eq!(
+                contract
+                    .contribution_view(1u64)
+                    .map(|details| details.reward),
+                Some(50)
+            );
+            advance_blocks(20);
+            assert_eq!(contract.current_reward(), 12);
+            assert_eq!(contract.get_reward(), 100);
+
+            // Halvings restart with each campaign
+            let _ = contract.create_campaign(30, 100, 1_000);
+            assert_eq!(contract.current_reward(), 100);
+            advance_blocks(10);
+            assert_eq!(contract.current_reward(), 50);
+        }
+
+        #[ink::test]
+        fn set_config_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let config = Config {
+                reward: 100,
+                bonus_bps: 5_000,
+                reward_mode: RewardMode::Sqrt,
+                epoch_length: 10,
+                streak_bonus_bps: 1_000,
+                contributor_cap: 2,
+                claim_ttl: 5,
+                referral_bonus: 10,
+                relayer_fee_bps: 100,
+                approval_stake: 1_000,
+                challenge_period: 10,
+                poke_tip: 1,
+                min_claim: 50,
+                endorsement_bonus: 5,
+                max_endorsements: 3,
+                review_quorum: 2,
+                attestation_threshold: 1_000,
+                min_account_age: 30,
+                self_approval: SelfApproval::OwnerOnly,
+                emit_transfer_events: true,
+                halving_period: 100,
+                deposit_per_byte: 1,
+                duplicate_claim_threshold: 500,
+            };
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.config(), Config::default());
+            assert_eq!(contract.set_config(config.clone()), Ok(()));
+            assert_eq!(contract.config(), config);
+            assert_eq!(contract.get_reward(), 100);
+            assert_eq!(contract.get_contributor_cap(), 2);
+
+            // Validate `ConfigUpdated` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Event::ConfigUpdated(ConfigUpdated {
+                config: updated, ..
+            }) = &decoded_events[0]
+            else {
+                panic!("encountered unexpected event kind: expected a ConfigUpdated event")
+            };
+            assert_eq!(*updated, config);
+
+            // Single settings are changed in place
+            let _ = contract.set_reward(200);
+            assert_eq!(
+                contract.config(),
+                Config {
+                    reward: 200,
+                    ..config
+                }
+            );
+        }
+
+        #[ink::test]
+        fn set_invalid_config_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.set_config(Config {
+                    bonus_bps: MAX_BPS + 1,
+                    ..Config::default()
+                }),
+                Err(DemoError::InvalidConfig)
+            );
+            assert_eq!(
+                contract.set_streak_bonus_bps(MAX_BPS + 1),
+                Err(DemoError::InvalidConfig)
+            );
+            assert_eq!(contract.config(), Config::default());
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_config(Config::default()),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn claim_ttl_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                claim_ttl: 5,
+                ..Config::default()
+            });
+            let _ = contract.approve(1u64, accounts.bob);
+            let _ = contract.approve_with_deadline(2u64, accounts.bob, 10);
+            assert_eq!(
+                contract.contributions.get(1u64).and_then(|c| c.deadline),
+                Some(5)
+            );
+            assert_eq!(
+                contract.contributions.get(2u64).and_then(|c| c.deadline),
+                Some(10)
+            );
+        }
+
+        #[ink::test]
+        fn poke_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.charlie, 0);
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.poke(), Ok(0));
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                poke_tip: 10,
+                ..Config::default()
+            });
+            let _ = contract.approve(1u64, accounts.bob);
+            let _ = contract.approve_with_deadline(2u64, accounts.bob, EXPIRY_NOTICE_PERIOD + 5);
+            let _ = contract.approve_with_deadline(3u64, accounts.bob, 2);
+
+            // Only the approaching deadline is reminded
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.poke(), Ok(1));
+
+            // Validate `ClaimExpiringSoon` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Some(Event::ClaimExpiringSoon(ClaimExpiringSoon { id, deadline, .. })) =
+                decoded_events.last()
+            else {
+                panic!("encountered unexpected event kind: expected a ClaimExpiringSoon event")
+            };
+            assert_eq!((*id, *deadline), (3u64, 2));
+            assert_eq!(get_balance(accounts.charlie), 10);
+
+            // Each contribution is reminded once, and no tip is paid without reminders
+            assert_eq!(contract.poke(), Ok(0));
+            assert_eq!(get_balance(accounts.charlie), 10);
+
+            advance_blocks(5);
+            assert_eq!(contract.poke(), Ok(1));
+            assert_eq!(get_balance(accounts.charlie), 20);
+        }
+
+        #[ink::test]
+        fn unclaimed_page_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            for contribution_id in 1u64..=3 {
+                let _ = contract.approve(contribution_id, accounts.bob);
+            }
+            assert_eq!(contract.total_unclaimed(), 3);
+            assert_eq!(contract.unclaimed_page(0, 10), vec![1u64, 2, 3]);
+            assert_eq!(contract.unclaimed_page(1, 1), vec![2u64]);
+            assert_eq!(contract.unclaimed_page(3, 10), vec![]);
+
+            // A claimed contribution is replaced by the last one
+            set_next_caller(accounts.bob);
+            assert!(contract.claim(1u64).is_ok());
+            assert_eq!(contract.total_unclaimed(), 2);
+            assert_eq!(contract.unclaimed_page(0, 10), vec![3u64, 2]);
+            assert!(contract.claim(2u64).is_ok());
+            assert!(contract.claim(3u64).is_ok());
+            assert_eq!(contract.total_unclaimed(), 0);
+            assert_eq!(contract.unclaimed_page(0, 10), vec![]);
+        }
+
+        #[ink::test]
+        fn levels_work() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_level_thresholds(vec![1, 3]),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            for invalid_thresholds in [vec![0, 3], vec![3, 3], vec![3, 1], vec![1; MAX_LEVELS + 1]]
+            {
+                assert_eq!(
+                    contract.set_level_thresholds(invalid_thresholds),
+                    Err(DemoError::InvalidConfig)
+                );
+            }
+            assert_eq!(contract.set_level_thresholds(vec![1, 3]), Ok(()));
+            assert_eq!(contract.level_thresholds(), vec![1, 3]);
+
+            let _ = contract.approve(1u64, accounts.bob);
+            assert_eq!(contract.level_of(accounts.bob), 1);
+
+            // Validate `LevelUp` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            assert!(decoded_events.iter().any(|event| matches!(
+                event,
+                Event::LevelUp(LevelUp { account, level: 1, .. }) if *account == accounts.bob
+            )));
+
+            let _ = contract.approve(2u64, accounts.bob);
+            assert_eq!(contract.level_of(accounts.bob), 1);
+            let _ = contract.approve(3u64, accounts.bob);
+            assert_eq!(contract.level_of(accounts.bob), 2);
+
+            // Levels are never lowered
+            let _ = contract.set_level_thresholds(vec![10]);
+            let _ = contract.approve(4u64, accounts.bob);
+            assert_eq!(contract.level_of(accounts.bob), 2);
+        }
+
+        #[ink::test]
+        fn tick_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.eve, 0);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                reward: 100,
+                claim_ttl: 2,
+                challenge_period: 2,
+                poke_tip: 10,
+                ..Config::default()
+            });
+            let _ = contract.approve(1u64, accounts.bob);
+            set_next_caller(accounts.charlie);
+            let _ = contract.propose_approval(2u64, accounts.django);
+
+            // Nothing is due, and no tip is paid
+            set_next_caller(accounts.eve);
+            assert_eq!(contract.due_actions(), vec![]);
+            assert_eq!(contract.tick(), Ok(0));
+            assert_eq!(get_balance(accounts.eve), 0);
+
+            advance_blocks(3);
+            assert_eq!(
+                contract.due_actions(),
+                vec![Task::Finalize(2u64), Task::Expire(1u64)]
+            );
+            assert_eq!(contract.tick(), Ok(2));
+            assert_eq!(contract.get_contributor(2u64), Some(accounts.django));
+            assert_eq!(contract.status_of(1u64), Status::Settled);
+            assert_eq!(get_balance(accounts.eve), 10);
+            assert_eq!(contract.due_actions(), vec![]);
+        }
+
+        #[ink::test]
+        fn failed_stake_refund_keeps_the_proposal() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                challenge_period: 2,
+                ..Config::default()
+            });
+            set_next_caller(accounts.charlie);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+            let _ = contract.propose_approval(1u64, accounts.django);
+
+            // The off-chain environment does not move the transferred value
+            set_balance(contract_id(), 50);
+            advance_blocks(3);
+            set_next_caller(accounts.eve);
+            assert_eq!(contract.tick(), Ok(0));
+            assert!(contract.get_proposed_approval(1u64).is_some());
+            assert_eq!(contract.get_contributor(1u64), None);
+            assert_eq!(contract.liabilities_of(Asset::Native), 100);
+
+            set_balance(contract_id(), 1_000);
+            assert_eq!(contract.tick(), Ok(1));
+            assert_eq!(contract.get_contributor(1u64), Some(accounts.django));
+            assert_eq!(contract.liabilities_of(Asset::Native), 0);
+        }
+
+        #[ink::test]
+        fn tick_skips_failed_tasks() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.eve, 0);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_config(Config {
+                reward: 100,
+                claim_ttl: 2,
+                challenge_period: 2,
+                poke_tip: 10,
+                ..Config::default()
+            });
+            let _ = contract.approve(1u64, accounts.bob);
+            set_next_caller(accounts.charlie);
+            let _ = contract.propose_approval(2u64, accounts.django);
+            set_next_caller(accounts.alice);
+            let _ = contract.set_denied(accounts.django, true);
+
+            // The denied contributor fails its finalization, the expiry is still executed
+            advance_blocks(3);
+            set_next_caller(accounts.eve);
+            assert_eq!(contract.tick(), Ok(1));
+            assert_eq!(contract.get_contributor(2u64), None);
+            assert_eq!(contract.status_of(1u64), Status::Settled);
+            assert_eq!(get_balance(accounts.eve), 10);
+            assert_eq!(contract.due_actions(), vec![Task::Finalize(2u64)]);
+
+            // Validate `TaskFailed` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            assert!(decoded_events.iter().any(|event| matches!(
+                event,
+                Event::TaskFailed(TaskFailed {
+                    task: Task::Finalize(2u64),
+                    error: DemoError::ContributorDenied,
+                    ..
+                })
+            )));
+        }
+
+        #[ink::test]
+        fn only_contract_owner_can_set_reward_strategy() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_reward_strategy(Some(accounts.django)),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_reward_strategy(Some(accounts.django)), Ok(()));
+            assert_eq!(contract.get_reward_strategy(), Some(accounts.django));
+        }
+
+        #[ink::test]
+        fn set_booster_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_booster(Some(accounts.django), 15_000),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.set_booster(Some(accounts.django), MAX_BPS - 1),
+                Err(DemoError::InvalidConfig)
+            );
+            assert_eq!(contract.set_booster(Some(accounts.django), 15_000), Ok(()));
+            assert_eq!(contract.get_booster(), Some((accounts.django, 15_000)));
+            assert_eq!(contract.set_booster(None, MAX_BPS), Ok(()));
+            assert_eq!(contract.get_booster(), None);
+            assert_eq!(contract.boost_bps(accounts.bob), MAX_BPS);
+        }
+
+        #[ink::test]
+        fn boost_is_not_paid_from_liabilities() {
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+
+            // The whole balance is owed to contributors, so no boost can be paid on top
+            contract.owe(Asset::Native, 1_000);
+            assert_eq!(contract.ensure_boost_covered(0), Ok(()));
+            assert_eq!(
+                contract.ensure_boost_covered(500),
+                Err(DemoError::InsufficientPool {
+                    needed: 500,
+                    available: 0
+                })
+            );
+
+            contract.settle(Asset::Native, 500);
+            assert_eq!(contract.ensure_boost_covered(500), Ok(()));
+        }
+
+        #[ink::test]
+        fn subscribers_work() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.add_subscriber(accounts.django),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.add_subscriber(accounts.django), Ok(()));
+            assert_eq!(contract.add_subscriber(accounts.django), Ok(()));
+            assert_eq!(contract.get_subscribers(), vec![accounts.django]);
+            for i in 1..MAX_SUBSCRIBERS {
+                assert_eq!(
+                    contract.add_subscriber(AccountId::from([0x10 + i as u8; 32])),
+                    Ok(())
+                );
+            }
+            assert_eq!(
+                contract.add_subscriber(accounts.eve),
+                Err(DemoError::TooManySubscribers)
+            );
+
+            assert_eq!(contract.remove_subscriber(accounts.django), Ok(()));
+            assert_eq!(contract.get_subscribers().len(), MAX_SUBSCRIBERS - 1);
+            assert!(!contract.get_subscribers().contains(&accounts.django));
+        }
+
+        #[ink::test]
+        fn claim_beyond_pool_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 50);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(100);
+            let _ = contract.create_campaign(0, 10, 1_000);
+            let _ = contract.approve(1u64, accounts.bob);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.claim(1u64),
+                Err(DemoError::InsufficientPool {
+                    needed: 100,
+                    available: 50
+                })
+            );
+        }
+
+        #[ink::test]
+        fn only_contract_owner_can_set_reward() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_reward(100),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn first_contribution_bonus_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.bob, 0);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(100);
+            let _ = contract.create_campaign(0, 10, 1_000);
+            assert_eq!(contract.set_bonus_bps(5_000), Ok(()));
+            assert_eq!(contract.get_bonus_bps(), 5_000);
+
+            // First contribution gets the bonus
+            assert_eq!(contract.get_approvals(accounts.bob), 0);
+            let _ = contract.approve(1u64, accounts.bob);
+            assert_eq!(contract.get_approvals(accounts.bob), 1);
+            assert_eq!(
+                contract.contributions.get(1u64).map(|c| c.reward),
+                Some(150)
+            );
+
+            // Repeat contributions do not
+            let _ = contract.approve(2u64, accounts.bob);
+            assert_eq!(contract.get_approvals(accounts.bob), 2);
+            assert_eq!(
+                contract.contributions.get(2u64).map(|c| c.reward),
+                Some(100)
+            );
+
+            set_next_caller(accounts.bob);
+            let _ = contract.claim(1u64);
+            let _ = contract.claim(2u64);
+            assert_eq!(get_balance(accounts.bob), 250);
+        }
+
+        #[ink::test]
+        fn reward_modes_work() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let reward_of = |contract: &Demo, contribution_id: ContributionId| {
+                contract
+                    .contributions
+                    .get(contribution_id)
+                    .map(|c| c.reward)
+            };
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(1_000);
+            let _ = contract.create_campaign(0, 10, 10_000);
+
+            // The difficulty is ignored by default
+            assert_eq!(contract.get_reward_mode(), RewardMode::Flat);
+            let _ = contract.approve_with_difficulty(1u64, accounts.bob, 25);
+            assert_eq!(reward_of(&contract, 1u64), Some(1_000));
+
+            assert_eq!(contract.set_reward_mode(RewardMode::Quadratic), Ok(()));
+            let _ = contract.approve_with_difficulty(2u64, accounts.bob, 25);
+            assert_eq!(reward_of(&contract, 2u64), Some(62));
+
+            assert_eq!(contract.set_reward_mode(RewardMode::Sqrt), Ok(()));
+            let _ = contract.approve_with_difficulty(3u64, accounts.bob, 25);
+            assert_eq!(reward_of(&contract, 3u64), Some(500));
+
+            // Approvals without difficulty get the whole reward, as do too difficult ones
+            let _ = contract.approve(4u64, accounts.bob);
+            assert_eq!(reward_of(&contract, 4u64), Some(1_000));
+            let _ = contract.approve_with_difficulty(5u64, accounts.bob, 200);
+            assert_eq!(reward_of(&contract, 5u64), Some(1_000));
+        }
+
+        #[ink::test]
+        fn difficulty_oracle_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_difficulty_oracle(Some(accounts.django)),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(1_000);
+            let _ = contract.create_campaign(0, 10, 10_000);
+            let _ = contract.set_reward_mode(RewardMode::Quadratic);
+            assert_eq!(
+                contract.set_difficulty_oracle(Some(accounts.django)),
+                Ok(())
+            );
+            assert_eq!(
+                contract.push_difficulty(1u64, 25),
+                Err(DemoError::CallerIsNotOracle)
+            );
+
+            // The pushed score weights the reward on approval
+            set_next_caller(accounts.django);
+            assert_eq!(contract.push_difficulty(1u64, 25), Ok(()));
+            assert_eq!(contract.difficulty_of(1u64), Some(25));
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1u64, accounts.bob);
+            let _ = contract.approve(2u64, accounts.bob);
+            assert_eq!(contract.contributions.get(1u64).map(|c| c.reward), Some(62));
+            assert_eq!(
+                contract.contributions.get(2u64).map(|c| c.reward),
+                Some(1_000)
+            );
+
+            set_next_caller(accounts.django);
+            assert_eq!(
+                contract.push_difficulty(2u64, 25),
+                Err(DemoError::ContributionAlreadyApproved {
+                    contributor: accounts.bob
+                })
+            );
+        }
+
+        #[ink::test]
+        fn only_contract_owner_can_set_reward_mode() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_reward_mode(RewardMode::Sqrt),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+            assert_eq!(
+                contract.approve_with_difficulty(1u64, accounts.bob, 50),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn only_contract_owner_can_set_bonus_bps() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_bonus_bps(5_000),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn effective_reputation_decays() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let point = POINTS_PER_APPROVAL * REPUTATION_PRECISION;
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.effective_reputation_of(accounts.bob), 0);
+            assert_eq!(contract.approve(1u64, accounts.bob), Ok(()));
+            assert_eq!(contract.effective_reputation_of(accounts.bob), point);
+
+            // The reputation halves over a half-life, while the raw score is kept
+            advance_blocks(REPUTATION_HALF_LIFE);
+            assert_eq!(contract.effective_reputation_of(accounts.bob), point / 2);
+            assert_eq!(contract.balance_of(accounts.bob), POINTS_PER_APPROVAL);
+
+            // New points add up to the decayed reputation
+            assert_eq!(contract.approve(2u64, accounts.bob), Ok(()));
+            assert_eq!(
+                contract.effective_reputation_of(accounts.bob),
+                point / 2 + point
+            );
+            advance_blocks(REPUTATION_HALF_LIFE / 2);
+            assert_eq!(
+                contract.effective_reputation_of(accounts.bob),
+                (point / 2 + point) * 3 / 4
+            );
+        }
+
+        #[ink::test]
+        fn create_campaign_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.active_campaign(), None);
+            assert_eq!(contract.create_campaign(0, 10, 1_000), Ok(0));
+
+            // Validate `CampaignCreated` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            assert_eq!(1, emitted_events.len());
+            let decoded_events = decode_events(emitted_events);
+            let Event::CampaignCreated(CampaignCreated {
+                id,
+                start,
+                end,
+                budget,
+                ..
+            }) = decoded_events[0]
+            else {
+                panic!("encountered unexpected event kind: expected a CampaignCreated event")
+            };
+            assert_eq!((id, start, end, budget), (0, 0, 10, 1_000));
+
+            assert_eq!(contract.active_campaign(), Some(0));
+            assert_eq!(contract.remaining_budget(), 1_000);
+            assert_eq!(
+                contract.get_campaign(0),
+                Some(Campaign {
+                    start: 0,
+                    end: 10,
+                    budget: 1_000,
+                    remaining: 1_000,
+                    asset: Asset::Native,
+                    in_usd: false,
+                })
+            );
+
+            // Another campaign cannot be created while one is active
+            assert_eq!(
+                contract.create_campaign(5, 20, 1_000),
+                Err(DemoError::CampaignAlreadyActive)
+            );
+        }
+
+        #[ink::test]
+        fn set_usd_pricing_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let usd_pricing = UsdPricing {
+                feed: accounts.django,
+                pair: BoundedString::try_from("DOT/USD").unwrap(),
+                unit: 10_000_000_000,
+                max_age: 60_000,
+                fallback_price: 5 * 10u128.pow(18),
+            };
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_usd_pricing(Some(usd_pricing.clone())),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.set_usd_pricing(Some(UsdPricing {
+                    unit: 0,
+                    ..usd_pricing.clone()
+                })),
+                Err(DemoError::InvalidConfig)
+            );
+            assert_eq!(
+                contract.set_usd_pricing(Some(UsdPricing {
+                    fallback_price: 0,
+                    ..usd_pricing.clone()
+                })),
+                Err(DemoError::InvalidConfig)
+            );
+            assert_eq!(contract.set_usd_pricing(Some(usd_pricing.clone())), Ok(()));
+            assert_eq!(contract.get_usd_pricing(), Some(usd_pricing));
+            assert_eq!(contract.set_usd_pricing(None), Ok(()));
+            assert_eq!(contract.get_usd_pricing(), None);
+        }
+
+        #[ink::test]
+        fn create_usd_campaign_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.create_usd_campaign(0, 10, 1_000, Asset::Native),
+                Err(DemoError::UsdPricingUnset)
+            );
+            assert_eq!(
+                contract.set_usd_pricing(Some(UsdPricing {
+                    feed: accounts.django,
+                    pair: BoundedString::try_from("DOT/USD").unwrap(),
+                    unit: 10_000_000_000,
+                    max_age: 60_000,
+                    fallback_price: 5 * 10u128.pow(18),
+                })),
+                Ok(())
+            );
+            let _ = contract.set_reward(100);
+            assert_eq!(
+                contract.create_usd_campaign(0, 10, 1_000, Asset::Native),
+                Ok(0)
+            );
+            assert_eq!(
+                contract.get_campaign(0).map(|campaign| campaign.in_usd),
+                Some(true)
+            );
+
+            // Rewards denominated in USD cents are not owed in the reward asset
+            assert_eq!(contract.approve(1, accounts.bob), Ok(()));
+            assert_eq!(contract.remaining_budget(), 900);
+            assert_eq!(contract.liabilities_of(Asset::Native), 0);
+        }
+
+        #[ink::test]
+        fn reward_assets_work() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let token = accounts.django;
+
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.create_campaign_with_asset(0, 10, 1_000, Asset::PSP22(token)),
+                Err(DemoError::UnknownRewardAsset)
+            );
+            assert_eq!(contract.add_reward_asset(token), Ok(()));
+            assert!(contract.is_reward_asset(token));
+            let _ = contract.set_reward(100);
+            assert_eq!(
+                contract.create_campaign_with_asset(0, 10, 1_000, Asset::PSP22(token)),
+                Ok(0)
+            );
+
+            // Contributions are rewarded in the asset of the campaign
+            let _ = contract.approve(1u64, accounts.bob);
+            assert_eq!(
+                contract.contributions.get(1u64).map(|c| c.asset),
+                Some(Asset::PSP22(token))
+            );
+            assert_eq!(contract.liabilities_of(Asset::PSP22(token)), 100);
+            assert_eq!(contract.liabilities_of(Asset::Native), 0);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.remove_reward_asset(token),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn native_liabilities_work() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(100);
+            let _ = contract.create_campaign(0, 10, 1_000);
+            let _ = contract
+                .approve_with_splits(1u64, vec![(accounts.bob, 5_000), (accounts.charlie, 5_000)]);
+            assert_eq!(contract.liabilities_of(Asset::Native), 100);
+
+            set_next_caller(accounts.bob);
+            let _ = contract.claim(1u64);
+            assert_eq!(contract.liabilities_of(Asset::Native), 50);
+        }
+
+        #[ink::test]
+        fn native_reward_asset_info_works() {
+            let contract = create_contract();
+            assert_eq!(
+                contract.reward_asset_info(),
+                AssetInfo {
+                    asset: Asset::Native,
+                    symbol: None,
+                    decimals: None,
+                }
+            );
+        }
+
+        #[ink::test]
+        fn finalize_campaign_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(100);
+            assert_eq!(
+                contract.finalize_campaign(0),
+                Err(DemoError::UnknownCampaign)
+            );
+            let campaign_id = contract.create_campaign(0, 10, 300).unwrap();
+            let _ = contract.approve(1u64, accounts.bob);
+            let _ = contract.approve(2u64, accounts.bob);
+            let _ = contract.approve(3u64, accounts.charlie);
+            // The campaign is out of budget, but not over yet
+            assert_eq!(contract.active_campaign(), None);
+            assert_eq!(
+                contract.finalize_campaign(campaign_id),
+                Err(DemoError::CampaignNotOver)
+            );
+
+            advance_blocks(11);
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.finalize_campaign(campaign_id),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            let summary = CampaignSummary {
+                total_paid: 300,
+                contributors: 2,
+                top_contributor: Some(accounts.bob),
+                top_reward: 200,
+                finalized_at: 11,
+            };
+            assert_eq!(contract.finalize_campaign(campaign_id), Ok(summary.clone()));
+            assert_eq!(
+                contract.campaign_summary(campaign_id),
+                Some(summary.clone())
+            );
+
+            // Validate `CampaignFinalized` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Some(Event::CampaignFinalized(CampaignFinalized {
+                id,
+                summary: finalized,
+                ..
+            })) = decoded_events.last()
+            else {
+                panic!("encountered unexpected event kind: expected a CampaignFinalized event")
+            };
+            assert_eq!(*id, campaign_id);
+            assert_eq!(*finalized, summary);
+
+            assert_eq!(
+                contract.finalize_campaign(campaign_id),
+                Err(DemoError::CampaignFinalized)
+            );
+        }
+
+        #[ink::test]
+        fn create_invalid_campaign_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.create_campaign(10, 0, 1_000),
+                Err(DemoError::InvalidCampaign)
+            );
+        }
+
+        #[ink::test]
+        fn only_contract_owner_can_create_campaign() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.create_campaign(0, 10, 1_000),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn rewards_are_only_granted_in_active_campaign() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(100);
+
+            // No campaign
+            let _ = contract.approve(1u64, accounts.bob);
+            assert_eq!(contract.contributions.get(1u64).map(|c| c.reward), Some(0));
+
+            // Campaign not started yet
+            let _ = contract.create_campaign(1, 2, 150);
+            let _ = contract.approve(2u64, accounts.bob);
+            assert_eq!(contract.contributions.get(2u64).map(|c| c.reward), Some(0));
+
+            // Active campaign, until the budget is exhausted
+            advance_block();
+            let _ = contract.approve(3u64, accounts.bob);
+            assert_eq!(
+                contract.contributions.get(3u64).map(|c| c.reward),
+                Some(100)
+            );
+            let _ = contract.approve(4u64, accounts.bob);
+            assert_eq!(contract.contributions.get(4u64).map(|c| c.reward), Some(50));
+            assert_eq!(contract.remaining_budget(), 0);
+            assert_eq!(contract.active_campaign(), None);
+
+            // Campaign ended
+            let _ = contract.create_campaign(2, 2, 1_000);
+            advance_block();
+            advance_block();
+            assert_eq!(contract.active_campaign(), None);
+            let _ = contract.approve(5u64, accounts.bob);
+            assert_eq!(contract.contributions.get(5u64).map(|c| c.reward), Some(0));
+        }
+
+        #[ink::test]
+        fn approvals_since_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1u64, accounts.bob);
+            advance_block();
+            let _ = contract.approve(2u64, accounts.bob);
+            let _ = contract.approve(3u64, accounts.bob);
+            advance_block();
+
+            assert_eq!(contract.approvals_since(0), vec![1u64, 2u64, 3u64]);
+            assert_eq!(contract.approvals_since(1), vec![2u64, 3u64]);
+            assert_eq!(contract.approvals_since(2), Vec::<ContributionId>::new());
+        }
+
+        #[ink::test]
+        fn approval_log_is_pruned() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            for contribution_id in 0..(APPROVAL_LOG_CAPACITY as u64 + 1) {
+                let _ = contract.approve(contribution_id, accounts.bob);
+            }
+
+            let approvals = contract.approvals_since(0);
+            assert_eq!(approvals.len(), APPROVAL_LOG_CAPACITY);
+            assert_eq!(approvals[0], 1u64);
+        }
+
+        #[ink::test]
+        fn approve_with_splits_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let contribution_id = 1u64;
+            let splits = vec![(accounts.bob, 7_500), (accounts.charlie, 2_500)];
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.bob, 0);
+            set_balance(accounts.charlie, 0);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(100);
+            let _ = contract.create_campaign(0, 10, 1_000);
+            assert_eq!(
+                contract.approve_with_splits(contribution_id, splits.clone()),
+                Ok(())
+            );
+            assert_eq!(contract.get_splits(contribution_id), splits);
+            assert_eq!(
+                contract.get_contributor(contribution_id),
+                Some(accounts.bob)
+            );
+            assert_eq!(contract.get_approvals(accounts.charlie), 1);
+
+            // Each co-author claims its share independently
+            set_next_caller(accounts.charlie);
+            assert!(contract.check(contribution_id));
+            assert!(contract.claim(contribution_id).is_ok());
+            assert_eq!(get_balance(accounts.charlie), 25);
+            assert_eq!(
+                contract.claim(contribution_id),
+                Err(DemoError::AlreadyClaimed)
+            );
+            assert_eq!(
+                contract
+                    .contributions
+                    .get(contribution_id)
+                    .map(|c| c.is_claimed),
+                Some(false)
+            );
+
+            set_next_caller(accounts.bob);
+            assert!(contract.claim(contribution_id).is_ok());
+            assert_eq!(get_balance(accounts.bob), 75);
+            assert_eq!(
+                contract
+                    .contributions
+                    .get(contribution_id)
+                    .map(|c| c.is_claimed),
+                Some(true)
+            );
+        }
+
+        #[ink::test]
+        fn approve_with_invalid_splits_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.approve_with_splits(1u64, vec![]),
+                Err(DemoError::InvalidSplits)
+            );
+            assert_eq!(
+                contract.approve_with_splits(1u64, vec![(accounts.bob, 5_000)]),
+                Err(DemoError::InvalidSplits)
+            );
+            assert_eq!(
+                contract
+                    .approve_with_splits(1u64, vec![(accounts.bob, 5_000), (accounts.bob, 5_000)]),
+                Err(DemoError::InvalidSplits)
+            );
+            assert_eq!(
+                contract.approve_with_splits(
+                    1u64,
+                    vec![(accounts.bob, u16::MAX), (accounts.charlie, 10_001)]
+                ),
+                Err(DemoError::InvalidSplits)
+            );
+        }
+
+        #[ink::test]
+        fn streak_bonus_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let reward_of = |contract: &Demo, contribution_id: ContributionId| {
+                contract
+                    .contributions
+                    .get(contribution_id)
+                    .map(|c| c.reward)
+            };
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(100);
+            let _ = contract.create_campaign(0, 100, 10_000);
+            assert_eq!(contract.set_epoch_length(10), Ok(()));
+            assert_eq!(contract.set_streak_bonus_bps(1_000), Ok(()));
+            assert_eq!(contract.get_epoch_length(), 10);
+            assert_eq!(contract.get_streak_bonus_bps(), 1_000);
+
+            // Epoch 0
+            let _ = contract.approve(1u64, accounts.bob);
+            assert_eq!(contract.streak_of(accounts.bob), 1);
+            assert_eq!(reward_of(&contract, 1u64), Some(100));
+
+            // Validate `StreakUpdated` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Event::StreakUpdated(StreakUpdated {
+                account, streak, ..
+            }) = decoded_events[1]
+            else {
+                panic!("encountered unexpected event kind: expected a StreakUpdated event")
+            };
+            assert_eq!((account, streak), (accounts.bob, 1));
+
+            // Epoch 1
+            advance_blocks(10);
+            let _ = contract.approve(2u64, accounts.bob);
+            assert_eq!(contract.streak_of(accounts.bob), 2);
+            assert_eq!(reward_of(&contract, 2u64), Some(110));
+            let _ = contract.approve(3u64, accounts.bob);
+            assert_eq!(contract.streak_of(accounts.bob), 2);
+            assert_eq!(reward_of(&contract, 3u64), Some(110));
+
+            // Epoch 3, the streak is reset after a gap
+            advance_blocks(20);
+            assert_eq!(contract.streak_of(accounts.bob), 0);
+            let _ = contract.approve(4u64, accounts.bob);
+            assert_eq!(contract.streak_of(accounts.bob), 1);
+            assert_eq!(reward_of(&contract, 4u64), Some(100));
+        }
+
+        #[ink::test]
+        fn only_contract_owner_can_configure_streaks() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_epoch_length(10),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+            assert_eq!(
+                contract.set_streak_bonus_bps(1_000),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn soulbound_points_work() {
+            use openbrush::contracts::traits::psp22::PSP22;
+
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = Workflow::approve(&mut contract, 1u64, accounts.bob);
+            let _ = contract
+                .approve_with_splits(2u64, vec![(accounts.bob, 5_000), (accounts.charlie, 5_000)]);
+            assert_eq!(contract.balance_of(accounts.bob), 2);
+            assert_eq!(contract.balance_of(accounts.charlie), 1);
+            assert_eq!(contract.total_supply(), 3);
+
+            // Validate `Transfer` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Event::Transfer(Transfer { from, to, value }) = decoded_events[1] else {
+                panic!("encountered unexpected event kind: expected a Transfer event")
+            };
+            assert_eq!((from, to, value), (None, Some(accounts.bob), 1));
+
+            // Points cannot be transferred
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.transfer(accounts.charlie, 1, vec![]),
+                Err(PSP22Error::Custom(String::from("Soulbound")))
+            );
+            assert_eq!(
+                PSP22::approve(&mut contract, accounts.charlie, 1),
+                Err(PSP22Error::Custom(String::from("Soulbound")))
+            );
+            assert_eq!(
+                contract.transfer_from(accounts.bob, accounts.charlie, 1, vec![]),
+                Err(PSP22Error::Custom(String::from("Soulbound")))
+            );
+            assert_eq!(contract.allowance(accounts.bob, accounts.charlie), 0);
+            assert_eq!(contract.balance_of(accounts.bob), 2);
+        }
+
+        #[ink::test]
+        fn sweep_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 10_000_000);
+            set_balance(accounts.django, 0);
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.get_sweep_timelock(), DEFAULT_SWEEP_TIMELOCK);
+            assert_eq!(contract.set_sweep_timelock(1_000), Ok(()));
+            assert_eq!(contract.schedule_sweep(accounts.django), Ok(()));
+            assert_eq!(
+                contract.get_scheduled_sweep(),
+                Some(Sweep {
+                    to: accounts.django,
+                    executable_at: 1_000,
+                })
+            );
+            assert_eq!(
+                contract.schedule_sweep(accounts.django),
+                Err(DemoError::SweepAlreadyScheduled)
+            );
+
+            // The sweep is timelocked
+            set_block_timestamp(999);
+            assert_eq!(contract.execute_sweep(), Err(DemoError::SweepTimelocked));
+
+            set_block_timestamp(1_000);
+            assert_eq!(contract.execute_sweep(), Ok(()));
+            let minimum_balance = ink::env::minimum_balance::<ink::env::DefaultEnvironment>();
+            assert_eq!(get_balance(accounts.django), 10_000_000 - minimum_balance);
+            assert_eq!(contract.get_scheduled_sweep(), None);
+
+            // Validate `SweepExecuted` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Event::SweepExecuted(SweepExecuted { to, amount, .. }) = decoded_events[1] else {
+                panic!("encountered unexpected event kind: expected a SweepExecuted event")
+            };
+            assert_eq!(
+                (to, amount),
+                (accounts.django, 10_000_000 - minimum_balance)
+            );
+        }
+
+        #[ink::test]
+        fn transfer_ownership_checked_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.transfer_ownership_checked(accounts.bob),
+                Err(DemoError::OwnershipIntentMissing)
+            );
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim_ownership_intent(), Ok(()));
+            assert!(contract.has_ownership_intent(accounts.bob));
+            assert_eq!(
+                contract.transfer_ownership_checked(accounts.bob),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.transfer_ownership_checked(accounts.bob), Ok(()));
+            assert_eq!(contract.owner(), Some(accounts.bob));
+            assert!(!contract.has_ownership_intent(accounts.bob));
+        }
+
+        #[ink::test]
+        fn terminate_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.terminate(accounts.django, false),
+                Err(DemoError::NoTerminationScheduled)
+            );
+            let _ = contract.set_sweep_timelock(1_000);
+            assert_eq!(contract.schedule_termination(accounts.django), Ok(()));
+            assert_eq!(
+                contract.terminate(accounts.django, false),
+                Err(DemoError::TerminationTimelocked)
+            );
+
+            set_block_timestamp(1_000);
+            assert_eq!(
+                contract.terminate(accounts.charlie, false),
+                Err(DemoError::NoTerminationScheduled)
+            );
+            let should_terminate = move || {
+                let _ = contract.terminate(accounts.django, false);
+            };
+            ink::env::test::assert_contract_termination::<ink::env::DefaultEnvironment, _>(
+                should_terminate,
+                accounts.django,
+                1_000,
+            );
+        }
+
+        #[ink::test]
+        fn terminate_with_liabilities_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(100);
+            let _ = contract.create_campaign(0, 10, 1_000);
+            let _ = contract.set_config(Config {
+                claim_ttl: 10,
+                ..contract.config()
+            });
+            let _ = contract.approve(1u64, accounts.bob);
+            let _ = contract.set_sweep_timelock(0);
+            let _ = contract.schedule_termination(accounts.django);
+            assert_eq!(
+                contract.terminate(accounts.django, false),
+                Err(DemoError::OutstandingLiabilities)
+            );
+            assert_eq!(
+                contract.terminate(accounts.django, true),
+                Err(DemoError::OutstandingLiabilities)
+            );
+
+            // A forced termination is allowed once the rewards expired
+            advance_blocks(11);
+            assert_eq!(
+                contract.terminate(accounts.django, false),
+                Err(DemoError::OutstandingLiabilities)
+            );
+            assert!(contract.owes_expired_rewards_only());
+
+            // But not while anything else is owed
+            contract.owe(Asset::Native, 10);
+            assert_eq!(
+                contract.terminate(accounts.django, true),
+                Err(DemoError::OutstandingLiabilities)
+            );
+            contract.settle(Asset::Native, 10);
+            assert!(contract.owes_expired_rewards_only());
+            assert_eq!(contract.cancel_termination(), Ok(()));
+        }
+
+        #[ink::test]
+        fn terminate_with_psp22_liabilities_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            contract.owe(Asset::PSP22(accounts.eve), 100);
+            let _ = contract.set_sweep_timelock(0);
+            let _ = contract.schedule_termination(accounts.django);
+            assert_eq!(contract.liabilities_of(Asset::Native), 0);
+            assert_eq!(
+                contract.terminate(accounts.django, false),
+                Err(DemoError::OutstandingLiabilities)
+            );
+
+            contract.settle(Asset::PSP22(accounts.eve), 100);
+            assert_eq!(contract.indebted_assets.get_or_default(), 0);
+        }
+
+        #[ink::test]
+        fn governance_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let action = ProposalAction::SetDenied {
+                account: accounts.django,
+                denied: true,
+            };
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1u64, accounts.bob);
+            let _ = contract.approve(2u64, accounts.bob);
+            let _ = contract.approve(3u64, accounts.charlie);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.propose(action.clone()),
+                Err(DemoError::GovernanceDisabled)
+            );
+            assert_eq!(
+                contract.set_governance(true),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_governance(true), Ok(()));
+            assert_eq!(
+                contract.propose(action.clone()),
+                Err(DemoError::NoVotingPower)
+            );
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.propose(action), Ok(0));
+            assert_eq!(contract.vote(0, true), Ok(()));
+            assert_eq!(contract.vote(0, true), Err(DemoError::AlreadyVoted));
+
+            // No vote is cast while governance is disabled
+            set_next_caller(accounts.alice);
+            let _ = contract.set_governance(false);
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.vote(0, false), Err(DemoError::GovernanceDisabled));
+            set_next_caller(accounts.alice);
+            let _ = contract.set_governance(true);
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.vote(0, false), Ok(()));
+            assert_eq!(contract.execute(0), Err(DemoError::VotingNotClosed));
+
+            // Votes are weighted by KUDOS points
+            advance_blocks(VOTING_PERIOD + 1);
+            assert_eq!(contract.vote(0, false), Err(DemoError::VotingClosed));
+            assert_eq!(contract.execute(0), Ok(()));
+            assert!(contract.is_denied(accounts.django));
+            assert_eq!(
+                contract
+                    .get_proposal(0)
+                    .map(|p| (p.votes_for, p.votes_against, p.executed)),
+                Some((2, 1, true))
+            );
+            assert_eq!(contract.execute(0), Err(DemoError::ProposalNotExecutable));
+        }
+
+        #[ink::test]
+        fn governance_needs_quorum() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let action = ProposalAction::SetPermission {
+                role: 1,
+                selector: traits::selectors::workflow::APPROVE.to_be_bytes(),
+                allowed: true,
+            };
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_governance(true);
+            let _ = contract.approve(1u64, accounts.bob);
+            let _ = contract.approve_range(2u64, 10u64, accounts.charlie);
+
+            // A single point out of 10 does not pass a proposal unopposed
+            set_next_caller(accounts.bob);
+            let _ = contract.propose(action.clone());
+            let _ = contract.vote(0, true);
+            advance_blocks(VOTING_PERIOD + 1);
+            assert_eq!(contract.execute(0), Err(DemoError::QuorumNotReached));
+
+            // Holders can vote approvers in
+            let _ = contract.propose(action);
+            let _ = contract.vote(1, true);
+            set_next_caller(accounts.charlie);
+            let _ = contract.vote(1, false);
+            let _ = contract.propose(ProposalAction::SetRole {
+                account: accounts.django,
+                role: Some(1),
+            });
+            let _ = contract.vote(2, true);
+            advance_blocks(VOTING_PERIOD + 1);
+            assert_eq!(contract.execute(1), Err(DemoError::ProposalNotExecutable));
+            assert_eq!(contract.execute(2), Ok(()));
+            assert_eq!(contract.role_of(accounts.django), Some(1));
+        }
+
+        #[ink::test]
+        fn cancel_sweep_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.cancel_sweep(), Err(DemoError::NoSweepScheduled));
+            let _ = contract.schedule_sweep(accounts.django);
+            assert_eq!(contract.cancel_sweep(), Ok(()));
+            assert_eq!(contract.get_scheduled_sweep(), None);
+
+            set_block_timestamp(DEFAULT_SWEEP_TIMELOCK);
+            assert_eq!(contract.execute_sweep(), Err(DemoError::NoSweepScheduled));
+        }
+
+        #[ink::test]
+        fn only_contract_owner_can_sweep() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.schedule_sweep(accounts.bob),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+            assert_eq!(
+                contract.cancel_sweep(),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+            assert_eq!(
+                contract.execute_sweep(),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+            assert_eq!(
+                contract.set_sweep_timelock(0),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn contributor_cap_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(100);
+            assert_eq!(contract.set_contributor_cap(2), Ok(()));
+            assert_eq!(contract.get_contributor_cap(), 2);
+
+            // No cap outside of a campaign
+            for contribution_id in 0..3u64 {
+                assert_eq!(contract.approve(contribution_id, accounts.bob), Ok(()));
+            }
 
-    #[ink(storage)]
-    #[derive(Default, Storage)]
-    pub struct Demo {
-        // The field to save the owner of the contract
-        #[storage_field]
-        ownable: ownable::Data,
+            let _ = contract.create_campaign(0, 10, 1_000);
+            assert_eq!(contract.approve(3u64, accounts.bob), Ok(()));
+            assert_eq!(contract.approve(4u64, accounts.bob), Ok(()));
+            assert_eq!(contract.get_campaign_approvals(0, accounts.bob), 2);
+            assert_eq!(
+                contract.can_approve(5u64, accounts.bob),
+                Err(DemoError::ContributorCapReached)
+            );
+            assert_eq!(
+                contract.approve(5u64, accounts.bob),
+                Err(DemoError::ContributorCapReached)
+            );
+            assert_eq!(
+                contract.approve_with_splits(
+                    5u64,
+                    vec![(accounts.charlie, 5_000), (accounts.bob, 5_000)]
+                ),
+                Err(DemoError::ContributorCapReached)
+            );
 
-        // The approved `Contribution`.
-        contributions: Mapping<ContributionId, AccountId>,
-    }
+            // Other contributors are not capped
+            assert_eq!(contract.approve(5u64, accounts.charlie), Ok(()));
+        }
 
-    /// Emitted when a `contribution` is approved.
-    #[ink(event)]
-    pub struct ContributionApproval {
-        id: ContributionId,
-        contributor: AccountId,
-    }
+        #[ink::test]
+        fn only_contract_owner_can_set_contributor_cap() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
 
-    impl Demo {
-        /// Constructor that initializes an asset reward for a given workflow
-        #[ink(constructor)]
-        pub fn new() -> Self {
-            let mut instance = Self::default();
-            ownable::Internal::_init_with_owner(&mut instance, Self::env().caller());
-            instance
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_contributor_cap(2),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
         }
 
-        /// Approve contribution. This is triggered by a workflow run.
-        #[ink(message)]
-        #[modifiers(only_owner)]
-        pub fn approve(
-            &mut self,
-            contribution_id: ContributionId,
-            contributor: AccountId,
-        ) -> Result<(), DemoError> {
-            match self.contributions.get(contribution_id) {
-                Some(_) => Err(DemoError::ContributionAlreadyApproved),
-                None => {
-                    self.contributions.insert(contribution_id, &contributor);
-                    self.env().emit_event(ContributionApproval {
-                        id: contribution_id,
-                        contributor,
-                    });
-                    Ok(())
-                }
-            }
+        #[ink::test]
+        fn verify_receipt_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let contribution_id = 1u64;
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(contribution_id, accounts.bob);
+
+            set_next_caller(accounts.bob);
+            let receipt = contract.claim(contribution_id).expect("claim failed");
+
+            assert!(contract.verify_receipt(receipt));
+            assert!(!contract.verify_receipt([0x00; 32]));
         }
 
-        /// Simply returns the `AccountId` of a given contribution.
-        #[ink(message)]
-        pub fn get_contributor(&self, contribution_id: ContributionId) -> Option<AccountId> {
-            self.contributions.get(contribution_id)
+        #[ink::test]
+        fn claim_airdrop_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.bob, 0);
+            let bob_leaf = contract.airdrop_leaf([0x01; 32], 300);
+            let charlie_leaf = contract.airdrop_leaf([0x02; 32], 200);
+            let root = merkle::hash_pair(&bob_leaf, &charlie_leaf);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.claim_airdrop(300, vec![charlie_leaf]),
+                Err(DemoError::AirdropUnset)
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_airdrop_root(Some(root)), Ok(()));
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(400);
+            contract.fund_airdrop();
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+            assert_eq!(contract.airdrop_pool(), 400);
+            assert_eq!(contract.liabilities_of(Asset::Native), 400);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.claim_airdrop(300, vec![charlie_leaf]),
+                Err(DemoError::IdentityNotRegistered)
+            );
+            let _ = contract.register_identity((Platform::GitHub, [0x01; 32]));
+            assert_eq!(
+                contract.claim_airdrop(1_000, vec![charlie_leaf]),
+                Err(DemoError::InvalidProof)
+            );
+            assert_eq!(contract.claim_airdrop(300, vec![charlie_leaf]), Ok(()));
+            assert_eq!(get_balance(accounts.bob), 300);
+            assert_eq!(contract.airdrop_pool(), 100);
+            assert!(contract.is_airdrop_claimed([0x01; 32]));
+            assert_eq!(
+                contract.claim_airdrop(300, vec![charlie_leaf]),
+                Err(DemoError::AirdropAlreadyClaimed)
+            );
+
+            // The airdrop is only paid from its own pool
+            set_next_caller(accounts.charlie);
+            let _ = contract.register_identity((Platform::GitHub, [0x02; 32]));
+            assert_eq!(
+                contract.claim_airdrop(200, vec![bob_leaf]),
+                Err(DemoError::InsufficientPool {
+                    needed: 200,
+                    available: 100
+                })
+            );
+
+            // Closing the airdrop returns its pool to the reward pool
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_airdrop_root(None), Ok(()));
+            assert_eq!(contract.airdrop_pool(), 0);
+            assert_eq!(contract.liabilities_of(Asset::Native), 0);
         }
-    }
 
-    #[cfg(test)]
-    mod tests {
-        /// Accounts
-        /// ALICE -> contract owner
-        /// BOB -> contributor
+        #[ink::test]
+        fn ledger_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            // The off-chain environment does not move the transferred value
+            set_balance(contract_id(), 1_000);
 
-        /// Imports all the definitions from the outer scope so we can use them here.
-        use super::*;
+            set_next_caller(accounts.alice);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000);
+            contract.fund();
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+            let _ = contract.set_reward(100);
+            let _ = contract.create_campaign(0, 10, 1_000);
+            let _ = contract.approve(1u64, accounts.bob);
+            advance_block();
+            set_next_caller(accounts.bob);
+            let _ = contract.claim(1u64);
 
-        use ink::env::test::EmittedEvent;
-        type Event = <Demo as ::ink::reflect::ContractEventBase>::Type;
+            assert_eq!(
+                contract.ledger_page(0, 10),
+                vec![
+                    (
+                        0,
+                        LedgerEntry {
+                            movement: Movement::Fund,
+                            amount: 1_000,
+                            balance: 1_000,
+                            block: 0,
+                        }
+                    ),
+                    (
+                        1,
+                        LedgerEntry {
+                            movement: Movement::Claim,
+                            amount: 100,
+                            balance: 900,
+                            block: 1,
+                        }
+                    ),
+                ]
+            );
+            assert_eq!(contract.ledger_page(1, 10).len(), 1);
+            assert_eq!(contract.ledger_page(0, 1).len(), 1);
+            assert!(contract.ledger_page(2, 10).is_empty());
+        }
 
-        /// We test if the constructor does its job.
         #[ink::test]
-        fn new_works() {
-            create_contract();
+        fn check_many_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1u64, accounts.bob);
+            let _ = contract.approve(2u64, accounts.charlie);
+
+            set_next_caller(accounts.bob);
+            let views = contract.check_many(vec![1u64, 2u64, 3u64]);
+            assert_eq!(
+                views
+                    .iter()
+                    .map(|(id, view)| (*id, view.status, view.is_contributor))
+                    .collect::<Vec<_>>(),
+                vec![
+                    (1u64, ContributionStatus::Approved, true),
+                    (2u64, ContributionStatus::Approved, false),
+                    (3u64, ContributionStatus::Unknown, false),
+                ]
+            );
+            assert_eq!(views[0].1, contract.check_detailed(1u64));
+
+            let ids = (0..u64::from(MAX_PAGE_SIZE) + 1).collect::<Vec<_>>();
+            assert_eq!(contract.check_many(ids).len(), MAX_PAGE_SIZE as usize);
         }
 
         #[ink::test]
-        fn approve_works() {
+        fn read_raw_works() {
+            use scale::Encode;
+
+            let contract = create_contract();
+            let key = vec![0x01, 0x02, 0x03];
+            ink::env::set_contract_storage(&RawBytes(key.clone()), &42u32);
+
+            assert_eq!(
+                contract.read_raw(vec![key, vec![0x04]]),
+                vec![Some(42u32.encode()), None]
+            );
+            let keys = (0..MAX_PAGE_SIZE + 1)
+                .map(|index| index.encode())
+                .collect::<Vec<_>>();
+            assert_eq!(contract.read_raw(keys).len(), MAX_PAGE_SIZE as usize);
+        }
+
+        #[ink::test]
+        fn check_detailed_works() {
             let accounts = default_accounts();
             let mut contract = create_contract();
             let contribution_id = 1u64;
+            set_balance(contract_id(), 1_000);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.check_detailed(contribution_id),
+                ContributionView {
+                    status: ContributionStatus::Unknown,
+                    is_contributor: false,
+                    reward: 0,
+                    claimable_at: None,
+                    is_claimed: false,
+                }
+            );
 
             set_next_caller(accounts.alice);
-            assert_eq!(contract.approve(contribution_id, accounts.bob), Ok(()));
+            let _ = contract.set_reward(100);
+            let _ = contract.create_campaign(0, 10, 1_000);
+            advance_block();
+            let _ = contract.approve(contribution_id, accounts.bob);
+            assert_eq!(
+                contract.check_detailed(contribution_id),
+                ContributionView {
+                    status: ContributionStatus::Approved,
+                    is_contributor: false,
+                    reward: 0,
+                    claimable_at: None,
+                    is_claimed: false,
+                }
+            );
 
-            // Validate `ContributionApproval` event emition
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.check_detailed(contribution_id),
+                ContributionView {
+                    status: ContributionStatus::Approved,
+                    is_contributor: true,
+                    reward: 100,
+                    claimable_at: Some(1),
+                    is_claimed: false,
+                }
+            );
+
+            let _ = contract.claim(contribution_id);
+            assert_eq!(
+                contract.check_detailed(contribution_id),
+                ContributionView {
+                    status: ContributionStatus::Claimed,
+                    is_contributor: true,
+                    reward: 100,
+                    claimable_at: Some(1),
+                    is_claimed: true,
+                }
+            );
+        }
+
+        #[ink::test]
+        fn contribution_uri_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let base_uri = BoundedString::try_from("ipfs://bafybeigdyrzt/").unwrap();
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1u64, accounts.bob);
+            assert_eq!(contract.contribution_uri(1u64), "");
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_metadata_base_uri(base_uri.clone()),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_metadata_base_uri(base_uri), Ok(()));
+            assert_eq!(contract.contribution_uri(1u64), "ipfs://bafybeigdyrzt/1");
+            assert_eq!(contract.contribution_uri(2u64), "");
+
+            // Validate `MetadataBaseUriUpdated` event emition
             let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
-            assert_eq!(1, emitted_events.len());
             let decoded_events = decode_events(emitted_events);
-            let Event::ContributionApproval(ContributionApproval { id, contributor }) =
-                decoded_events[0];
-            assert_eq!(id, contribution_id);
-            assert_eq!(contributor, accounts.bob);
+            let Some(Event::MetadataBaseUriUpdated(MetadataBaseUriUpdated { base_uri, .. })) =
+                decoded_events.last()
+            else {
+                panic!("encountered unexpected event kind: expected a MetadataBaseUriUpdated event")
+            };
+            assert_eq!(base_uri, "ipfs://bafybeigdyrzt/");
+        }
 
-            let maybe_contributor = contract.contributions.get(contribution_id);
-            assert_eq!(maybe_contributor, Some(accounts.bob));
+        #[ink::test]
+        fn contribution_view_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+
+            assert_eq!(contract.contribution_view(1u64), None);
+
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity((Platform::GitHub, [0x01; 32]));
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reward(100);
+            let _ = contract.create_campaign(0, 10, 1_000);
+            let _ = contract.approve(1u64, accounts.bob);
+            set_next_caller(accounts.bob);
+            let _ = contract.claim_partial(1u64, 40);
 
-            // Approve it again returns an error
             assert_eq!(
-                contract.approve(contribution_id, accounts.alice),
-                Err(DemoError::ContributionAlreadyApproved)
+                contract.contribution_view(1u64),
+                Some(ContributionDetails {
+                    id: 1u64,
+                    status: ContributionStatus::Approved,
+                    contributor: accounts.bob,
+                    contributor_id: Some((Platform::GitHub, [0x01; 32])),
+                    reward: 100,
+                    asset: Asset::Native,
+                    claimed: 40,
+                    splits: vec![Split {
+                        account: accounts.bob,
+                        bps: MAX_BPS,
+                        is_claimed: false,
+                    }],
+                    approved_at: 0,
+                    deadline: None,
+                    tags: Vec::new(),
+                })
             );
         }
 
         #[ink::test]
-        fn only_contract_owner_can_approve() {
+        fn identity_view_works() {
             let accounts = default_accounts();
             let mut contract = create_contract();
-            let contribution_id = 1u64;
 
-            set_next_caller(accounts.bob);
             assert_eq!(
-                contract.approve(contribution_id, accounts.alice),
-                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+                contract.identity_view(accounts.bob),
+                IdentityView {
+                    account: accounts.bob,
+                    contributor_id: None,
+                    profile: None,
+                    did: None,
+                    approvals: 0,
+                    points: 0,
+                    reputation: 0,
+                    streak: 0,
+                    payout_address: accounts.bob,
+                }
             );
+
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity((Platform::GitHub, [0x01; 32]));
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1u64, accounts.bob);
+            let view = contract.identity_view(accounts.bob);
+            assert_eq!(view.contributor_id, Some((Platform::GitHub, [0x01; 32])));
+            assert_eq!(view.approvals, 1);
+            assert_eq!(view.points, POINTS_PER_APPROVAL);
+            assert_eq!(view.reputation, POINTS_PER_APPROVAL * REPUTATION_PRECISION);
         }
 
         #[ink::test]
-        fn already_approved_contribution_fails() {
+        fn error_message_works() {
             let accounts = default_accounts();
             let mut contract = create_contract();
-            let contribution_id = 1u64;
+            use scale::Encode;
+            let message = |text: &str| Some(BoundedString::try_from(text).unwrap());
+            let code = DemoError::AlreadyClaimed.encode()[0] as u32;
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_error_message(code, *b"en", message("Already claimed")),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
 
             set_next_caller(accounts.alice);
-            let _ = contract.approve(contribution_id, accounts.alice);
+            assert_eq!(
+                contract.set_error_message(code, *b"en", message("Already claimed")),
+                Ok(())
+            );
+            assert_eq!(
+                contract.set_error_message(code, *b"fr", message("Déjà réclamé")),
+                Ok(())
+            );
+            assert_eq!(
+                contract.error_message(code, *b"en"),
+                Some(String::from("Already claimed"))
+            );
+            assert_eq!(
+                contract.error_message(code, *b"fr"),
+                Some(String::from("Déjà réclamé"))
+            );
+            assert_eq!(contract.error_message(code, *b"de"), None);
+
+            // Validate `ErrorMessageUpdated` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Some(Event::ErrorMessageUpdated(ErrorMessageUpdated {
+                code: updated_code,
+                lang,
+                ..
+            })) = decoded_events.last()
+            else {
+                panic!("encountered unexpected event kind: expected a ErrorMessageUpdated event")
+            };
+            assert_eq!(*updated_code, code);
+            assert_eq!(*lang, *b"fr");
+
+            assert_eq!(contract.set_error_message(code, *b"fr", None), Ok(()));
+            assert_eq!(contract.error_message(code, *b"fr"), None);
 
+            // The table is bounded
+            for index in 1..MAX_ERROR_MESSAGES {
+                assert_eq!(
+                    contract.set_error_message(1_000 + index, *b"en", message("Failure")),
+                    Ok(())
+                );
+            }
             assert_eq!(
-                contract.approve(contribution_id, accounts.alice),
-                Err(DemoError::ContributionAlreadyApproved)
+                contract.set_error_message(code, *b"fr", message("Déjà réclamé")),
+                Err(DemoError::TooManyErrorMessages)
+            );
+            // Replacing a message leaves room
+            assert_eq!(
+                contract.set_error_message(code, *b"en", message("Nothing left to claim")),
+                Ok(())
             );
         }
 
         #[ink::test]
-        fn contributor_getter_works() {
+        fn link_did_works() {
             let accounts = default_accounts();
             let mut contract = create_contract();
-            let contribution_id = 1u64;
+            let did = |uri: &str| BoundedString::try_from(String::from(uri)).unwrap();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.link_did(did("did:kilt:4abc")),
+                Err(DemoError::IdentityNotRegistered)
+            );
+            let _ = contract.register_identity((Platform::GitHub, [0x01; 32]));
+            for uri in ["", "kilt:4abc", "did:kilt", "did::4abc", "did:kilt:"] {
+                assert_eq!(contract.link_did(did(uri)), Err(DemoError::InvalidDid));
+            }
+            assert_eq!(contract.link_did(did("did:kilt:4abc")), Ok(()));
+            assert_eq!(
+                contract.did_of(accounts.bob),
+                Some(Did {
+                    uri: did("did:kilt:4abc"),
+                    is_verified: false,
+                })
+            );
+
+            // Validate `DidLinked` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            let Some(Event::DidLinked(DidLinked { account, uri, .. })) = decoded_events.last()
+            else {
+                panic!("encountered unexpected event kind: expected a DidLinked event")
+            };
+            assert_eq!(*account, accounts.bob);
+            assert_eq!(uri, "did:kilt:4abc");
+
+            assert_eq!(
+                contract.verify_did(accounts.bob, did("did:kilt:4abc")),
+                Err(DemoError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
 
+            // The owner attests the linked DID only
             set_next_caller(accounts.alice);
-            let _ = contract.approve(contribution_id, accounts.bob);
+            assert_eq!(
+                contract.verify_did(accounts.bob, did("did:kilt:4xyz")),
+                Err(DemoError::InvalidDid)
+            );
+            assert_eq!(
+                contract.verify_did(accounts.bob, did("did:kilt:4abc")),
+                Ok(())
+            );
+            assert_eq!(
+                contract.identity_view(accounts.bob).did,
+                Some(Did {
+                    uri: did("did:kilt:4abc"),
+                    is_verified: true,
+                })
+            );
 
+            // Linking another DID drops the attestation
+            set_next_caller(accounts.bob);
+            let _ = contract.link_did(did("did:kilt:4xyz"));
             assert_eq!(
-                contract.get_contributor(contribution_id),
-                Some(accounts.bob)
+                contract.did_of(accounts.bob).map(|did| did.is_verified),
+                Some(false)
             );
-            assert_eq!(contract.get_contributor(2u64), None);
         }
 
-        fn default_accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
-            ink::env::test::default_accounts::<Environment>()
+        #[ink::test]
+        fn claim_unknown_contribution_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1u64), Err(DemoError::UnknownContribution));
         }
 
-        fn set_next_caller(caller: AccountId) {
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
+        #[ink::test]
+        fn only_contributor_can_claim() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let contribution_id = 1u64;
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(contribution_id, accounts.bob);
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.claim(contribution_id),
+                Err(DemoError::CallerIsNotContributor)
+            );
         }
 
         /// Creates a new instance of `Demo`.
         ///
         /// Returns the `contract_instance`.
         fn create_contract() -> Demo {
-            let accounts = default_accounts();
-            set_next_caller(accounts.alice);
-            Demo::new()
+            test_utils::create_contract(Demo::new)
         }
 
-        fn decode_events(emittend_events: Vec<EmittedEvent>) -> Vec<Event> {
-            emittend_events
-                .into_iter()
-                .map(|event| {
-                    <Event as scale::Decode>::decode(&mut &event.data[..]).expect("invalid data")
-                })
-                .collect()
+        fn decode_events(emitted_events: Vec<EmittedEvent>) -> Vec<Event> {
+            test_utils::decode_events(emitted_events)
         }
     }
 }
@@ -0,0 +1,79 @@
+//! Integer math helpers for the reward curves.
+
+/// Returns the integer square root of `n`, rounded down.
+pub fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method, starting above the root so that the iterates decrease
+    let mut x = n;
+    let mut y = x / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+/// Returns `a * b / c` rounded down, saturating on overflow and returning 0 when `c` is 0.
+pub fn mul_div(a: u128, b: u128, c: u128) -> u128 {
+    if c == 0 {
+        return 0;
+    }
+    a.checked_mul(b)
+        .map(|product| product / c)
+        .unwrap_or_else(|| (a / c).saturating_mul(b))
+}
+
+/// Returns `value` decayed over `elapsed` time, halving every `half_life`.
+///
+/// Within a half-life, the decay is linearly interpolated between two halvings.
+pub fn decay(value: u128, elapsed: u128, half_life: u128) -> u128 {
+    if half_life == 0 {
+        return value;
+    }
+    let halvings = elapsed / half_life;
+    if halvings >= u128::BITS.into() {
+        return 0;
+    }
+    let halved = value >> halvings;
+    halved - mul_div(halved, elapsed % half_life, 2 * half_life)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn isqrt_works() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(1), 1);
+        assert_eq!(isqrt(2), 1);
+        assert_eq!(isqrt(3), 1);
+        assert_eq!(isqrt(4), 2);
+        assert_eq!(isqrt(99), 9);
+        assert_eq!(isqrt(100_000_000), 10_000);
+        assert_eq!(isqrt(u128::MAX), u64::MAX.into());
+    }
+
+    #[test]
+    fn mul_div_works() {
+        assert_eq!(mul_div(100, 2_500, 10_000), 25);
+        assert_eq!(mul_div(7, 3, 2), 10);
+        assert_eq!(mul_div(1, 1, 0), 0);
+        assert_eq!(mul_div(u128::MAX, 2, 4), u128::MAX / 4 * 2);
+    }
+
+    #[test]
+    fn decay_works() {
+        assert_eq!(decay(1_000, 0, 90), 1_000);
+        assert_eq!(decay(1_000, 45, 90), 750);
+        assert_eq!(decay(1_000, 90, 90), 500);
+        assert_eq!(decay(1_000, 135, 90), 375);
+        assert_eq!(decay(1_000, 180, 90), 250);
+        assert_eq!(decay(1_000, 90 * 10, 90), 0);
+        assert_eq!(decay(u128::MAX, 90 * 128, 90), 0);
+        assert_eq!(decay(u128::MAX, u128::MAX, 90), 0);
+        assert_eq!(decay(1_000, 1_000, 0), 1_000);
+    }
+}
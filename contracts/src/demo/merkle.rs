@@ -0,0 +1,54 @@
+//! Merkle proofs of allowlists posted as a single root.
+
+use crate::types::HashValue;
+use ink::env::hash::Blake2x256;
+
+/// Returns the Blake2x256 hash of a pair of nodes, sorted so that proofs need no directions.
+pub fn hash_pair(a: &HashValue, b: &HashValue) -> HashValue {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    let mut input = [0u8; 64];
+    input[..32].copy_from_slice(left);
+    input[32..].copy_from_slice(right);
+    let mut output = HashValue::default();
+    ink::env::hash_bytes::<Blake2x256>(&input, &mut output);
+    output
+}
+
+/// Returns `true` if `proof` proves that `leaf` is in the tree of a given `root`.
+pub fn verify(root: &HashValue, leaf: HashValue, proof: &[HashValue]) -> bool {
+    proof
+        .iter()
+        .fold(leaf, |node, sibling| hash_pair(&node, sibling))
+        == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_pair_is_sorted() {
+        assert_eq!(
+            hash_pair(&[0x01; 32], &[0x02; 32]),
+            hash_pair(&[0x02; 32], &[0x01; 32])
+        );
+        assert_ne!(
+            hash_pair(&[0x01; 32], &[0x02; 32]),
+            hash_pair(&[0x01; 32], &[0x03; 32])
+        );
+    }
+
+    #[test]
+    fn verify_works() {
+        let leaves = [[0x01; 32], [0x02; 32], [0x03; 32]];
+        let node = hash_pair(&leaves[0], &leaves[1]);
+        let root = hash_pair(&node, &leaves[2]);
+
+        assert!(verify(&root, leaves[0], &[leaves[1], leaves[2]]));
+        assert!(verify(&root, leaves[1], &[leaves[0], leaves[2]]));
+        assert!(verify(&root, leaves[2], &[node]));
+        assert!(!verify(&root, leaves[2], &[leaves[0]]));
+        assert!(!verify(&root, [0x04; 32], &[node]));
+        assert!(!verify(&root, leaves[0], &[]));
+    }
+}
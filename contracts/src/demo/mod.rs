@@ -1,2 +0,0 @@
-pub mod errors;
-pub mod types;
\ No newline at end of file
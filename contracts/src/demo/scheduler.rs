@@ -0,0 +1,51 @@
+//! A lazy scheduler of the delayed actions of the contract.
+//!
+//! Nothing is queued: the deadlines already stored tell when each action is due, and every
+//! deadline is checked through `Due::is_due` instead of an ad-hoc comparison.
+
+use openbrush::traits::{BlockNumber, Timestamp};
+
+/// The current block number and timestamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Now {
+    pub block: BlockNumber,
+    pub timestamp: Timestamp,
+}
+
+/// The moment a delayed action becomes due.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Due {
+    /// Due after the given block, the last block of a period.
+    AfterBlock(BlockNumber),
+    /// Due from the given timestamp, the end of a timelock.
+    At(Timestamp),
+}
+
+impl Due {
+    /// Returns `true` if the action is due at `now`.
+    pub fn is_due(&self, now: Now) -> bool {
+        match *self {
+            Due::AfterBlock(block) => now.block > block,
+            Due::At(timestamp) => now.timestamp >= timestamp,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_due_works() {
+        let now = Now {
+            block: 10,
+            timestamp: 60_000,
+        };
+        // The last block of a period is not due yet
+        assert!(Due::AfterBlock(9).is_due(now));
+        assert!(!Due::AfterBlock(10).is_due(now));
+        // A timelock is over at its end
+        assert!(Due::At(60_000).is_due(now));
+        assert!(!Due::At(60_001).is_due(now));
+    }
+}
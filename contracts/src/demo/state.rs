@@ -0,0 +1,199 @@
+//! The lifecycle of a contribution as an explicit state machine.
+//!
+//! Every message changing the lifecycle of a contribution goes through `transition`, so a new
+//! status or action cannot silently reach a state the contract does not handle.
+
+use crate::errors::DemoError;
+
+/// The lifecycle status of a contribution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Nothing is recorded for the contribution.
+    Unknown,
+    /// The contribution is submitted by its contributor, pending its approval.
+    Submitted,
+    /// An approval of the contribution is proposed with a stake, until its challenge period
+    /// is over.
+    Proposed,
+    /// The proposed approval is disputed and awaits the decision of its arbiter.
+    Disputed,
+    /// The contribution is approved and some shares are left to claim.
+    Approved,
+    /// Every share of the contribution is claimed or expired.
+    Settled,
+    /// The settled contribution is archived: its id cannot be approved again.
+    Archived,
+}
+
+impl Status {
+    /// Every status, to enumerate the transitions.
+    pub const ALL: [Status; 7] = [
+        Status::Unknown,
+        Status::Submitted,
+        Status::Proposed,
+        Status::Disputed,
+        Status::Approved,
+        Status::Settled,
+        Status::Archived,
+    ];
+}
+
+/// An action changing the lifecycle of a contribution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// The contributor submits the contribution.
+    Submit,
+    /// The approver rejects the submission.
+    Reject,
+    /// The approver (or a redeemed voucher) approves the contribution.
+    Approve,
+    /// Anyone proposes an approval with a stake.
+    Propose,
+    /// The owner challenges the proposed approval, slashing its stake.
+    Challenge,
+    /// Anyone disputes the proposed approval.
+    Dispute,
+    /// The arbiter upholds the disputed approval.
+    Uphold,
+    /// The arbiter overturns the disputed approval, slashing its stake.
+    Overturn,
+    /// Anyone finalizes the unchallenged proposed approval.
+    Finalize,
+    /// A contributor claims a share, leaving other shares to claim.
+    Claim,
+    /// A contributor claims the last share left.
+    ClaimLast,
+    /// Anyone expires the shares left once the claim deadline is passed.
+    Expire,
+    /// The settled contribution is archived.
+    Archive,
+}
+
+impl Action {
+    /// Every action, to enumerate the transitions.
+    pub const ALL: [Action; 13] = [
+        Action::Submit,
+        Action::Reject,
+        Action::Approve,
+        Action::Propose,
+        Action::Challenge,
+        Action::Dispute,
+        Action::Uphold,
+        Action::Overturn,
+        Action::Finalize,
+        Action::Claim,
+        Action::ClaimLast,
+        Action::Expire,
+        Action::Archive,
+    ];
+}
+
+/// Returns the status a contribution in `status` reaches through `action`.
+pub fn transition(status: Status, action: Action) -> Result<Status, DemoError> {
+    match (status, action) {
+        (Status::Unknown, Action::Submit) => Ok(Status::Submitted),
+        (Status::Unknown | Status::Submitted, Action::Approve) => Ok(Status::Approved),
+        (Status::Unknown, Action::Propose) => Ok(Status::Proposed),
+        (Status::Submitted, Action::Reject) => Ok(Status::Unknown),
+        (Status::Proposed | Status::Disputed, Action::Challenge) => Ok(Status::Unknown),
+        (Status::Proposed, Action::Dispute) => Ok(Status::Disputed),
+        (Status::Proposed, Action::Finalize) => Ok(Status::Approved),
+        (Status::Disputed, Action::Uphold) => Ok(Status::Approved),
+        (Status::Disputed, Action::Overturn) => Ok(Status::Unknown),
+        (Status::Approved, Action::Claim) => Ok(Status::Approved),
+        (Status::Approved, Action::ClaimLast | Action::Expire) => Ok(Status::Settled),
+        (Status::Settled, Action::Archive) => Ok(Status::Archived),
+        _ => Err(DemoError::InvalidTransition),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the statuses reachable from `status`, including itself.
+    fn reachable_from(status: Status) -> Vec<Status> {
+        let mut reached = vec![status];
+        let mut index = 0;
+        while index < reached.len() {
+            for action in Action::ALL {
+                if let Ok(next) = transition(reached[index], action) {
+                    if !reached.contains(&next) {
+                        reached.push(next);
+                    }
+                }
+            }
+            index += 1;
+        }
+        reached
+    }
+
+    #[test]
+    fn transitions_are_exhaustive() {
+        let valid = [
+            (Status::Unknown, Action::Submit, Status::Submitted),
+            (Status::Unknown, Action::Approve, Status::Approved),
+            (Status::Unknown, Action::Propose, Status::Proposed),
+            (Status::Submitted, Action::Reject, Status::Unknown),
+            (Status::Submitted, Action::Approve, Status::Approved),
+            (Status::Proposed, Action::Challenge, Status::Unknown),
+            (Status::Proposed, Action::Dispute, Status::Disputed),
+            (Status::Proposed, Action::Finalize, Status::Approved),
+            (Status::Disputed, Action::Challenge, Status::Unknown),
+            (Status::Disputed, Action::Uphold, Status::Approved),
+            (Status::Disputed, Action::Overturn, Status::Unknown),
+            (Status::Approved, Action::Claim, Status::Approved),
+            (Status::Approved, Action::ClaimLast, Status::Settled),
+            (Status::Approved, Action::Expire, Status::Settled),
+            (Status::Settled, Action::Archive, Status::Archived),
+        ];
+        for status in Status::ALL {
+            for action in Action::ALL {
+                let expected = valid
+                    .iter()
+                    .find(|(from, by, _)| (*from, *by) == (status, action))
+                    .map_or(Err(DemoError::InvalidTransition), |(_, _, to)| Ok(*to));
+                assert_eq!(
+                    transition(status, action),
+                    expected,
+                    "{status:?} through {action:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn every_status_is_reachable() {
+        let reached = reachable_from(Status::Unknown);
+        for status in Status::ALL {
+            assert!(reached.contains(&status), "{status:?} is unreachable");
+        }
+    }
+
+    #[test]
+    fn every_status_ends_up_archived() {
+        for status in Status::ALL {
+            assert!(
+                reachable_from(status).contains(&Status::Archived),
+                "{status:?} cannot be archived"
+            );
+        }
+    }
+
+    #[test]
+    fn rewards_are_paid_once() {
+        for status in Status::ALL {
+            for action in [Action::Claim, Action::ClaimLast, Action::Expire] {
+                assert_eq!(
+                    transition(status, action).is_ok(),
+                    status == Status::Approved,
+                    "{status:?} through {action:?}"
+                );
+            }
+        }
+        // Once settled, a contribution is never approved nor paid again
+        for status in reachable_from(Status::Settled) {
+            assert!(matches!(status, Status::Settled | Status::Archived));
+        }
+    }
+}
@@ -1,3 +1 @@
-pub use ink::prelude::string::String;
-
-pub type ContributionId = u64;
\ No newline at end of file
+pub use traits::types::*;
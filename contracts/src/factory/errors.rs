@@ -0,0 +1,34 @@
+use openbrush::contracts::traits::ownable::OwnableError;
+
+/// Errors that can occur upon calling this contract.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum FactoryError {
+    OwnableError(OwnableError),
+    /// A `Demo` is already deployed for this repository.
+    DemoAlreadyExists,
+    /// The `Demo` instantiation failed.
+    InstantiationFailed,
+    /// No `Demo` is deployed for this repository.
+    UnknownProject,
+    /// The funding transfer failed.
+    TransferFailed,
+    /// The drip amount is zero or exceeds the deposit.
+    InvalidSponsorship,
+    /// The caller already sponsors the project.
+    SponsorshipAlreadyExists,
+    /// The caller does not sponsor the project.
+    UnknownSponsorship,
+    /// The maximum number of sponsors of the project is reached.
+    TooManySponsors,
+    /// The project name or repository URL is empty, or the logo URI is empty.
+    InvalidMetadata,
+    /// The caller is not the owner of the `Demo` of the project.
+    CallerIsNotProjectOwner,
+}
+
+impl From<OwnableError> for FactoryError {
+    fn from(error: OwnableError) -> Self {
+        FactoryError::OwnableError(error)
+    }
+}
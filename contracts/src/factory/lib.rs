@@ -0,0 +1,574 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+pub mod errors;
+
+#[openbrush::implementation(Ownable)]
+#[openbrush::contract]
+pub mod factory {
+    use super::errors::FactoryError;
+    use demo::demo::DemoRef;
+    use demo::types::{BoundedString, MAX_URI_LENGTH};
+    use ink::{
+        codegen::TraitCallBuilder, env::call::FromAccountId, prelude::vec::Vec, storage::Mapping,
+        ToAccountId,
+    };
+    use openbrush::{contracts::traits::ownable::Ownable as _, modifiers, traits::Storage};
+
+    /// The hash identifying a GitHub repository (e.g. `blake2("kudos-ink/demo")`).
+    pub type RepoHash = Hash;
+
+    /// The length of a sponsorship epoch: about 7 days of 6s blocks.
+    pub const EPOCH_LENGTH: BlockNumber = 7 * 24 * 60 * 10;
+
+    /// The maximum number of sponsors of a project.
+    pub const MAX_SPONSORS: usize = 10;
+
+    /// The maximum length of a project name, in bytes.
+    pub const MAX_PROJECT_NAME_LENGTH: usize = 64;
+
+    /// A recurring funding of a project, dripped from a deposit every epoch.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Sponsorship {
+        /// The part of the deposit not dripped yet.
+        pub deposit: Balance,
+        /// The amount credited to the project budget each epoch.
+        pub drip: Balance,
+        /// The last epoch the sponsorship was dripped at.
+        pub last_epoch: BlockNumber,
+    }
+
+    /// The metadata of a project, rendered on its card by the UI.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct ProjectMetadata {
+        /// The name of the project.
+        pub name: BoundedString<MAX_PROJECT_NAME_LENGTH>,
+        /// The URL of the repository.
+        pub repo_url: BoundedString<MAX_URI_LENGTH>,
+        /// The URI of the logo image.
+        pub logo_uri: Option<BoundedString<MAX_URI_LENGTH>>,
+    }
+
+    /// A project with its `Demo`, budget and metadata.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ProjectInfo {
+        /// The `Demo` deployed for the repository.
+        pub demo: AccountId,
+        /// The total budget funded to the `Demo`.
+        pub budget: Balance,
+        /// The metadata set by the project owner, if any.
+        pub metadata: Option<ProjectMetadata>,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Factory {
+        // The field to save the owner of the contract
+        #[storage_field]
+        ownable: ownable::Data,
+
+        // The code hash of the `Demo` contract to instantiate.
+        demo_code_hash: Hash,
+
+        // The deployed `Demo` instance of each repository.
+        demos: Mapping<RepoHash, AccountId>,
+
+        // The total budget funded to the `Demo` of each repository.
+        budgets: Mapping<RepoHash, Balance>,
+
+        // The sponsorship of each (repository, sponsor).
+        sponsorships: Mapping<(RepoHash, AccountId), Sponsorship>,
+
+        // The sponsors of each repository.
+        sponsors: Mapping<RepoHash, Vec<AccountId>>,
+
+        // The metadata of each repository.
+        metadata: Mapping<RepoHash, ProjectMetadata>,
+    }
+
+    /// Emitted when a `Demo` is instantiated for a repository.
+    #[ink(event)]
+    pub struct DemoCreated {
+        #[ink(topic)]
+        repo_hash: RepoHash,
+        demo: AccountId,
+    }
+
+    /// Emitted when the `Demo` of a repository is funded.
+    #[ink(event)]
+    pub struct ProjectFunded {
+        #[ink(topic)]
+        repo_hash: RepoHash,
+        amount: Balance,
+    }
+
+    /// Emitted when a sponsor starts dripping a deposit to the `Demo` of a repository.
+    #[ink(event)]
+    pub struct ProjectSponsored {
+        #[ink(topic)]
+        repo_hash: RepoHash,
+        sponsor: AccountId,
+        deposit: Balance,
+        drip: Balance,
+    }
+
+    /// Emitted when the metadata of a project is set.
+    #[ink(event)]
+    pub struct ProjectMetadataUpdated {
+        #[ink(topic)]
+        repo_hash: RepoHash,
+    }
+
+    /// Emitted when a sponsorship is cancelled and its undripped remainder refunded.
+    #[ink(event)]
+    pub struct SponsorshipCancelled {
+        #[ink(topic)]
+        repo_hash: RepoHash,
+        sponsor: AccountId,
+        refund: Balance,
+    }
+
+    impl Factory {
+        /// Constructor that initializes the factory with the uploaded `Demo` code hash.
+        #[ink(constructor)]
+        pub fn new(demo_code_hash: Hash) -> Self {
+            let mut instance = Self::default();
+            ownable::Internal::_init_with_owner(&mut instance, Self::env().caller());
+            instance.demo_code_hash = demo_code_hash;
+            instance
+        }
+
+        /// Instantiate a fresh `Demo` for the given repository.
+        ///
+        /// Its events are indexed by the repository hash, and its ownership is transferred to
+        /// the caller.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn create_demo(&mut self, repo_hash: RepoHash) -> Result<AccountId, FactoryError> {
+            if self.demos.contains(repo_hash) {
+                return Err(FactoryError::DemoAlreadyExists);
+            }
+
+            let mut demo = DemoRef::new()
+                .code_hash(self.demo_code_hash)
+                .endowment(0)
+                .salt_bytes(repo_hash)
+                .try_instantiate()
+                .map_err(|_| FactoryError::InstantiationFailed)?
+                .map_err(|_| FactoryError::InstantiationFailed)?;
+            demo.set_project_id(repo_hash)
+                .map_err(|_| FactoryError::InstantiationFailed)?;
+            // The caller signs this very call, so the checked handover through a claimed
+            // intent, impossible before the deployment, is not needed
+            demo.transfer_ownership(Some(self.env().caller()))
+                .map_err(|_| FactoryError::InstantiationFailed)?;
+
+            let address = demo.to_account_id();
+            self.demos.insert(repo_hash, &address);
+            self.env().emit_event(DemoCreated {
+                repo_hash,
+                demo: address,
+            });
+            Ok(address)
+        }
+
+        /// Fund the reward budget of the `Demo` of a given repository with the transferred value.
+        ///
+        /// Each `Demo` pays its rewards from its own balance only, so a project never dips into
+        /// the budget of another one. The value goes through its payable `fund` message, so the
+        /// `Demo` ledger records it.
+        #[ink(message, payable)]
+        pub fn fund_project(&mut self, repo_hash: RepoHash) -> Result<(), FactoryError> {
+            let demo = self
+                .demo_of(repo_hash)
+                .ok_or(FactoryError::UnknownProject)?;
+            let amount = self.env().transferred_value();
+            self.fund_demo(demo, amount)?;
+            self.budgets
+                .insert(repo_hash, &self.budget_of(repo_hash).saturating_add(amount));
+            self.env().emit_event(ProjectFunded { repo_hash, amount });
+            Ok(())
+        }
+
+        /// Sponsor the `Demo` of a given repository with the transferred value, credited to its
+        /// budget by `drip` every epoch.
+        ///
+        /// The drips are paid lazily, whenever the sponsorships of the project are accessed.
+        #[ink(message, payable)]
+        pub fn sponsor(&mut self, repo_hash: RepoHash, drip: Balance) -> Result<(), FactoryError> {
+            if !self.demos.contains(repo_hash) {
+                return Err(FactoryError::UnknownProject);
+            }
+            let sponsor = self.env().caller();
+            let deposit = self.env().transferred_value();
+            if drip == 0 || drip > deposit {
+                return Err(FactoryError::InvalidSponsorship);
+            }
+            self._drip(repo_hash)?;
+            if self.sponsorships.contains((repo_hash, sponsor)) {
+                return Err(FactoryError::SponsorshipAlreadyExists);
+            }
+            let mut sponsors = self.sponsors_of(repo_hash);
+            if sponsors.len() >= MAX_SPONSORS {
+                return Err(FactoryError::TooManySponsors);
+            }
+            sponsors.push(sponsor);
+            self.sponsors.insert(repo_hash, &sponsors);
+            self.sponsorships.insert(
+                (repo_hash, sponsor),
+                &Sponsorship {
+                    deposit,
+                    drip,
+                    last_epoch: self.current_epoch(),
+                },
+            );
+            self.env().emit_event(ProjectSponsored {
+                repo_hash,
+                sponsor,
+                deposit,
+                drip,
+            });
+            Ok(())
+        }
+
+        /// Credit the drips due to the `Demo` of a given repository by its sponsors.
+        #[ink(message)]
+        pub fn drip(&mut self, repo_hash: RepoHash) -> Result<(), FactoryError> {
+            self._drip(repo_hash)
+        }
+
+        /// Cancel the caller's sponsorship of a given repository, refunding the undripped
+        /// remainder of the deposit once the due drips are credited.
+        ///
+        /// Returns the refunded amount.
+        #[ink(message)]
+        pub fn cancel_sponsorship(&mut self, repo_hash: RepoHash) -> Result<Balance, FactoryError> {
+            let sponsor = self.env().caller();
+            self._drip(repo_hash)?;
+            let sponsorship = self
+                .sponsorships
+                .get((repo_hash, sponsor))
+                .ok_or(FactoryError::UnknownSponsorship)?;
+            self.remove_sponsorship(repo_hash, sponsor);
+            self.env()
+                .transfer(sponsor, sponsorship.deposit)
+                .map_err(|_| FactoryError::TransferFailed)?;
+            self.env().emit_event(SponsorshipCancelled {
+                repo_hash,
+                sponsor,
+                refund: sponsorship.deposit,
+            });
+            Ok(sponsorship.deposit)
+        }
+
+        /// Simply returns the sponsorship of a given repository by a given sponsor, as of its
+        /// last drip.
+        #[ink(message)]
+        pub fn sponsor_info(&self, repo_hash: RepoHash, sponsor: AccountId) -> Option<Sponsorship> {
+            self.sponsorships.get((repo_hash, sponsor))
+        }
+
+        /// Simply returns the sponsors of a given repository.
+        #[ink(message)]
+        pub fn sponsors_of(&self, repo_hash: RepoHash) -> Vec<AccountId> {
+            self.sponsors.get(repo_hash).unwrap_or_default()
+        }
+
+        /// Simply returns the total budget funded to the `Demo` of a given repository.
+        #[ink(message)]
+        pub fn budget_of(&self, repo_hash: RepoHash) -> Balance {
+            self.budgets.get(repo_hash).unwrap_or_default()
+        }
+
+        /// Set the metadata of a given repository.
+        ///
+        /// Only the owner of its `Demo` can set it.
+        #[ink(message)]
+        pub fn set_project_metadata(
+            &mut self,
+            repo_hash: RepoHash,
+            metadata: ProjectMetadata,
+        ) -> Result<(), FactoryError> {
+            let demo = self
+                .demo_of(repo_hash)
+                .ok_or(FactoryError::UnknownProject)?;
+            if metadata.name.is_empty()
+                || metadata.repo_url.is_empty()
+                || metadata
+                    .logo_uri
+                    .as_ref()
+                    .map_or(false, |uri| uri.is_empty())
+            {
+                return Err(FactoryError::InvalidMetadata);
+            }
+            let demo: DemoRef = FromAccountId::from_account_id(demo);
+            if demo.owner() != Some(self.env().caller()) {
+                return Err(FactoryError::CallerIsNotProjectOwner);
+            }
+            self.metadata.insert(repo_hash, &metadata);
+            self.env().emit_event(ProjectMetadataUpdated { repo_hash });
+            Ok(())
+        }
+
+        /// Returns the `Demo`, budget and metadata of a given repository, so the UI renders
+        /// its card from chain data only.
+        #[ink(message)]
+        pub fn project_info(&self, repo_hash: RepoHash) -> Option<ProjectInfo> {
+            Some(ProjectInfo {
+                demo: self.demo_of(repo_hash)?,
+                budget: self.budget_of(repo_hash),
+                metadata: self.metadata.get(repo_hash),
+            })
+        }
+
+        /// Simply returns the `AccountId` of the `Demo` deployed for a given repository.
+        #[ink(message)]
+        pub fn demo_of(&self, repo_hash: RepoHash) -> Option<AccountId> {
+            self.demos.get(repo_hash)
+        }
+
+        /// Returns the current sponsorship epoch.
+        fn current_epoch(&self) -> BlockNumber {
+            self.env().block_number() / EPOCH_LENGTH
+        }
+
+        /// Transfers the drips due by the sponsors of a repository to its `Demo`, removing the
+        /// exhausted sponsorships.
+        fn _drip(&mut self, repo_hash: RepoHash) -> Result<(), FactoryError> {
+            let Some(demo) = self.demo_of(repo_hash) else {
+                return Ok(());
+            };
+            let epoch = self.current_epoch();
+            let mut amount: Balance = 0;
+            for sponsor in self.sponsors_of(repo_hash) {
+                let Some(mut sponsorship) = self.sponsorships.get((repo_hash, sponsor)) else {
+                    continue;
+                };
+                let epochs = epoch.saturating_sub(sponsorship.last_epoch);
+                let dripped = sponsorship
+                    .drip
+                    .saturating_mul(epochs.into())
+                    .min(sponsorship.deposit);
+                sponsorship.deposit -= dripped;
+                sponsorship.last_epoch = epoch;
+                amount = amount.saturating_add(dripped);
+                if sponsorship.deposit == 0 {
+                    self.remove_sponsorship(repo_hash, sponsor);
+                } else {
+                    self.sponsorships.insert((repo_hash, sponsor), &sponsorship);
+                }
+            }
+            if amount == 0 {
+                return Ok(());
+            }
+            self.fund_demo(demo, amount)?;
+            self.budgets
+                .insert(repo_hash, &self.budget_of(repo_hash).saturating_add(amount));
+            self.env().emit_event(ProjectFunded { repo_hash, amount });
+            Ok(())
+        }
+
+        /// Transfers `amount` to a `Demo` through its payable `fund` message.
+        fn fund_demo(&mut self, demo: AccountId, amount: Balance) -> Result<(), FactoryError> {
+            let mut demo: DemoRef = FromAccountId::from_account_id(demo);
+            match demo
+                .call_mut()
+                .fund()
+                .transferred_value(amount)
+                .try_invoke()
+            {
+                Ok(Ok(())) => Ok(()),
+                _ => Err(FactoryError::TransferFailed),
+            }
+        }
+
+        /// Removes the sponsorship of a repository by `sponsor`.
+        fn remove_sponsorship(&mut self, repo_hash: RepoHash, sponsor: AccountId) {
+            self.sponsorships.remove((repo_hash, sponsor));
+            let mut sponsors = self.sponsors_of(repo_hash);
+            sponsors.retain(|account| *account != sponsor);
+            self.sponsors.insert(repo_hash, &sponsors);
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        /// Accounts
+        /// ALICE -> contract owner
+        /// BOB -> non owner
+
+        /// Imports all the definitions from the outer scope so we can use them here.
+        use super::*;
+        use traits::test_utils::{
+            self, contract_id, default_accounts, get_balance, set_balance, set_next_caller,
+        };
+
+        /// We test if the constructor does its job.
+        #[ink::test]
+        fn new_works() {
+            let contract = create_contract();
+            assert_eq!(contract.demo_code_hash, Hash::from([0x01; 32]));
+        }
+
+        #[ink::test]
+        fn only_contract_owner_can_create_demo() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.create_demo(Hash::from([0x02; 32])),
+                Err(FactoryError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn already_created_demo_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let repo_hash = Hash::from([0x02; 32]);
+            contract.demos.insert(repo_hash, &accounts.django);
+
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.create_demo(repo_hash),
+                Err(FactoryError::DemoAlreadyExists)
+            );
+        }
+
+        #[ink::test]
+        fn demo_getter_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let repo_hash = Hash::from([0x02; 32]);
+            contract.demos.insert(repo_hash, &accounts.django);
+
+            assert_eq!(contract.demo_of(repo_hash), Some(accounts.django));
+            assert_eq!(contract.demo_of(Hash::from([0x03; 32])), None);
+        }
+
+        #[ink::test]
+        fn fund_project_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let repo_hash = Hash::from([0x02; 32]);
+            assert_eq!(
+                contract.fund_project(repo_hash),
+                Err(FactoryError::UnknownProject)
+            );
+            assert_eq!(contract.budget_of(repo_hash), 0);
+            // The value reaches the `Demo` by a cross-contract call to its `fund` message, which
+            // the off-chain environment does not support
+        }
+
+        #[ink::test]
+        fn sponsor_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let repo_hash = Hash::from([0x02; 32]);
+            contract.demos.insert(repo_hash, &accounts.django);
+            set_balance(accounts.bob, 0);
+            // The off-chain environment does not move the transferred value
+            set_balance(contract_id(), 1_000);
+
+            set_next_caller(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+            assert_eq!(
+                contract.sponsor(repo_hash, 101),
+                Err(FactoryError::InvalidSponsorship)
+            );
+            assert_eq!(contract.sponsor(repo_hash, 30), Ok(()));
+            assert_eq!(
+                contract.sponsor(repo_hash, 30),
+                Err(FactoryError::SponsorshipAlreadyExists)
+            );
+            assert_eq!(contract.sponsors_of(repo_hash), vec![accounts.bob]);
+
+            // Nothing is dripped within the first epoch. The later drips reach the `Demo` by a
+            // cross-contract call to its `fund` message, which the off-chain environment does
+            // not support
+            assert_eq!(contract.drip(repo_hash), Ok(()));
+            assert_eq!(contract.budget_of(repo_hash), 0);
+            assert_eq!(
+                contract.sponsor_info(repo_hash, accounts.bob),
+                Some(Sponsorship {
+                    deposit: 100,
+                    drip: 30,
+                    last_epoch: 0,
+                })
+            );
+
+            // The undripped remainder is refunded on cancellation
+            assert_eq!(contract.cancel_sponsorship(repo_hash), Ok(100));
+            assert_eq!(get_balance(accounts.bob), 100);
+            assert_eq!(contract.sponsor_info(repo_hash, accounts.bob), None);
+            assert!(contract.sponsors_of(repo_hash).is_empty());
+            assert_eq!(
+                contract.cancel_sponsorship(repo_hash),
+                Err(FactoryError::UnknownSponsorship)
+            );
+        }
+
+        #[ink::test]
+        fn project_info_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            let repo_hash = Hash::from([0x02; 32]);
+            let metadata = ProjectMetadata {
+                name: BoundedString::try_from("Kudos Ink!").unwrap(),
+                repo_url: BoundedString::try_from("https://github.com/kudos-ink/demo").unwrap(),
+                logo_uri: None,
+            };
+            assert_eq!(
+                contract.set_project_metadata(repo_hash, metadata.clone()),
+                Err(FactoryError::UnknownProject)
+            );
+            assert_eq!(contract.project_info(repo_hash), None);
+
+            contract.demos.insert(repo_hash, &accounts.django);
+            assert_eq!(
+                contract.set_project_metadata(
+                    repo_hash,
+                    ProjectMetadata {
+                        name: BoundedString::default(),
+                        ..metadata.clone()
+                    }
+                ),
+                Err(FactoryError::InvalidMetadata)
+            );
+            assert_eq!(
+                contract.project_info(repo_hash),
+                Some(ProjectInfo {
+                    demo: accounts.django,
+                    budget: 0,
+                    metadata: None,
+                })
+            );
+
+            // The owner of the `Demo` is checked by a cross-contract call, so the metadata is
+            // stored directly
+            contract.metadata.insert(repo_hash, &metadata);
+            assert_eq!(
+                contract
+                    .project_info(repo_hash)
+                    .and_then(|info| info.metadata),
+                Some(metadata)
+            );
+        }
+
+        /// Creates a new instance of `Factory`.
+        ///
+        /// Returns the `contract_instance`.
+        fn create_contract() -> Factory {
+            test_utils::create_contract(|| Factory::new(Hash::from([0x01; 32])))
+        }
+    }
+}
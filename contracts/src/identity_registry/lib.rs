@@ -0,0 +1,110 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[ink::contract]
+pub mod identity_registry {
+    use ink::storage::Mapping;
+    use traits::{errors::DemoError, identity::IdentityRegistry, types::ContributorId};
+
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct Registry {
+        // The account bound to each contributor identity, per platform.
+        identities: Mapping<ContributorId, AccountId>,
+        // The first contributor identity bound to each account.
+        accounts: Mapping<AccountId, ContributorId>,
+    }
+
+    /// Emitted when a contributor identity is bound to an account.
+    #[ink(event)]
+    pub struct IdentityRegistered {
+        contributor_id: ContributorId,
+        caller: AccountId,
+    }
+
+    impl Registry {
+        /// Constructor that initializes an empty registry.
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl IdentityRegistry for Registry {
+        /// Bind the caller's account to a contributor identity.
+        #[ink(message)]
+        fn register_identity(&mut self, contributor_id: ContributorId) -> Result<(), DemoError> {
+            if self.identities.contains(contributor_id) {
+                return Err(DemoError::IdentityAlreadyRegistered);
+            }
+
+            let caller = self.env().caller();
+            self.identities.insert(contributor_id, &caller);
+            if !self.accounts.contains(caller) {
+                self.accounts.insert(caller, &contributor_id);
+            }
+            self.env().emit_event(IdentityRegistered {
+                contributor_id,
+                caller,
+            });
+            Ok(())
+        }
+
+        /// Simply returns the `AccountId` bound to a given contributor identity.
+        #[ink(message)]
+        fn get_identity(&self, contributor_id: ContributorId) -> Option<AccountId> {
+            self.identities.get(contributor_id)
+        }
+
+        /// Simply returns the (first) contributor identity bound to a given account.
+        #[ink(message)]
+        fn get_contributor_id(&self, account: AccountId) -> Option<ContributorId> {
+            self.accounts.get(account)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        /// Accounts
+        /// BOB, CHARLIE -> contributors
+
+        /// Imports all the definitions from the outer scope so we can use them here.
+        use super::*;
+        use traits::test_utils::{default_accounts, set_next_caller};
+        use traits::types::Platform;
+
+        #[ink::test]
+        fn register_identity_works() {
+            let accounts = default_accounts();
+            let mut contract = Registry::new();
+            let contributor_id = (Platform::GitHub, [0x01; 32]);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(contributor_id), Ok(()));
+            assert_eq!(ink::env::test::recorded_events().count(), 1);
+            assert_eq!(contract.get_identity(contributor_id), Some(accounts.bob));
+            assert_eq!(contract.get_identity((Platform::GitLab, [0x01; 32])), None);
+            assert_eq!(
+                contract.get_contributor_id(accounts.bob),
+                Some(contributor_id)
+            );
+            assert_eq!(contract.get_contributor_id(accounts.charlie), None);
+        }
+
+        #[ink::test]
+        fn already_registered_identity_fails() {
+            let accounts = default_accounts();
+            let mut contract = Registry::new();
+            let contributor_id = (Platform::GitHub, [0x01; 32]);
+
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(contributor_id);
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.register_identity(contributor_id),
+                Err(DemoError::IdentityAlreadyRegistered)
+            );
+            assert_eq!(contract.get_identity(contributor_id), Some(accounts.bob));
+        }
+    }
+}
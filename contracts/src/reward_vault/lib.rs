@@ -0,0 +1,218 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[openbrush::implementation(Ownable)]
+#[openbrush::contract]
+pub mod reward_vault {
+    use ink::storage::{Lazy, Mapping};
+    use openbrush::{modifiers, traits::Storage};
+    use traits::{errors::VaultError, vault::RewardVault};
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Vault {
+        // The field to save the owner of the contract
+        #[storage_field]
+        ownable: ownable::Data,
+
+        // The reward contract allowed to credit rewards.
+        reward_contract: Lazy<AccountId>,
+
+        // The balance credited to each account.
+        credits: Mapping<AccountId, Balance>,
+
+        // The total balance credited and not withdrawn yet.
+        total_credits: Lazy<Balance>,
+    }
+
+    /// Emitted when a reward is credited to an account.
+    #[ink(event)]
+    pub struct Credited {
+        #[ink(topic)]
+        account: AccountId,
+        amount: Balance,
+    }
+
+    /// Emitted when an account withdraws its credited rewards.
+    #[ink(event)]
+    pub struct Withdrawn {
+        #[ink(topic)]
+        account: AccountId,
+        amount: Balance,
+    }
+
+    impl Vault {
+        /// Constructor that initializes a vault for a given reward contract.
+        #[ink(constructor)]
+        pub fn new(reward_contract: AccountId) -> Self {
+            let mut instance = Self::default();
+            ownable::Internal::_init_with_owner(&mut instance, Self::env().caller());
+            instance.reward_contract.set(&reward_contract);
+            instance
+        }
+
+        /// Set the reward contract allowed to credit rewards (e.g. after an upgrade).
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_reward_contract(
+            &mut self,
+            reward_contract: AccountId,
+        ) -> Result<(), VaultError> {
+            self.reward_contract.set(&reward_contract);
+            Ok(())
+        }
+
+        /// Simply returns the reward contract allowed to credit rewards.
+        #[ink(message)]
+        pub fn get_reward_contract(&self) -> Option<AccountId> {
+            self.reward_contract.get()
+        }
+
+        /// Simply returns the total balance credited and not withdrawn yet.
+        #[ink(message)]
+        pub fn total_credits(&self) -> Balance {
+            self.total_credits.get_or_default()
+        }
+    }
+
+    impl RewardVault for Vault {
+        /// Credit `amount`, transferred with the call, to `account`. Only the reward contract
+        /// is allowed to call it.
+        #[ink(message, payable)]
+        fn credit(&mut self, account: AccountId, amount: Balance) -> Result<(), VaultError> {
+            if self.get_reward_contract() != Some(self.env().caller()) {
+                return Err(VaultError::CallerIsNotRewardContract);
+            }
+            let total_credits = self.total_credits().saturating_add(amount);
+            if total_credits > self.env().balance() {
+                return Err(VaultError::InsufficientFunds);
+            }
+
+            self.credits
+                .insert(account, &self.credit_of(account).saturating_add(amount));
+            self.total_credits.set(&total_credits);
+            self.env().emit_event(Credited { account, amount });
+            Ok(())
+        }
+
+        /// Simply returns the balance credited to a given account.
+        #[ink(message)]
+        fn credit_of(&self, account: AccountId) -> Balance {
+            self.credits.get(account).unwrap_or_default()
+        }
+
+        /// Withdraw the whole balance credited to the caller.
+        #[ink(message)]
+        fn withdraw(&mut self) -> Result<Balance, VaultError> {
+            let caller = self.env().caller();
+            let amount = self.credit_of(caller);
+            if amount == 0 {
+                return Err(VaultError::NothingToWithdraw);
+            }
+
+            self.credits.remove(caller);
+            self.total_credits
+                .set(&self.total_credits().saturating_sub(amount));
+            self.env()
+                .transfer(caller, amount)
+                .map_err(|_| VaultError::TransferFailed)?;
+            self.env().emit_event(Withdrawn {
+                account: caller,
+                amount,
+            });
+            Ok(amount)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        /// Accounts
+        /// ALICE -> contract owner
+        /// BOB -> reward contract
+        /// CHARLIE -> contributor
+
+        /// Imports all the definitions from the outer scope so we can use them here.
+        use super::*;
+        use traits::test_utils::{
+            self, contract_id, default_accounts, get_balance, set_balance, set_next_caller,
+        };
+
+        #[ink::test]
+        fn new_works() {
+            let accounts = default_accounts();
+            let contract = create_contract();
+            assert_eq!(contract.get_reward_contract(), Some(accounts.bob));
+            assert_eq!(contract.total_credits(), 0);
+        }
+
+        #[ink::test]
+        fn credit_and_withdraw_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+            set_balance(accounts.charlie, 0);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.credit(accounts.charlie, 100), Ok(()));
+            assert_eq!(contract.credit(accounts.charlie, 50), Ok(()));
+            assert_eq!(contract.credit_of(accounts.charlie), 150);
+            assert_eq!(contract.total_credits(), 150);
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.withdraw(), Ok(150));
+            assert_eq!(get_balance(accounts.charlie), 150);
+            assert_eq!(contract.credit_of(accounts.charlie), 0);
+            assert_eq!(contract.total_credits(), 0);
+            assert_eq!(contract.withdraw(), Err(VaultError::NothingToWithdraw));
+        }
+
+        #[ink::test]
+        fn only_reward_contract_can_credit() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 1_000);
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.credit(accounts.charlie, 100),
+                Err(VaultError::CallerIsNotRewardContract)
+            );
+        }
+
+        #[ink::test]
+        fn credit_beyond_balance_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+            set_balance(contract_id(), 100);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.credit(accounts.charlie, 60), Ok(()));
+            assert_eq!(
+                contract.credit(accounts.charlie, 60),
+                Err(VaultError::InsufficientFunds)
+            );
+        }
+
+        #[ink::test]
+        fn only_contract_owner_can_set_reward_contract() {
+            let accounts = default_accounts();
+            let mut contract = create_contract();
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_reward_contract(accounts.charlie),
+                Err(VaultError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_reward_contract(accounts.charlie), Ok(()));
+            assert_eq!(contract.get_reward_contract(), Some(accounts.charlie));
+        }
+
+        /// Creates a new instance of `Vault` for the BOB reward contract.
+        ///
+        /// Returns the `contract_instance`.
+        fn create_contract() -> Vault {
+            test_utils::create_contract(|| Vault::new(default_accounts().bob))
+        }
+    }
+}
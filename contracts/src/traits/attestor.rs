@@ -0,0 +1,12 @@
+use ink::primitives::AccountId;
+
+/// The interface of a contract attesting contributions, so that other dapps can gate features
+/// (e.g. "only contributors may mint") with a cheap cross-contract read.
+///
+/// Dapps query it through `ink::contract_ref!(KudosAttestor)`.
+#[ink::trait_definition]
+pub trait KudosAttestor {
+    /// Returns `true` if `account` has at least `min_count` approved contributions.
+    #[ink(message)]
+    fn has_contribution(&self, account: AccountId, min_count: u32) -> bool;
+}
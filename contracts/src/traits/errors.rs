@@ -0,0 +1,301 @@
+use openbrush::{
+    contracts::{
+        reentrancy_guard::ReentrancyGuardError,
+        traits::{access_control::AccessControlError, ownable::OwnableError, psp22::PSP22Error},
+    },
+    traits::{AccountId, Balance},
+};
+
+/// Errors that can occur upon calling the `Demo` contract.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum DemoError {
+    OwnableError(OwnableError),
+    ReentrancyGuardError(ReentrancyGuardError),
+    /// A `contribution` is already approved in the DB, for `contributor`.
+    ContributionAlreadyApproved {
+        contributor: AccountId,
+    },
+    /// A `contributor_id` is already bound to an account.
+    IdentityAlreadyRegistered,
+    /// The `contribution` is not approved.
+    UnknownContribution,
+    /// The caller is not the contributor of the `contribution`.
+    CallerIsNotContributor,
+    /// The `contribution` is already claimed.
+    AlreadyClaimed,
+    /// The reward transfer failed.
+    TransferFailed,
+    /// The campaign ends before it starts.
+    InvalidCampaign,
+    /// A campaign is already active.
+    CampaignAlreadyActive,
+    /// The account is on the deny list.
+    ContributorDenied,
+    /// The account is not on the allow list while the strict mode is enabled.
+    ContributorNotAllowed,
+    /// The co-author splits are empty, duplicated or do not sum to 10000 bps.
+    InvalidSplits,
+    /// A sweep is already scheduled.
+    SweepAlreadyScheduled,
+    /// No sweep is scheduled.
+    NoSweepScheduled,
+    /// The timelock of the scheduled sweep is not over yet.
+    SweepTimelocked,
+    /// The contributor reached the maximum number of approvals in the active campaign.
+    ContributorCapReached,
+    /// No identity verifier is set.
+    NoVerifier,
+    /// The signature is not made by the identity verifier.
+    InvalidSignature,
+    /// Identities are managed by the identity registry.
+    IdentityRegistryInUse,
+    /// The claim deadline is already passed.
+    InvalidDeadline,
+    /// A reward setting is out of range.
+    InvalidConfig,
+    /// The claim deadline of the `contribution` is passed.
+    ClaimDeadlinePassed,
+    /// The claim deadline of the `contribution` is not passed yet.
+    ClaimDeadlineNotPassed,
+    /// The account is already bound to another contributor identity.
+    AccountAlreadyRegistered,
+    /// The tags are too many, duplicated, empty or too long.
+    InvalidTags,
+    /// The referrer is the caller or is not registered.
+    InvalidReferrer,
+    /// No referral bonus is owed to the caller.
+    NoReferralRewards,
+    /// The voucher nonce is already redeemed.
+    VoucherAlreadyRedeemed,
+    /// The nonce is not the next nonce of the approver.
+    InvalidNonce,
+    /// The contract balance does not cover the `needed` reward.
+    InsufficientPool {
+        needed: Balance,
+        available: Balance,
+    },
+    AccessControlError(AccessControlError),
+    PSP22Error(PSP22Error),
+    /// Too many contributions are given at once.
+    TooManyContributions,
+    /// The `contribution` is not fully claimed yet.
+    ContributionNotSettled,
+    /// The `contribution` cannot be archived by anyone yet.
+    ArchiveTooEarly,
+    /// The `contribution` is archived.
+    ContributionArchived,
+    /// The PSP22 token is not accepted as reward.
+    UnknownRewardAsset,
+    /// The maximum number of subscribers is reached.
+    TooManySubscribers,
+    /// The claimed amount exceeds the share left to the caller.
+    AmountExceedsShare {
+        remaining: Balance,
+    },
+    /// Another contribution was approved under the same deterministic id.
+    ContributionIdCollision,
+    /// The caller has no registered identity.
+    IdentityNotRegistered,
+    /// The profile is empty or exceeds its bounds.
+    InvalidProfile,
+    /// No termination is scheduled for the beneficiary.
+    NoTerminationScheduled,
+    /// The timelock of the scheduled termination is not over yet.
+    TerminationTimelocked,
+    /// Rewards are still owed to contributors.
+    OutstandingLiabilities,
+    /// Importing contributions is locked.
+    ImportFinalized,
+    /// The contract is built without the `call-runtime` feature.
+    CallRuntimeUnavailable,
+    /// The runtime call failed.
+    CallRuntimeFailed,
+    /// Governance is disabled.
+    GovernanceDisabled,
+    /// The caller holds no KUDOS points.
+    NoVotingPower,
+    /// The proposal does not exist.
+    UnknownProposal,
+    /// The caller already voted on the proposal.
+    AlreadyVoted,
+    /// The vote on the proposal is over.
+    VotingClosed,
+    /// The vote on the proposal is not over yet.
+    VotingNotClosed,
+    /// The proposal did not pass or is already executed.
+    ProposalNotExecutable,
+    /// The SS58 address cannot be decoded.
+    InvalidAddress,
+    /// Optimistic approvals are disabled.
+    OptimisticApprovalsDisabled,
+    /// The transferred stake is below the approval stake.
+    InsufficientStake,
+    /// An approval of the `contribution` is already proposed.
+    ApprovalAlreadyProposed,
+    /// No approval of the `contribution` is proposed.
+    UnknownProposedApproval,
+    /// The challenge period of the proposed approval is over.
+    ChallengePeriodOver,
+    /// The challenge period of the proposed approval is not over yet.
+    ChallengePeriodNotOver,
+    /// The range of contributions ends before it starts.
+    InvalidRange,
+    /// The handle and salt do not hash to the caller's identity.
+    InvalidPreimage,
+    /// The account is the zero account.
+    ZeroAccount,
+    /// The account is the contract itself.
+    ContractAccount,
+    /// A contribution cannot be linked to itself.
+    InvalidRelation,
+    /// The arbiter pool is empty.
+    NoArbiters,
+    /// The maximum number of arbiters is reached.
+    TooManyArbiters,
+    /// The proposed approval is already disputed.
+    AlreadyDisputed,
+    /// The proposed approval is disputed and awaits its resolution.
+    ApprovalDisputed,
+    /// The proposed approval is not disputed.
+    UnknownDispute,
+    /// The caller is not the arbiter of the dispute.
+    CallerIsNotArbiter,
+    /// The session expires before the current block.
+    InvalidSessionExpiry,
+    /// The session key of the caller is expired.
+    SessionExpired,
+    /// The caller is not the difficulty oracle.
+    CallerIsNotOracle,
+    /// No USD pricing is set.
+    UsdPricingUnset,
+    /// The claimed amount is below the minimum accepted by the caller.
+    SlippageExceeded,
+    /// The contribution is already submitted.
+    AlreadySubmitted,
+    /// The contribution is not submitted.
+    UnknownSubmission,
+    /// The encrypted memo is empty or too long.
+    InvalidMemo,
+    /// The contributor has no encryption public key.
+    EncryptionKeyUnset,
+    /// The dust of the caller is below the minimum claim.
+    DustBelowMinimum,
+    /// The caller is a contributor of the contribution.
+    CallerIsContributor,
+    /// The caller already endorsed the contribution.
+    AlreadyEndorsed,
+    /// The contribution reached the maximum number of endorsements.
+    EndorsementCapReached,
+    /// The action is not allowed in the current lifecycle status of the contribution.
+    InvalidTransition,
+    /// The new owner did not claim its intent to take over the ownership.
+    OwnershipIntentMissing,
+    /// No airdrop is open.
+    AirdropUnset,
+    /// The airdrop of the identity is already claimed.
+    AirdropAlreadyClaimed,
+    /// The Merkle proof does not prove the airdrop of the identity.
+    InvalidProof,
+    /// Reviews are disabled.
+    ReviewsDisabled,
+    /// The caller already reviewed the contribution.
+    AlreadyReviewed,
+    /// The contribution is not reviewed for the contributor.
+    ContributorMismatch,
+    /// The maximum number of pending proposed approvals is reached.
+    TooManyPendingApprovals,
+    /// No value is transferred with the tip.
+    ZeroTip,
+    /// No tips are left to the caller.
+    NoTips,
+    /// The merge commit SHA is neither a SHA-1 nor a SHA-256 hash.
+    InvalidCommitSha,
+    /// The claimed amount needs an unexpired account age attestation of the caller.
+    AttestationRequired,
+    /// The account age attestation is expired.
+    AttestationExpired,
+    /// The approver would be paid by their own approval.
+    SelfApprovalForbidden,
+    /// No conflicting reviews of the contribution are pending.
+    UnknownConflict,
+    /// The contribution reached the maximum number of sponsors.
+    TooManySponsors,
+    /// The DID URI is malformed or is not the DID linked to the account.
+    InvalidDid,
+    /// The transferred value does not cover the `needed` storage deposit of the approval.
+    InsufficientDeposit {
+        needed: Balance,
+    },
+    /// The maximum number of error messages is reached.
+    TooManyErrorMessages,
+    /// The caller is a suspected duplicate contributor pending its manual review.
+    DuplicateUnderReview,
+    /// The campaign does not exist.
+    UnknownCampaign,
+    /// The campaign is still running.
+    CampaignNotOver,
+    /// The campaign is already finalized.
+    CampaignFinalized,
+    /// The project has no maintainers.
+    NoMaintainers,
+    /// The maximum number of maintainers of the project is reached.
+    TooManyMaintainers,
+    /// The caller is not an allowlisted proxy contract.
+    CallerIsNotProxy,
+    /// The caller is not an allowlisted sponsor.
+    CallerIsNotSponsor,
+    /// No value is transferred as the sponsored reward.
+    ZeroReward,
+    /// No value is transferred with the project fees.
+    ZeroDeposit,
+    /// The tips of the contribution are not fully paid to its co-authors yet.
+    TipsUnpaid,
+    /// Too few KUDOS points voted on the proposal.
+    QuorumNotReached,
+}
+
+impl From<OwnableError> for DemoError {
+    fn from(error: OwnableError) -> Self {
+        DemoError::OwnableError(error)
+    }
+}
+
+impl From<AccessControlError> for DemoError {
+    fn from(error: AccessControlError) -> Self {
+        DemoError::AccessControlError(error)
+    }
+}
+
+impl From<PSP22Error> for DemoError {
+    fn from(error: PSP22Error) -> Self {
+        DemoError::PSP22Error(error)
+    }
+}
+
+impl From<ReentrancyGuardError> for DemoError {
+    fn from(error: ReentrancyGuardError) -> Self {
+        DemoError::ReentrancyGuardError(error)
+    }
+}
+
+/// Errors that can occur upon calling a `RewardVault` contract.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum VaultError {
+    OwnableError(OwnableError),
+    /// The caller is not the reward contract of the vault.
+    CallerIsNotRewardContract,
+    /// The vault balance does not cover the credited rewards.
+    InsufficientFunds,
+    /// Nothing is credited to the caller.
+    NothingToWithdraw,
+    /// The withdrawal transfer failed.
+    TransferFailed,
+}
+
+impl From<OwnableError> for VaultError {
+    fn from(error: OwnableError) -> Self {
+        VaultError::OwnableError(error)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::types::ContributionId;
+use openbrush::traits::{AccountId, Balance};
+
+/// The interface of a contract notified of approved contributions, so that integrations (badge
+/// minters, analytics, DAOs) can react to approvals without the reward contract knowing them.
+///
+/// Reward contracts call it through `ink::contract_ref!(OnContributionApproved)`, ignoring
+/// failures.
+#[ink::trait_definition]
+pub trait OnContributionApproved {
+    /// Called once a contribution is approved with its (first) contributor and its reward.
+    #[ink(message)]
+    fn on_contribution_approved(
+        &mut self,
+        contribution_id: ContributionId,
+        contributor: AccountId,
+        reward: Balance,
+    );
+}
@@ -0,0 +1,21 @@
+use crate::{errors::DemoError, types::ContributorId};
+use ink::primitives::AccountId;
+
+/// The interface of a registry binding contributor identities to accounts, shared by many
+/// reward contracts.
+///
+/// Reward contracts query it through `ink::contract_ref!(IdentityRegistry)`.
+#[ink::trait_definition]
+pub trait IdentityRegistry {
+    /// Bind the caller's account to a contributor identity.
+    #[ink(message)]
+    fn register_identity(&mut self, contributor_id: ContributorId) -> Result<(), DemoError>;
+
+    /// Simply returns the `AccountId` bound to a given contributor identity.
+    #[ink(message)]
+    fn get_identity(&self, contributor_id: ContributorId) -> Option<AccountId>;
+
+    /// Simply returns the (first) contributor identity bound to a given account.
+    #[ink(message)]
+    fn get_contributor_id(&self, account: AccountId) -> Option<ContributorId>;
+}
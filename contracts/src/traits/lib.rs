@@ -0,0 +1,14 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod attestor;
+pub mod errors;
+pub mod hooks;
+pub mod identity;
+pub mod price;
+pub mod selectors;
+pub mod strategy;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+pub mod types;
+pub mod vault;
+pub mod workflow;
@@ -0,0 +1,12 @@
+use ink::prelude::string::String;
+
+/// The interface of a price oracle, as deployed by DIA on ink! chains.
+///
+/// Reward contracts call it through `ink::contract_ref!(PriceFeed)`.
+#[ink::trait_definition]
+pub trait PriceFeed {
+    /// Returns the timestamp (in milliseconds) and the USD price, with 18 decimals, of the last
+    /// update of a given pair (e.g. `"DOT/USD"`).
+    #[ink(message)]
+    fn get_latest_price(&self, pair: String) -> Option<(u64, u128)>;
+}
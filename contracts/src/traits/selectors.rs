@@ -0,0 +1,127 @@
+//! The selectors of the public messages of the reward contract, for wallets and
+//! cross-contract callers.
+//!
+//! They are pinned with `#[ink(message, selector = ..)]` to the values ink! derives from
+//! the message names, so they stay stable across releases even if a message is renamed or
+//! moved to another trait.
+//!
+//! Only the contributor flow is pinned: identities, submissions, approvals, claims, tips and
+//! their views. The administration and keeper messages are meant for the owner tooling,
+//! shipped with the metadata of each release, and keep their derived selectors.
+
+/// The selectors of the messages of the `Workflow` trait.
+pub mod workflow {
+    /// `Workflow::register_identity`.
+    pub const REGISTER_IDENTITY: u32 = 0xEDDAA3C6;
+
+    /// `Workflow::approve`.
+    pub const APPROVE: u32 = 0x471895DC;
+
+    /// `Workflow::check`.
+    pub const CHECK: u32 = 0xC25F8127;
+
+    /// `Workflow::claim`.
+    pub const CLAIM: u32 = 0x1F5C9AA8;
+}
+
+/// The selectors of the messages of the `Demo` contract.
+pub mod demo {
+    /// `Demo::submit`.
+    pub const SUBMIT: u32 = 0x8EAC2BAF;
+
+    /// `Demo::claim_partial`.
+    pub const CLAIM_PARTIAL: u32 = 0x8BAAC0C8;
+
+    /// `Demo::estimate_claim`.
+    pub const ESTIMATE_CLAIM: u32 = 0x1F3DA261;
+
+    /// `Demo::claimed_amount`.
+    pub const CLAIMED_AMOUNT: u32 = 0x25E89EBF;
+
+    /// `Demo::contribution_view`.
+    pub const CONTRIBUTION_VIEW: u32 = 0x6697A394;
+
+    /// `Demo::identity_view`.
+    pub const IDENTITY_VIEW: u32 = 0x65309C78;
+
+    /// `Demo::tip`.
+    pub const TIP: u32 = 0xEDD26B46;
+
+    /// `Demo::get_reward`.
+    pub const GET_REWARD: u32 = 0xD759B94D;
+
+    /// `Demo::current_reward`.
+    pub const CURRENT_REWARD: u32 = 0xDFC67E5B;
+
+    /// `Demo::approval_deposit`.
+    pub const APPROVAL_DEPOSIT: u32 = 0x8E0ECFE5;
+
+    /// `Demo::set_payout_address`.
+    pub const SET_PAYOUT_ADDRESS: u32 = 0xC8DB9B86;
+
+    /// `Demo::payout_address_of`.
+    pub const PAYOUT_ADDRESS_OF: u32 = 0x1AE5B453;
+
+    /// `Demo::verify_receipt`.
+    pub const VERIFY_RECEIPT: u32 = 0x8A576109;
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn selectors_are_derived_from_names() {
+        assert_eq!(
+            super::workflow::REGISTER_IDENTITY,
+            ink::selector_id!("Workflow::register_identity")
+        );
+        assert_eq!(
+            super::workflow::APPROVE,
+            ink::selector_id!("Workflow::approve")
+        );
+        assert_eq!(super::workflow::CHECK, ink::selector_id!("Workflow::check"));
+        assert_eq!(super::workflow::CLAIM, ink::selector_id!("Workflow::claim"));
+        assert_eq!(super::demo::SUBMIT, ink::selector_id!("submit"));
+        assert_eq!(
+            super::demo::CLAIM_PARTIAL,
+            ink::selector_id!("claim_partial")
+        );
+        assert_eq!(
+            super::demo::ESTIMATE_CLAIM,
+            ink::selector_id!("estimate_claim")
+        );
+        assert_eq!(
+            super::demo::CLAIMED_AMOUNT,
+            ink::selector_id!("claimed_amount")
+        );
+        assert_eq!(
+            super::demo::CONTRIBUTION_VIEW,
+            ink::selector_id!("contribution_view")
+        );
+        assert_eq!(
+            super::demo::IDENTITY_VIEW,
+            ink::selector_id!("identity_view")
+        );
+        assert_eq!(super::demo::TIP, ink::selector_id!("tip"));
+        assert_eq!(super::demo::GET_REWARD, ink::selector_id!("get_reward"));
+        assert_eq!(
+            super::demo::CURRENT_REWARD,
+            ink::selector_id!("current_reward")
+        );
+        assert_eq!(
+            super::demo::APPROVAL_DEPOSIT,
+            ink::selector_id!("approval_deposit")
+        );
+        assert_eq!(
+            super::demo::SET_PAYOUT_ADDRESS,
+            ink::selector_id!("set_payout_address")
+        );
+        assert_eq!(
+            super::demo::PAYOUT_ADDRESS_OF,
+            ink::selector_id!("payout_address_of")
+        );
+        assert_eq!(
+            super::demo::VERIFY_RECEIPT,
+            ink::selector_id!("verify_receipt")
+        );
+    }
+}
@@ -0,0 +1,13 @@
+use crate::types::Contribution;
+use openbrush::traits::Balance;
+
+/// The interface of a contract computing the reward of a contribution, so that reward formulas
+/// can change without upgrading the reward contract.
+///
+/// Reward contracts call it through `ink::contract_ref!(RewardStrategy)`.
+#[ink::trait_definition]
+pub trait RewardStrategy {
+    /// Returns the whole reward of a given contribution, shared by its co-authors.
+    #[ink(message)]
+    fn compute(&self, contribution: Contribution) -> Balance;
+}
@@ -0,0 +1,76 @@
+//! Helpers of the off-chain unit tests of the contracts, on the default environment.
+
+use ink::env::{
+    test::{self, DefaultAccounts, EmittedEvent},
+    DefaultEnvironment,
+};
+use openbrush::traits::{AccountId, Balance, Timestamp};
+
+/// Returns the default accounts of the off-chain environment, Alice being the default caller.
+pub fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+
+/// Sets the caller of the next calls.
+pub fn set_next_caller(caller: AccountId) {
+    test::set_caller::<DefaultEnvironment>(caller);
+}
+
+/// Advances the block number (and timestamp) by one block.
+pub fn advance_block() {
+    test::advance_block::<DefaultEnvironment>();
+}
+
+/// Advances the block number (and timestamp) by `blocks` blocks.
+pub fn advance_blocks(blocks: u32) {
+    for _ in 0..blocks {
+        advance_block();
+    }
+}
+
+/// Sets the timestamp of the current block.
+pub fn set_block_timestamp(timestamp: Timestamp) {
+    test::set_block_timestamp::<DefaultEnvironment>(timestamp);
+}
+
+/// Returns the account of the contract under test.
+pub fn contract_id() -> AccountId {
+    test::callee::<DefaultEnvironment>()
+}
+
+/// Sets the native balance of an account.
+pub fn set_balance(account_id: AccountId, balance: Balance) {
+    test::set_account_balance::<DefaultEnvironment>(account_id, balance)
+}
+
+/// Returns the native balance of an account.
+pub fn get_balance(account_id: AccountId) -> Balance {
+    test::get_account_balance::<DefaultEnvironment>(account_id).expect("Cannot get account balance")
+}
+
+/// Adds `amount` to the native balance of an account.
+pub fn fund(account_id: AccountId, amount: Balance) {
+    let balance = test::get_account_balance::<DefaultEnvironment>(account_id).unwrap_or_default();
+    set_balance(account_id, balance.saturating_add(amount));
+}
+
+/// Creates a new instance of a contract with its `constructor`, called by Alice.
+///
+/// Returns the `contract_instance`.
+pub fn create_contract<C>(constructor: impl FnOnce() -> C) -> C {
+    set_next_caller(default_accounts().alice);
+    constructor()
+}
+
+/// Decodes the emitted events of a contract, `E` being its event enum.
+pub fn decode_events<E: scale::Decode>(emitted_events: Vec<EmittedEvent>) -> Vec<E> {
+    emitted_events
+        .into_iter()
+        .map(|event| E::decode(&mut &event.data[..]).expect("invalid data"))
+        .collect()
+}
+
+/// Decodes every event emitted so far.
+pub fn recorded_events<E: scale::Decode>() -> Vec<E> {
+    decode_events(test::recorded_events().collect())
+}
@@ -0,0 +1,959 @@
+pub use ink::prelude::string::String;
+use ink::prelude::vec::Vec;
+use openbrush::traits::{AccountId, Balance, BlockNumber, Timestamp};
+
+pub type ContributionId = u64;
+
+pub type CampaignId = u32;
+
+pub type ProposalId = u32;
+
+pub type RoleId = u32;
+
+pub type HashValue = [u8; 32];
+
+/// The algorithm hashing identities, so that the UI and the contract agree on them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum HashAlgorithm {
+    /// The Blake2b 256-bit hash, native to Substrate.
+    #[default]
+    Blake2x256,
+    /// The Keccak 256-bit hash, as computed by Ethereum JS libraries.
+    Keccak256,
+}
+
+impl HashAlgorithm {
+    /// Returns the hash of `input`.
+    pub fn hash(&self, input: &[u8]) -> HashValue {
+        let mut output = HashValue::default();
+        match self {
+            HashAlgorithm::Blake2x256 => {
+                ink::env::hash_bytes::<ink::env::hash::Blake2x256>(input, &mut output)
+            }
+            HashAlgorithm::Keccak256 => {
+                ink::env::hash_bytes::<ink::env::hash::Keccak256>(input, &mut output)
+            }
+        }
+        output
+    }
+}
+
+/// A compressed ECDSA public key.
+pub type EcdsaPublicKey = [u8; 33];
+
+/// A recoverable ECDSA signature.
+pub type EcdsaSignature = [u8; 65];
+
+/// An X25519 public key, memos are encrypted to off-chain.
+pub type EncryptionPublicKey = [u8; 32];
+
+/// The kind of a contribution to a repository.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum ContributionKind {
+    Issue,
+    PullRequest,
+}
+
+/// The platform a contributor identity belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum Platform {
+    GitHub,
+    GitLab,
+    Email,
+}
+
+/// A contributor identity: the hash of a handle (or e-mail) on a given platform.
+pub type ContributorId = (Platform, HashValue);
+
+/// The basis points of a whole reward.
+pub const MAX_BPS: u16 = 10_000;
+
+/// The highest difficulty score of a contribution.
+pub const MAX_DIFFICULTY: u8 = 100;
+
+/// The maximum number of co-authors of a contribution.
+pub const MAX_SPLITS: usize = 64;
+
+/// The maximum length of a profile display name, in bytes.
+pub const MAX_DISPLAY_NAME_LENGTH: usize = 64;
+
+/// The maximum length of a profile URI, in bytes.
+pub const MAX_URI_LENGTH: usize = 256;
+
+/// The maximum length of a price feed pair, in bytes.
+pub const MAX_PAIR_LENGTH: usize = 32;
+
+/// The maximum length of a localized error message, in bytes.
+pub const MAX_ERROR_MESSAGE_LENGTH: usize = 256;
+
+/// An ISO 639-1 language code, e.g. `*b"en"`.
+pub type LanguageCode = [u8; 2];
+
+/// The length of a string or collection exceeds its bound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoundExceeded;
+
+/// A string of at most `N` bytes, encoded like a `String`.
+///
+/// Its bound is checked on decoding too, so an oversized payload can be neither stored nor
+/// returned.
+#[derive(Debug, Clone, Default, PartialEq, Eq, scale::Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct BoundedString<const N: usize>(String);
+
+impl<const N: usize> BoundedString<N> {
+    /// Returns the string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<const N: usize> core::ops::Deref for BoundedString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<const N: usize> TryFrom<String> for BoundedString<N> {
+    type Error = BoundExceeded;
+
+    fn try_from(value: String) -> Result<Self, BoundExceeded> {
+        if value.len() > N {
+            return Err(BoundExceeded);
+        }
+        Ok(Self(value))
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for BoundedString<N> {
+    type Error = BoundExceeded;
+
+    fn try_from(value: &str) -> Result<Self, BoundExceeded> {
+        Self::try_from(String::from(value))
+    }
+}
+
+impl<const N: usize> From<BoundedString<N>> for String {
+    fn from(value: BoundedString<N>) -> Self {
+        value.0
+    }
+}
+
+impl<const N: usize> scale::Decode for BoundedString<N> {
+    fn decode<I: scale::Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let bytes = BoundedVec::<u8, N>::decode(input)?;
+        String::from_utf8(bytes.0)
+            .map(Self)
+            .map_err(|_| "invalid UTF-8 string".into())
+    }
+}
+
+/// A vector of at most `N` items, encoded like a `Vec`.
+///
+/// Its bound is checked on decoding too, before any item is decoded. Its items can be changed
+/// in place, but not added.
+#[derive(Debug, Clone, Default, PartialEq, Eq, scale::Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct BoundedVec<T, const N: usize>(Vec<T>);
+
+impl<T, const N: usize> core::ops::Deref for BoundedVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> core::ops::DerefMut for BoundedVec<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+impl<T, const N: usize> TryFrom<Vec<T>> for BoundedVec<T, N> {
+    type Error = BoundExceeded;
+
+    fn try_from(value: Vec<T>) -> Result<Self, BoundExceeded> {
+        if value.len() > N {
+            return Err(BoundExceeded);
+        }
+        Ok(Self(value))
+    }
+}
+
+impl<T, const N: usize> From<BoundedVec<T, N>> for Vec<T> {
+    fn from(value: BoundedVec<T, N>) -> Self {
+        value.0
+    }
+}
+
+impl<T: scale::Decode, const N: usize> scale::Decode for BoundedVec<T, N> {
+    fn decode<I: scale::Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let length = <scale::Compact<u32>>::decode(input)?.0 as usize;
+        if length > N {
+            return Err("collection exceeds its bound".into());
+        }
+        let mut items = Vec::with_capacity(length);
+        for _ in 0..length {
+            items.push(T::decode(input)?);
+        }
+        Ok(Self(items))
+    }
+}
+
+/// How the reward of a contribution is weighted by its difficulty score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum RewardMode {
+    /// The whole reward, whatever the difficulty.
+    #[default]
+    Flat,
+    /// The reward times the square of the difficulty ratio.
+    Quadratic,
+    /// The reward times the square root of the difficulty ratio.
+    Sqrt,
+}
+
+/// Who may approve a contribution paying their own account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum SelfApproval {
+    /// Every approver.
+    #[default]
+    Allowed,
+    /// Only the owner, for repositories with a single maintainer.
+    OwnerOnly,
+    /// No approver.
+    Forbidden,
+}
+
+/// The reward settings of a contract.
+#[derive(Debug, Clone, PartialEq, Eq, Default, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Config {
+    /// The reward paid for each approved contribution.
+    pub reward: Balance,
+    /// The bonus (in basis points of the reward) granted on a first contribution.
+    pub bonus_bps: u16,
+    /// How the reward is weighted by the difficulty of a contribution.
+    pub reward_mode: RewardMode,
+    /// The length of an epoch in blocks, zero disables streaks.
+    pub epoch_length: BlockNumber,
+    /// The bonus (in basis points of the reward) granted per consecutive epoch of a streak.
+    pub streak_bonus_bps: u16,
+    /// The maximum number of approvals per contributor in a campaign, zero means no cap.
+    pub contributor_cap: u32,
+    /// The number of blocks an approved reward can be claimed for, zero means forever.
+    pub claim_ttl: BlockNumber,
+    /// The bonus paid to the referrer of a contributor on their first approved contribution.
+    pub referral_bonus: Balance,
+    /// The fee (in basis points of the claimed amount) paid to a relayer claiming on behalf
+    /// of a contributor.
+    pub relayer_fee_bps: u16,
+    /// The stake required to propose an approval optimistically.
+    pub approval_stake: Balance,
+    /// The number of blocks a proposed approval can be challenged for, zero disables
+    /// optimistic approvals.
+    pub challenge_period: BlockNumber,
+    /// The tip paid to keepers whose `poke` emits expiry reminders.
+    pub poke_tip: Balance,
+    /// The minimum native payout of a claim, smaller payouts are kept as dust until the dust
+    /// of the contributor reaches it.
+    pub min_claim: Balance,
+    /// The reward increment granted per endorsement of a contribution by a peer.
+    pub endorsement_bonus: Balance,
+    /// The maximum number of endorsements of a contribution, zero disables endorsements.
+    pub max_endorsements: u32,
+    /// The number of distinct approvers reviewing a contribution before its approval, zero
+    /// disables reviews.
+    pub review_quorum: u32,
+    /// The claimed amount above which the contributor must hold an unexpired attestation of
+    /// their account age signed by the verifier, zero disables attestations.
+    pub attestation_threshold: Balance,
+    /// The minimum age (in days) of the GitHub account of a contributor to be attested.
+    pub min_account_age: u32,
+    /// Who may approve a contribution paying their own account.
+    pub self_approval: SelfApproval,
+    /// Whether native claim payouts also emit a PSP22 `Transfer` event from the contract, for
+    /// explorers and wallets indexing token events only.
+    pub emit_transfer_events: bool,
+    /// The number of blocks after which the reward halves, counted from the start of the
+    /// active campaign (or from the first block without campaign), zero disables halvings.
+    pub halving_period: BlockNumber,
+    /// The storage deposit per byte charged by the chain, covered by the approvers, zero
+    /// leaves the deposit of approvals to the contract.
+    pub deposit_per_byte: Balance,
+    /// The amount a suspected duplicate contributor may claim before its manual review, zero
+    /// disables the limit.
+    pub duplicate_claim_threshold: Balance,
+    /// The bounty (in basis points of the native rewards reclaimed) paid to keepers sweeping
+    /// expired contributions.
+    pub sweep_bounty_bps: u16,
+}
+
+/// A delayed action of the contract, executed by anyone once due.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Task {
+    /// Approve the unchallenged proposed approval of a contribution.
+    Finalize(ContributionId),
+    /// Return the unclaimed reward of a contribution past its claim deadline.
+    Expire(ContributionId),
+    /// Execute the scheduled sweep.
+    Sweep,
+}
+
+/// The asset a reward is paid in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum Asset {
+    /// The native currency of the chain.
+    #[default]
+    Native,
+    /// A PSP22 token contract.
+    PSP22(AccountId),
+}
+
+/// A movement of the native reward pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum Movement {
+    /// A deposit to the pool.
+    Fund,
+    /// A stake of a proposed approval.
+    Stake,
+    /// A reward (or referral bonus) paid to a contributor.
+    Claim,
+    /// A fee or tip paid to a relayer or keeper.
+    Fee,
+    /// A sweep of the pool.
+    Withdraw,
+    /// A stake refunded to its proposer.
+    Refund,
+}
+
+/// An entry of the ledger of the native reward pool.
+///
+/// Its integers are SCALE compact encoded to keep it small in storage.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct LedgerEntry {
+    /// The kind of movement.
+    pub movement: Movement,
+    /// The moved amount.
+    #[codec(compact)]
+    pub amount: Balance,
+    /// The contract balance after the movement.
+    #[codec(compact)]
+    pub balance: Balance,
+    /// The block of the movement.
+    #[codec(compact)]
+    pub block: BlockNumber,
+}
+
+/// How rewards denominated in USD cents are converted to the reward asset.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct UsdPricing {
+    /// The `PriceFeed` contract.
+    pub feed: AccountId,
+    /// The pair of the reward asset on the feed (e.g. `"DOT/USD"`).
+    pub pair: BoundedString<MAX_PAIR_LENGTH>,
+    /// The number of smallest units in one whole reward asset (e.g. 10^10 for DOT).
+    pub unit: Balance,
+    /// The age (in milliseconds) from which the feed price is stale.
+    pub max_age: Timestamp,
+    /// The USD price of one whole reward asset, with 18 decimals, used when the feed is stale.
+    pub fallback_price: u128,
+}
+
+/// The display metadata of a reward asset.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct AssetInfo {
+    /// The asset.
+    pub asset: Asset,
+    /// The ticker symbol of the asset, unknown for the native currency.
+    pub symbol: Option<String>,
+    /// The number of decimals of the asset, unknown for the native currency (see the chain
+    /// properties).
+    pub decimals: Option<u8>,
+}
+
+/// The public profile of a contributor.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Profile {
+    /// The name displayed on the leaderboard.
+    pub display_name: BoundedString<MAX_DISPLAY_NAME_LENGTH>,
+    /// The URI of the avatar image.
+    pub avatar_uri: Option<BoundedString<MAX_URI_LENGTH>>,
+    /// The URL of the contributor website.
+    pub website: Option<BoundedString<MAX_URI_LENGTH>>,
+}
+
+/// The decentralized identifier (e.g. a KILT `did:kilt:` DID) linked to a contributor.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Did {
+    /// The DID URI, `did:<method>:<identifier>`.
+    pub uri: BoundedString<MAX_URI_LENGTH>,
+    /// Whether the owner attested that the DID is controlled by the contributor.
+    pub is_verified: bool,
+}
+
+/// The address the rewards of a contributor are paid to, changed with a delay.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Payout {
+    /// The address paid before the change takes effect.
+    pub previous: AccountId,
+    /// The address paid once the change takes effect.
+    pub next: AccountId,
+    /// The block from which `next` is paid.
+    pub effective_at: BlockNumber,
+}
+
+/// The share of a contribution reward owed to one of its co-authors.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Split {
+    /// The account of the co-author.
+    pub account: AccountId,
+    /// The share of the reward, in basis points.
+    #[codec(compact)]
+    pub bps: u16,
+    /// Whether the co-author claimed their share.
+    pub is_claimed: bool,
+}
+
+/// An approved contribution.
+///
+/// Its integers are SCALE compact encoded to keep it small in storage.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Contribution {
+    /// The account of the (first) contributor.
+    pub contributor: AccountId,
+    /// The reward paid to the co-authors on claim.
+    #[codec(compact)]
+    pub reward: Balance,
+    /// The asset the reward is paid in.
+    pub asset: Asset,
+    /// Whether every co-author claimed their share.
+    pub is_claimed: bool,
+    /// The co-authors and their share of the reward.
+    pub splits: BoundedVec<Split, MAX_SPLITS>,
+    /// The block the contribution was approved at.
+    #[codec(compact)]
+    pub approved_at: BlockNumber,
+    /// The campaign the reward was drawn from.
+    pub campaign_id: Option<CampaignId>,
+    /// The last block the reward can be claimed at.
+    pub deadline: Option<BlockNumber>,
+}
+
+impl Contribution {
+    /// Returns the share of `account` in the contribution.
+    pub fn split_of(&self, account: AccountId) -> Option<&Split> {
+        self.splits.iter().find(|split| split.account == account)
+    }
+
+    /// Returns `true` if the claim deadline is passed at `block`.
+    pub fn is_expired(&self, block: BlockNumber) -> bool {
+        self.deadline.map_or(false, |deadline| block > deadline)
+    }
+
+    /// Returns the part of the reward matching a share in basis points.
+    pub fn share(&self, bps: u16) -> Balance {
+        self.reward.saturating_mul(bps.into()) / Balance::from(MAX_BPS)
+    }
+}
+
+/// How a contribution relates to another one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum Relation {
+    /// The contribution duplicates the other one.
+    DuplicateOf,
+    /// The contribution is superseded by the other one, e.g. a pull request reopened under a
+    /// new number.
+    SupersededBy,
+}
+
+/// An approval proposed by anyone with a stake, final unless challenged in time.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct ProposedApproval {
+    /// The account of the proposer, refunded on finalization.
+    pub proposer: AccountId,
+    /// The account of the contributor.
+    pub contributor: AccountId,
+    /// The stake of the proposer, slashed on challenge.
+    pub stake: Balance,
+    /// The last block the approval can be challenged at.
+    pub challenge_end: BlockNumber,
+}
+
+/// A contribution submitted by its contributor, pending its approval.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Submission {
+    /// The account of the contributor.
+    pub contributor: AccountId,
+    /// The hash of the evidence of the contribution (e.g. the pull request URL).
+    pub evidence: HashValue,
+    /// The block the contribution is submitted at.
+    pub submitted_at: BlockNumber,
+}
+
+/// The record of a contribution reviewed and declined by an approver.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Rejection {
+    /// The reason of the rejection, as coded by the workflow bot.
+    pub reason_code: u32,
+    /// The approver rejecting the contribution.
+    pub rejected_by: AccountId,
+    /// The block the contribution is rejected at.
+    pub rejected_at: BlockNumber,
+}
+
+/// A dispute of a proposed approval, resolved by a pseudo-randomly assigned arbiter.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Dispute {
+    /// The account of the assigned arbiter.
+    pub arbiter: AccountId,
+    /// The last block the arbiter can resolve the dispute at, before the owner takes over.
+    pub deadline: BlockNumber,
+}
+
+/// An approval of a contribution signed off-chain, redeemed by the contributor.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Voucher {
+    /// The approved contribution.
+    pub contribution_id: ContributionId,
+    /// The account of the contributor.
+    pub contributor: AccountId,
+    /// The reward of the contribution.
+    pub amount: Balance,
+    /// The unique number of the voucher, preventing replays.
+    pub nonce: u64,
+}
+
+/// An event of a contribution indexed by the project of the contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum ProjectEvent {
+    /// The `ContributionApproval` event.
+    Approval,
+    /// The `AlreadyApproved` event.
+    AlreadyApproved,
+    /// The `ContributionClaim` event.
+    Claim,
+}
+
+/// The status of a contribution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum ContributionStatus {
+    /// The contribution is not approved.
+    Unknown,
+    /// The contribution is submitted by its contributor and awaits its approval.
+    Pending,
+    /// The contribution is approved and some shares are not claimed yet.
+    Approved,
+    /// Every share of the contribution is claimed.
+    Claimed,
+}
+
+/// The status of a contribution from the point of view of an account.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct ContributionView {
+    /// The status of the contribution.
+    pub status: ContributionStatus,
+    /// Whether the account is a contributor of the contribution.
+    pub is_contributor: bool,
+    /// The share of the reward owed to the account.
+    pub reward: Balance,
+    /// The block from which the account can claim its share.
+    pub claimable_at: Option<BlockNumber>,
+    /// Whether the account claimed its share.
+    pub is_claimed: bool,
+}
+
+/// A preview of the claim of the share of a contribution left to an account.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct ClaimEstimate {
+    /// The asset the reward is paid in.
+    pub asset: Asset,
+    /// The share of the reward left to the account.
+    pub remaining: Balance,
+    /// The payout of the share left, boosted and converted to the reward asset.
+    pub payout: Balance,
+    /// The fee cut from the payout when a relayer claims on behalf of the account.
+    pub relayer_fee: Balance,
+    /// The amount paid on claim, with the dust of the account: native payouts are kept as
+    /// dust while below the minimum claim.
+    pub available: Balance,
+    /// Whether the reward pool covers the amount paid, always when a reward vault holds the
+    /// rewards as it is not visible to the contract.
+    pub is_covered: bool,
+}
+
+/// A contribution denormalized for display, with its status, claims and resolved identity.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct ContributionDetails {
+    /// The id of the contribution.
+    pub id: ContributionId,
+    /// The status of the contribution.
+    pub status: ContributionStatus,
+    /// The account of the (first) contributor.
+    pub contributor: AccountId,
+    /// The identity bound to the account of the contributor, if any.
+    pub contributor_id: Option<ContributorId>,
+    /// The reward paid to the co-authors on claim.
+    pub reward: Balance,
+    /// The asset the reward is paid in.
+    pub asset: Asset,
+    /// The amount of the reward claimed so far.
+    pub claimed: Balance,
+    /// The co-authors and their share of the reward.
+    pub splits: Vec<Split>,
+    /// The block the contribution was approved at.
+    pub approved_at: BlockNumber,
+    /// The last block the reward can be claimed at.
+    pub deadline: Option<BlockNumber>,
+    /// The tags of the contribution.
+    pub tags: Vec<String>,
+}
+
+/// An account denormalized for display, with its identity, profile and standing.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct IdentityView {
+    /// The account.
+    pub account: AccountId,
+    /// The identity bound to the account, if any.
+    pub contributor_id: Option<ContributorId>,
+    /// The public profile of the account, if any.
+    pub profile: Option<Profile>,
+    /// The DID linked to the account, if any.
+    pub did: Option<Did>,
+    /// The number of approved contributions of the account.
+    pub approvals: u32,
+    /// The KUDOS points of the account.
+    pub points: Balance,
+    /// The decayed reputation of the account (in millionths of KUDOS points).
+    pub reputation: Balance,
+    /// The current streak of the account.
+    pub streak: u32,
+    /// The address the rewards of the account are paid to.
+    pub payout_address: AccountId,
+}
+
+/// An invariant of the contract state found violated by a self-check.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum InvariantViolation {
+    /// The native rewards owed exceed the contract balance.
+    Insolvent {
+        liabilities: Balance,
+        balance: Balance,
+    },
+    /// No contribution id is indexed at `index`, below the contribution count.
+    MissingIndex { index: u32 },
+    /// An indexed contribution is neither stored nor archived.
+    MissingContribution { contribution_id: ContributionId },
+    /// The claim status of a contribution does not match the claim status of its splits.
+    ClaimStatusMismatch { contribution_id: ContributionId },
+}
+
+/// An action of a contributor, as kept in their activity log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum Activity {
+    /// The contributor bound their account to an identity.
+    Registered,
+    /// A contribution of the contributor was approved.
+    Approved(ContributionId),
+    /// The contributor claimed (part of) their share of a contribution.
+    Claimed(ContributionId),
+}
+
+/// The consecutive-epoch activity of a contributor.
+#[derive(Debug, Clone, PartialEq, Eq, Default, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Streak {
+    /// The last epoch with an approved contribution.
+    pub last_epoch: BlockNumber,
+    /// The number of consecutive epochs with an approved contribution.
+    pub length: u32,
+}
+
+/// A timed reward campaign with a budget cap.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Campaign {
+    /// The first block of the campaign.
+    pub start: BlockNumber,
+    /// The last block of the campaign.
+    pub end: BlockNumber,
+    /// The total budget of the campaign.
+    pub budget: Balance,
+    /// The budget left to pay rewards.
+    pub remaining: Balance,
+    /// The asset the rewards are paid in.
+    pub asset: Asset,
+    /// Whether the budget and the rewards are denominated in USD cents, converted to the asset
+    /// on claim.
+    pub in_usd: bool,
+}
+
+/// The summary of a finalized campaign, frozen at its finalization.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct CampaignSummary {
+    /// The rewards granted by the campaign, net of the expired ones returned to its budget.
+    pub total_paid: Balance,
+    /// The number of distinct contributors rewarded by the campaign.
+    pub contributors: u32,
+    /// The contributor granted the most rewards by the campaign, if any.
+    pub top_contributor: Option<AccountId>,
+    /// The rewards granted to the top contributor.
+    pub top_reward: Balance,
+    /// The block the campaign was finalized at.
+    pub finalized_at: BlockNumber,
+}
+
+/// A change of the contract settings enacted by governance.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum ProposalAction {
+    /// Replace the reward settings.
+    SetConfig(Config),
+    /// Add an account to (or remove it from) the deny list.
+    SetDenied { account: AccountId, denied: bool },
+    /// Assign a role to an account, or remove its role with `None`: the approvers are the
+    /// accounts whose role is permitted to approve.
+    SetRole {
+        account: AccountId,
+        role: Option<RoleId>,
+    },
+    /// Permit (or forbid) a role to call the owner message of a given selector.
+    SetPermission {
+        role: RoleId,
+        selector: [u8; 4],
+        allowed: bool,
+    },
+}
+
+/// A governance proposal voted by KUDOS points holders.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Proposal {
+    /// The change enacted if the proposal passes.
+    pub action: ProposalAction,
+    /// The account of the proposer.
+    pub proposer: AccountId,
+    /// The last block of the vote.
+    pub end: BlockNumber,
+    /// The KUDOS points voting for the proposal.
+    pub votes_for: Balance,
+    /// The KUDOS points voting against the proposal.
+    pub votes_against: Balance,
+    /// Whether the proposal was executed.
+    pub executed: bool,
+}
+
+/// A scheduled drain of the contract balance.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Sweep {
+    /// The account receiving the balance.
+    pub to: AccountId,
+    /// The timestamp from which the sweep can be executed.
+    pub executable_at: Timestamp,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scale::{Decode, Encode};
+
+    #[test]
+    fn hash_algorithm_works() {
+        // The hashes of the empty input
+        assert_eq!(
+            HashAlgorithm::Blake2x256.hash(b""),
+            [
+                0x0e, 0x57, 0x51, 0xc0, 0x26, 0xe5, 0x43, 0xb2, 0xe8, 0xab, 0x2e, 0xb0, 0x60, 0x99,
+                0xda, 0xa1, 0xd1, 0xe5, 0xdf, 0x47, 0x77, 0x8f, 0x77, 0x87, 0xfa, 0xab, 0x45, 0xcd,
+                0xf1, 0x2f, 0xe3, 0xa8,
+            ]
+        );
+        assert_eq!(
+            HashAlgorithm::Keccak256.hash(b""),
+            [
+                0xc5, 0xd2, 0x46, 0x01, 0x86, 0xf7, 0x23, 0x3c, 0x92, 0x7e, 0x7d, 0xb2, 0xdc, 0xc7,
+                0x03, 0xc0, 0xe5, 0x00, 0xb6, 0x53, 0xca, 0x82, 0x27, 0x3b, 0x7b, 0xfa, 0xd8, 0x04,
+                0x5d, 0x85, 0xa4, 0x70,
+            ]
+        );
+    }
+
+    #[test]
+    fn contribution_encoding_works() {
+        let contribution = Contribution {
+            contributor: AccountId::from([0x01; 32]),
+            reward: 100,
+            asset: Asset::Native,
+            is_claimed: false,
+            splits: BoundedVec::try_from(vec![Split {
+                account: AccountId::from([0x01; 32]),
+                bps: MAX_BPS,
+                is_claimed: false,
+            }])
+            .expect("too many splits"),
+            approved_at: 5,
+            campaign_id: None,
+            deadline: None,
+        };
+        let encoded = contribution.encode();
+        // The reward, the split share and the approval block take 2, 2 and 1 bytes
+        assert_eq!(encoded.len(), 32 + 2 + 1 + 1 + 1 + (32 + 2 + 1) + 1 + 1 + 1);
+        assert_eq!(
+            Contribution::decode(&mut &encoded[..]).expect("decoding failed"),
+            contribution
+        );
+    }
+
+    #[test]
+    fn bounded_string_works() {
+        let name = BoundedString::<4>::try_from("kudo").expect("too long");
+        assert_eq!(name.as_str(), "kudo");
+        assert_eq!(BoundedString::<4>::try_from("kudos"), Err(BoundExceeded));
+
+        // Bounded strings are encoded like strings, and their bound is checked on decoding
+        let encoded = name.encode();
+        assert_eq!(encoded, String::from("kudo").encode());
+        assert_eq!(BoundedString::<4>::decode(&mut &encoded[..]), Ok(name));
+        assert!(BoundedString::<3>::decode(&mut &encoded[..]).is_err());
+        assert!(BoundedString::<4>::decode(&mut &[0x08, 0xff, 0xfe][..]).is_err());
+    }
+
+    #[test]
+    fn bounded_vec_works() {
+        let mut items = BoundedVec::<u8, 2>::try_from(vec![1, 2]).expect("too long");
+        items[0] = 3;
+        assert_eq!(Vec::from(items.clone()), vec![3, 2]);
+        assert_eq!(
+            BoundedVec::<u8, 2>::try_from(vec![1, 2, 3]),
+            Err(BoundExceeded)
+        );
+
+        let encoded = items.encode();
+        assert_eq!(encoded, vec![3u8, 2].encode());
+        assert_eq!(BoundedVec::<u8, 2>::decode(&mut &encoded[..]), Ok(items));
+        assert!(BoundedVec::<u8, 1>::decode(&mut &encoded[..]).is_err());
+        // The bound is checked before decoding the items
+        assert!(BoundedVec::<u8, 2>::decode(&mut &[0xfc, 0xff, 0xff, 0xff][..]).is_err());
+    }
+}
@@ -0,0 +1,25 @@
+use crate::errors::VaultError;
+use ink::primitives::AccountId;
+use openbrush::traits::Balance;
+
+/// The interface of a vault holding the rewards credited by a reward contract, which
+/// contributors then withdraw themselves.
+///
+/// Reward contracts call it through `ink::contract_ref!(RewardVault)`.
+#[ink::trait_definition]
+pub trait RewardVault {
+    /// Credit `amount`, transferred with the call, to `account`. Only the reward contract
+    /// is allowed to call it.
+    #[ink(message, payable)]
+    fn credit(&mut self, account: AccountId, amount: Balance) -> Result<(), VaultError>;
+
+    /// Simply returns the balance credited to a given account.
+    #[ink(message)]
+    fn credit_of(&self, account: AccountId) -> Balance;
+
+    /// Withdraw the whole balance credited to the caller.
+    ///
+    /// Returns the withdrawn amount.
+    #[ink(message)]
+    fn withdraw(&mut self) -> Result<Balance, VaultError>;
+}
@@ -0,0 +1,35 @@
+use crate::{
+    errors::DemoError,
+    types::{ContributionId, ContributorId, HashValue},
+};
+use ink::primitives::AccountId;
+
+/// The interface a reward contract implements to be driven by a reward workflow.
+///
+/// Other contracts call it through `ink::contract_ref!(Workflow)`.
+#[ink::trait_definition]
+pub trait Workflow {
+    /// Bind the caller's account to a contributor identity.
+    #[ink(message, selector = 0xEDDAA3C6)]
+    fn register_identity(&mut self, contributor_id: ContributorId) -> Result<(), DemoError>;
+
+    /// Approve contribution. This is triggered by a workflow run.
+    ///
+    /// The caller attaches the storage deposit of the approval, the excess being refunded.
+    #[ink(message, payable, selector = 0x471895DC)]
+    fn approve(
+        &mut self,
+        contribution_id: ContributionId,
+        contributor: AccountId,
+    ) -> Result<(), DemoError>;
+
+    /// Returns `true` if the caller is the contributor of a given contribution.
+    #[ink(message, selector = 0xC25F8127)]
+    fn check(&self, contribution_id: ContributionId) -> bool;
+
+    /// Claim the reward of a given contribution.
+    ///
+    /// Returns the receipt hash of the payment.
+    #[ink(message, selector = 0x1F5C9AA8)]
+    fn claim(&mut self, contribution_id: ContributionId) -> Result<HashValue, DemoError>;
+}